@@ -1,16 +1,84 @@
-async fn health_check(State(state): State<AppState>) -> Result<Json<HealthResponse>, StatusCode> {
+async fn health_check(State(state): State<AppState>) -> (StatusCode, Json<HealthResponse>) {
     let health_status = state.observability.health_status();
+    let mut checks = std::collections::HashMap::new();
+
+    let database_start = std::time::Instant::now();
+    let comprehensive = state.database.comprehensive_health_check().await;
+    let database_ms = u64::try_from(database_start.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+    let sample_start = std::time::Instant::now();
+    let sample = state.database.get_inbox_count().await;
+    let sample_ms = u64::try_from(sample_start.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+    let health_state = match (&comprehensive, &sample) {
+        (Ok(status), Ok(_)) => classify_health(status, sample_ms),
+        _ => HealthState::Unhealthy,
+    };
+
+    checks.insert(
+        "database".to_string(),
+        match &comprehensive {
+            Ok(status) => CheckResponse {
+                status: if status.overall_healthy {
+                    "healthy"
+                } else {
+                    "unhealthy"
+                }
+                .to_string(),
+                message: Some(format!(
+                    "Pool utilization {:.0}%",
+                    status.pool_metrics.utilization_percentage
+                )),
+                duration_ms: database_ms,
+            },
+            Err(e) => CheckResponse {
+                status: "unhealthy".to_string(),
+                message: Some(e.to_string()),
+                duration_ms: database_ms,
+            },
+        },
+    );
+    checks.insert(
+        "sample_query".to_string(),
+        CheckResponse {
+            status: match &sample {
+                Ok(_) if sample_ms >= DEGRADED_QUERY_LATENCY_MS => "degraded",
+                Ok(_) => "healthy",
+                Err(_) => "unhealthy",
+            }
+            .to_string(),
+            message: sample.as_ref().err().map(ToString::to_string),
+            duration_ms: sample_ms,
+        },
+    );
 
     let response = HealthResponse {
-        status: health_status.status,
+        status: health_state.as_str().to_string(),
         timestamp: health_status.timestamp.to_string(),
         uptime: health_status.uptime,
         version: health_status.version,
         environment: "production".to_string(),
-        checks: std::collections::HashMap::new(),
+        checks,
     };
 
-    Ok(Json(response))
+    (health_state.status_code(), Json(response))
+}
+
+/// Map a database health snapshot and sample-query latency to a [`HealthState`]
+///
+/// A database that fails its connectivity check is `unhealthy`. One that is
+/// still serving but has a busy pool or a slow sample query is `degraded`.
+#[must_use]
+pub fn classify_health(status: &ComprehensiveHealthStatus, sample_query_ms: u64) -> HealthState {
+    if !status.overall_healthy {
+        HealthState::Unhealthy
+    } else if status.pool_metrics.utilization_percentage >= DEGRADED_POOL_UTILIZATION_PERCENT
+        || sample_query_ms >= DEGRADED_QUERY_LATENCY_MS
+    {
+        HealthState::Degraded
+    } else {
+        HealthState::Healthy
+    }
 }
 
 async fn readiness_check(
@@ -66,12 +134,51 @@ async fn metrics_endpoint(State(state): State<AppState>) -> Result<String, Statu
 use axum::{extract::State, http::StatusCode, response::Json, routing::get, Router};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use things3_core::{ObservabilityManager, ThingsDatabase};
+use things3_core::{ComprehensiveHealthStatus, ObservabilityManager, ThingsDatabase};
 use tokio::net::TcpListener;
 use tower_http::cors::CorsLayer;
 use tracing::{info, instrument};
 
+/// Pool utilization (percent) at or above which `/health` reports `degraded`
+pub const DEGRADED_POOL_UTILIZATION_PERCENT: f64 = 80.0;
+
+/// Sample-query latency (ms) at or above which `/health` reports `degraded`
+pub const DEGRADED_QUERY_LATENCY_MS: u64 = 500;
+
 // Struct definitions - must come after all functions to avoid items_after_statements
+/// Overall state reported by `/health`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthState {
+    /// Database reachable, pool and sample query within limits
+    Healthy,
+    /// Still serving, but the pool is under pressure or queries are slow
+    Degraded,
+    /// The database cannot be queried
+    Unhealthy,
+}
+
+impl HealthState {
+    /// Lowercase name used in the JSON body
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Healthy => "healthy",
+            Self::Degraded => "degraded",
+            Self::Unhealthy => "unhealthy",
+        }
+    }
+
+    /// HTTP status for this state: 200 unless the database is unusable
+    #[must_use]
+    pub const fn status_code(self) -> StatusCode {
+        match self {
+            Self::Healthy | Self::Degraded => StatusCode::OK,
+            Self::Unhealthy => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
 /// Application state
 #[derive(Clone)]
 pub struct AppState {
@@ -482,4 +589,41 @@ mod tests {
         assert_eq!(response.uptime.as_secs(), 86400);
         assert_eq!(response.environment, "production");
     }
+
+    #[tokio::test]
+    async fn test_classify_health_states() {
+        let temp_file = NamedTempFile::new().unwrap();
+        things3_core::test_utils::create_test_database(temp_file.path())
+            .await
+            .unwrap();
+        let db = ThingsDatabase::new(temp_file.path()).await.unwrap();
+        let mut status = db.comprehensive_health_check().await.unwrap();
+        status.pool_metrics.utilization_percentage = 10.0;
+
+        assert_eq!(classify_health(&status, 5), HealthState::Healthy);
+        assert_eq!(
+            classify_health(&status, DEGRADED_QUERY_LATENCY_MS),
+            HealthState::Degraded
+        );
+
+        status.pool_metrics.utilization_percentage = DEGRADED_POOL_UTILIZATION_PERCENT;
+        assert_eq!(classify_health(&status, 5), HealthState::Degraded);
+
+        status.overall_healthy = false;
+        assert_eq!(classify_health(&status, 5), HealthState::Unhealthy);
+    }
+
+    #[test]
+    fn test_health_state_status_codes() {
+        assert_eq!(HealthState::Healthy.status_code(), StatusCode::OK);
+        assert_eq!(HealthState::Degraded.status_code(), StatusCode::OK);
+        assert_eq!(
+            HealthState::Unhealthy.status_code(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+        assert_eq!(
+            serde_json::to_string(&HealthState::Degraded).unwrap(),
+            "\"degraded\""
+        );
+    }
 }
@@ -22,17 +22,20 @@ pub mod metrics;
 #[cfg(feature = "observability")]
 pub mod monitoring;
 
+pub mod output;
+
 pub mod progress;
 // pub mod thread_safe_db; // Removed - ThingsDatabase is now Send + Sync
 pub mod websocket;
 
 use crate::events::EventBroadcaster;
+use crate::output::{OutputFormat, PlainRenderer, TaskRenderer};
 use crate::websocket::WebSocketServer;
 use clap::{Parser, Subcommand};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use things3_core::{Result, ThingsDatabase};
+use things3_core::{DataExporter, ExportData, ExportFormat, Result, ThingsDatabase};
 
 #[derive(Parser, Debug)]
 #[command(name = "things3")]
@@ -60,6 +63,10 @@ pub struct Cli {
     #[arg(long, env = "THINGS_UNSAFE_DIRECT_DB")]
     pub unsafe_direct_db: bool,
 
+    /// Output format for inbox, today, projects, areas, and search
+    #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
+    pub format: OutputFormat,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -134,6 +141,24 @@ pub enum Commands {
     },
     /// Validate real-time features health
     Validate,
+    /// Show database statistics
+    Stats {
+        /// Emit JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Export all tasks, projects, and areas to a file
+    Export {
+        /// Export format (json, csv, opml, markdown, taskpaper, ical)
+        #[arg(long, short)]
+        format: ExportFormat,
+        /// Output file path; parent directories are created as needed
+        #[arg(long, short)]
+        output: PathBuf,
+        /// Overwrite the output file if it already exists
+        #[arg(long)]
+        force: bool,
+    },
     /// Bulk operations with progress tracking
     Bulk {
         #[command(subcommand)]
@@ -160,10 +185,15 @@ pub enum BulkOperation {
     SearchAndProcess {
         /// Search query
         query: String,
+        /// Tag every matching to-do with this tag (requires `--unsafe-direct-db`)
+        #[arg(long)]
+        tag: Option<String>,
     },
 }
 
-/// Print tasks to the given writer
+/// Print tasks to the given writer as a bulleted list
+///
+/// Other formats are available through [`OutputFormat::renderer`].
 ///
 /// # Examples
 ///
@@ -187,29 +217,12 @@ pub fn print_tasks<W: Write>(
     tasks: &[things3_core::Task],
     writer: &mut W,
 ) -> Result<()> {
-    if tasks.is_empty() {
-        writeln!(writer, "No tasks found")?;
-        return Ok(());
-    }
-
-    writeln!(writer, "Found {} tasks:", tasks.len())?;
-    for task in tasks {
-        writeln!(writer, "  • {} ({:?})", task.title, task.task_type)?;
-        if let Some(notes) = &task.notes {
-            writeln!(writer, "    Notes: {notes}")?;
-        }
-        if let Some(deadline) = &task.deadline {
-            writeln!(writer, "    Deadline: {deadline}")?;
-        }
-        if !task.tags.is_empty() {
-            writeln!(writer, "    Tags: {}", task.tags.join(", "))?;
-        }
-        writeln!(writer)?;
-    }
-    Ok(())
+    PlainRenderer.render_tasks(tasks, writer)
 }
 
-/// Print projects to the given writer
+/// Print projects to the given writer as a bulleted list
+///
+/// Other formats are available through [`OutputFormat::renderer`].
 ///
 /// # Examples
 ///
@@ -233,29 +246,12 @@ pub fn print_projects<W: Write>(
     projects: &[things3_core::Project],
     writer: &mut W,
 ) -> Result<()> {
-    if projects.is_empty() {
-        writeln!(writer, "No projects found")?;
-        return Ok(());
-    }
-
-    writeln!(writer, "Found {} projects:", projects.len())?;
-    for project in projects {
-        writeln!(writer, "  • {} ({:?})", project.title, project.status)?;
-        if let Some(notes) = &project.notes {
-            writeln!(writer, "    Notes: {notes}")?;
-        }
-        if let Some(deadline) = &project.deadline {
-            writeln!(writer, "    Deadline: {deadline}")?;
-        }
-        if !project.tags.is_empty() {
-            writeln!(writer, "    Tags: {}", project.tags.join(", "))?;
-        }
-        writeln!(writer)?;
-    }
-    Ok(())
+    PlainRenderer.render_projects(projects, writer)
 }
 
-/// Print areas to the given writer
+/// Print areas to the given writer as a bulleted list
+///
+/// Other formats are available through [`OutputFormat::renderer`].
 ///
 /// # Examples
 ///
@@ -279,21 +275,58 @@ pub fn print_areas<W: Write>(
     areas: &[things3_core::Area],
     writer: &mut W,
 ) -> Result<()> {
-    if areas.is_empty() {
-        writeln!(writer, "No areas found")?;
+    PlainRenderer.render_areas(areas, writer)
+}
+
+/// Print database statistics to the given writer
+///
+/// Shows the [`ThingsDatabase::get_stats`] totals alongside inbox, today,
+/// overdue, and completed-today counts, as an aligned table or, with `json`,
+/// a single JSON object keyed by metric.
+///
+/// # Examples
+///
+/// ```no_run
+/// use things3_cli::print_stats;
+/// use things3_core::ThingsDatabase;
+/// use std::io;
+///
+/// # async fn example() -> things3_core::Result<()> {
+/// let db = ThingsDatabase::new(std::path::Path::new("test.db")).await?;
+/// print_stats(&db, false, &mut io::stdout()).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+/// Returns an error if a database query or writing fails
+pub async fn print_stats<W: Write>(db: &ThingsDatabase, json: bool, writer: &mut W) -> Result<()> {
+    let stats = db.get_stats().await?;
+    let rows = [
+        ("tasks", "Tasks", stats.task_count),
+        ("projects", "Projects", stats.project_count),
+        ("areas", "Areas", stats.area_count),
+        ("inbox", "Inbox", db.get_inbox_count().await?),
+        ("today", "Today", db.get_today_count().await?),
+        ("overdue", "Overdue", db.get_overdue_count().await?),
+        (
+            "completed_today",
+            "Completed today",
+            db.get_completed_today_count().await?,
+        ),
+    ];
+
+    if json {
+        let object: serde_json::Map<String, serde_json::Value> = rows
+            .iter()
+            .map(|(key, _, count)| ((*key).to_string(), (*count).into()))
+            .collect();
+        writeln!(writer, "{}", serde_json::Value::Object(object))?;
         return Ok(());
     }
 
-    writeln!(writer, "Found {} areas:", areas.len())?;
-    for area in areas {
-        writeln!(writer, "  • {}", area.title)?;
-        if let Some(notes) = &area.notes {
-            writeln!(writer, "    Notes: {notes}")?;
-        }
-        if !area.tags.is_empty() {
-            writeln!(writer, "    Tags: {}", area.tags.join(", "))?;
-        }
-        writeln!(writer)?;
+    for (_, label, count) in rows {
+        writeln!(writer, "{label:<16}{count:>8}")?;
     }
     Ok(())
 }
@@ -337,6 +370,59 @@ pub async fn health_check(db: &ThingsDatabase) -> Result<()> {
     Ok(())
 }
 
+/// Export all tasks, projects, and areas to `output` in the given format
+///
+/// Parent directories are created if needed. An existing file is only
+/// replaced when `force` is set. Returns the number of bytes written.
+///
+/// # Examples
+///
+/// ```no_run
+/// use things3_cli::export_to_file;
+/// use things3_core::{ExportFormat, ThingsDatabase};
+/// use std::path::Path;
+///
+/// # async fn example() -> things3_core::Result<()> {
+/// let db = ThingsDatabase::new(Path::new("test.db")).await?;
+/// export_to_file(&db, ExportFormat::Json, Path::new("backup/things.json"), false).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+/// Returns an error if the file exists and `force` is not set, if the format
+/// is not enabled in this build, or if reading the database or writing fails
+pub async fn export_to_file(
+    db: &ThingsDatabase,
+    format: ExportFormat,
+    output: &Path,
+    force: bool,
+) -> Result<usize> {
+    if output.exists() && !force {
+        return Err(things3_core::ThingsError::validation(format!(
+            "Refusing to overwrite existing file {} (use --force)",
+            output.display()
+        )));
+    }
+
+    let tasks = db.get_all_tasks_excluding_trashed().await?;
+    let projects = db.get_all_projects().await?;
+    let areas = db.get_all_areas().await?;
+    let data = ExportData::new(tasks, projects, areas);
+
+    // Render before touching the filesystem so a disabled format leaves no file behind
+    let content = DataExporter::new_default()
+        .export(&data, format)
+        .map_err(|e| things3_core::ThingsError::unknown(e.to_string()))?;
+
+    if let Some(parent) = output.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(output, &content)?;
+
+    Ok(content.len())
+}
+
 // Temporarily disabled during SQLx migration
 // /// Start the MCP server
 // ///
@@ -7,7 +7,7 @@ use std::sync::Arc;
 use things3_cli::mcp::start_mcp_server;
 #[cfg(all(feature = "mcp-server", feature = "observability"))]
 use things3_cli::mcp::start_mcp_server_with_config;
-use things3_cli::{start_websocket_server, watch_updates, Cli, Commands};
+use things3_cli::{start_websocket_server, watch_updates, BulkOperation, Cli, Commands};
 use things3_core::{Result, ThingsConfig, ThingsDatabase};
 
 #[cfg(all(feature = "mcp-server", feature = "observability"))]
@@ -84,16 +84,19 @@ This flag exists for emergency recovery only and will be removed.
         ThingsConfig::from_env()
     };
 
-    // Create database connection
-    let db = ThingsDatabase::new(&config.database_path).await?;
+    // Create database connection, falling back to the default path if allowed
+    let db = ThingsDatabase::new(&config.get_effective_database_path()?)
+        .await?
+        .with_timezone(config.timezone);
     let db = Arc::new(db);
+    let renderer = cli.format.renderer();
+    let mut stdout = std::io::stdout();
 
     match cli.command {
         Commands::Inbox { limit } => {
             info!("Fetching inbox tasks...");
             let tasks = db.get_inbox(limit).await?;
-            let json = serde_json::to_string_pretty(&tasks)?;
-            println!("{json}");
+            renderer.render_tasks(&tasks, &mut stdout)?;
         }
         Commands::Today { limit: _ } => {
             error!("Today command is temporarily disabled during SQLx migration");
@@ -106,14 +109,12 @@ This flag exists for emergency recovery only and will be removed.
         Commands::Projects { area: _, limit } => {
             info!("Fetching projects...");
             let projects = db.get_projects(limit).await?;
-            let json = serde_json::to_string_pretty(&projects)?;
-            println!("{json}");
+            renderer.render_projects(&projects, &mut stdout)?;
         }
         Commands::Areas { limit: _ } => {
             info!("Fetching areas...");
             let areas = db.get_areas().await?;
-            let json = serde_json::to_string_pretty(&areas)?;
-            println!("{json}");
+            renderer.render_areas(&areas, &mut stdout)?;
         }
         Commands::Search { query, limit } => {
             let tasks = db.search_tasks(&query).await?;
@@ -122,7 +123,7 @@ This flag exists for emergency recovery only and will be removed.
             } else {
                 tasks
             };
-            println!("{}", serde_json::to_string_pretty(&limited_tasks)?);
+            renderer.render_tasks(&limited_tasks, &mut stdout)?;
         }
         #[cfg(feature = "mcp-server")]
         Commands::Mcp => {
@@ -182,6 +183,34 @@ This flag exists for emergency recovery only and will be removed.
             // TODO: Implement validation logic
             println!("✅ Real-time features validation completed");
         }
+        Commands::Stats { json } => {
+            things3_cli::print_stats(&db, json, &mut stdout).await?;
+        }
+        Commands::Export {
+            format,
+            output,
+            force,
+        } => {
+            info!("Exporting to {}", output.display());
+            let bytes = things3_cli::export_to_file(&db, format, &output, force).await?;
+            println!("✅ Exported {bytes} bytes to {}", output.display());
+        }
+        Commands::Bulk {
+            operation:
+                BulkOperation::SearchAndProcess {
+                    query,
+                    tag: Some(tag),
+                },
+        } => {
+            if !cli.unsafe_direct_db {
+                return Err(things3_core::ThingsError::validation(
+                    "Tagging search results writes to the database directly; pass --unsafe-direct-db",
+                ));
+            }
+            info!("Tagging tasks matching {:?} with '{}'", query, tag);
+            let count = db.tag_search_results(&query, &tag).await?;
+            println!("🏷️  Tagged {count} task(s) matching '{query}' with '{tag}'");
+        }
         Commands::Bulk { operation: _ } => {
             error!("Bulk operations are temporarily disabled during SQLx migration");
             println!("🚧 Bulk operations are temporarily disabled");
@@ -598,7 +627,8 @@ mod tests {
         let cli = Cli::parse_from(["things3", "bulk", "search-and-process", "test"]);
         match cli.command {
             Commands::Bulk { operation } => match operation {
-                BulkOperation::SearchAndProcess { query } => {
+                BulkOperation::SearchAndProcess { query, tag } => {
+                    assert_eq!(tag, None);
                     assert_eq!(query, "test");
                 }
                 _ => panic!("Expected SearchAndProcess operation"),
@@ -607,12 +637,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_main_bulk_search_and_process_command_with_tag() {
+        let cli = Cli::parse_from([
+            "things3",
+            "bulk",
+            "search-and-process",
+            "Q3",
+            "--tag",
+            "quarterly",
+        ]);
+        match cli.command {
+            Commands::Bulk { operation } => match operation {
+                BulkOperation::SearchAndProcess { query, tag } => {
+                    assert_eq!(query, "Q3");
+                    assert_eq!(tag.as_deref(), Some("quarterly"));
+                }
+                _ => panic!("Expected SearchAndProcess operation"),
+            },
+            _ => panic!("Expected Bulk command"),
+        }
+    }
+
     #[test]
     fn test_main_bulk_search_and_process_command_default_limit() {
         let cli = Cli::parse_from(["things3", "bulk", "search-and-process", "test"]);
         match cli.command {
             Commands::Bulk { operation } => match operation {
-                BulkOperation::SearchAndProcess { query } => {
+                BulkOperation::SearchAndProcess { query, tag } => {
+                    assert_eq!(tag, None);
                     assert_eq!(query, "test");
                 }
                 _ => panic!("Expected SearchAndProcess operation"),
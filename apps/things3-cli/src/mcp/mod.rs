@@ -422,6 +422,10 @@ impl From<ThingsError> for McpError {
             ThingsError::AppleScript { message } => {
                 McpError::internal_error(format!("AppleScript automation failed: {message}"))
             }
+            ThingsError::Timeout { operation, elapsed } => McpError::database_operation_failed(
+                operation,
+                ThingsError::Timeout { operation, elapsed },
+            ),
             ThingsError::Unknown { message } => McpError::internal_error(message),
         }
     }
@@ -603,6 +607,9 @@ fn build_jsonrpc_error_response(
 #[allow(dead_code)]
 /// Start the MCP server
 ///
+/// Serves stdin/stdout until stdin reaches EOF (the client closed the pipe) or
+/// the process receives Ctrl-C / `SIGTERM`, then closes the database pool.
+///
 /// # Errors
 /// Returns an error if the server fails to start
 pub async fn start_mcp_server(
@@ -611,84 +618,51 @@ pub async fn start_mcp_server(
     unsafe_direct_db: bool,
 ) -> things3_core::Result<()> {
     let io = StdIo::new();
-    start_mcp_server_generic(db, config, io, unsafe_direct_db).await
+    start_mcp_server_generic_with_shutdown(db, config, io, unsafe_direct_db, shutdown_signal())
+        .await
 }
 
 /// Generic MCP server implementation that works with any I/O implementation
 ///
 /// This function is generic over the I/O layer, allowing it to work with both
 /// production stdin/stdout (via `StdIo`) and test mocks (via `MockIo`).
+/// It runs until the input reaches EOF.
 pub async fn start_mcp_server_generic<I: McpIo>(
     db: Arc<ThingsDatabase>,
     config: ThingsConfig,
-    mut io: I,
+    io: I,
     unsafe_direct_db: bool,
 ) -> things3_core::Result<()> {
-    let server = Arc::new(tokio::sync::Mutex::new(ThingsMcpServer::new(
-        db,
-        config,
-        unsafe_direct_db,
-    )));
-
-    // Read JSON-RPC requests line by line
-    loop {
-        // Read a line from input
-        let line = io.read_line().await.map_err(|e| {
-            things3_core::ThingsError::unknown(format!("Failed to read from input: {}", e))
-        })?;
-
-        // EOF reached
-        let Some(line) = line else {
-            break;
-        };
-
-        // Skip empty lines
-        if line.is_empty() {
-            continue;
-        }
-
-        // Parse JSON-RPC request
-        let request: serde_json::Value = serde_json::from_str(&line).map_err(|e| {
-            things3_core::ThingsError::unknown(format!("Failed to parse JSON-RPC request: {}", e))
-        })?;
-
-        // Handle the request. If the handler errors we MUST NOT propagate with
-        // `?` — that terminates the loop and drops the MCP connection (#148).
-        // Convert handler errors into JSON-RPC error responses instead.
-        // Extract `id` before consuming `request` so we can use it in the error
-        // path without cloning the entire request value on every hot-path call.
-        let request_id = request.get("id").cloned();
-        let server_clone = Arc::clone(&server);
-        let response_opt = {
-            let server = server_clone.lock().await;
-            match server.handle_jsonrpc_request(request).await {
-                Ok(opt) => opt,
-                Err(e) => build_jsonrpc_error_response(request_id, &e),
-            }
-        };
-
-        // Only write response if this is a request (not a notification)
-        if let Some(response) = response_opt {
-            let response_str = serde_json::to_string(&response).map_err(|e| {
-                things3_core::ThingsError::unknown(format!("Failed to serialize response: {}", e))
-            })?;
-
-            io.write_line(&response_str).await.map_err(|e| {
-                things3_core::ThingsError::unknown(format!("Failed to write response: {}", e))
-            })?;
-
-            io.flush().await.map_err(|e| {
-                things3_core::ThingsError::unknown(format!("Failed to flush output: {}", e))
-            })?;
-        }
-        // Notifications don't require a response, so we silently continue
-    }
+    start_mcp_server_generic_with_shutdown(db, config, io, unsafe_direct_db, std::future::pending())
+        .await
+}
 
-    Ok(())
+/// Like [`start_mcp_server_generic`], but also stops when `shutdown` completes
+///
+/// A request that is already being handled finishes first; the server then
+/// stops reading input and closes the database pool.
+///
+/// # Errors
+/// Returns an error if reading input, parsing a request, or writing a response fails
+pub async fn start_mcp_server_generic_with_shutdown<I, F>(
+    db: Arc<ThingsDatabase>,
+    config: ThingsConfig,
+    io: I,
+    unsafe_direct_db: bool,
+    shutdown: F,
+) -> things3_core::Result<()>
+where
+    I: McpIo,
+    F: std::future::Future<Output = ()>,
+{
+    let server = ThingsMcpServer::new(Arc::clone(&db), config, unsafe_direct_db);
+    serve(server, db, io, shutdown).await
 }
 
 /// Start the MCP server with comprehensive configuration
 ///
+/// Like [`start_mcp_server`], stops on stdin EOF, Ctrl-C or `SIGTERM`.
+///
 /// # Arguments
 /// * `db` - Database connection
 /// * `mcp_config` - MCP server configuration
@@ -700,35 +674,99 @@ pub async fn start_mcp_server_with_config(
     mcp_config: McpServerConfig,
     unsafe_direct_db: bool,
 ) -> things3_core::Result<()> {
-    let io = StdIo::new();
-    start_mcp_server_with_config_generic(db, mcp_config, io, unsafe_direct_db).await
+    let server = new_server_with_mcp_config(Arc::clone(&db), mcp_config, unsafe_direct_db);
+    serve(server, db, StdIo::new(), shutdown_signal()).await
 }
 
 /// Generic MCP server with config implementation that works with any I/O implementation
 pub async fn start_mcp_server_with_config_generic<I: McpIo>(
     db: Arc<ThingsDatabase>,
     mcp_config: McpServerConfig,
-    mut io: I,
+    io: I,
     unsafe_direct_db: bool,
 ) -> things3_core::Result<()> {
+    let server = new_server_with_mcp_config(Arc::clone(&db), mcp_config, unsafe_direct_db);
+    serve(server, db, io, std::future::pending()).await
+}
+
+fn new_server_with_mcp_config(
+    db: Arc<ThingsDatabase>,
+    mcp_config: McpServerConfig,
+    unsafe_direct_db: bool,
+) -> ThingsMcpServer {
     // Convert McpServerConfig to ThingsConfig for backward compatibility
     let things_config = ThingsConfig::new(
         mcp_config.database.path.clone(),
         mcp_config.database.fallback_to_default,
     );
+    ThingsMcpServer::new_with_mcp_config(db, things_config, mcp_config, unsafe_direct_db)
+}
 
-    let server = Arc::new(tokio::sync::Mutex::new(
-        ThingsMcpServer::new_with_mcp_config(db, things_config, mcp_config, unsafe_direct_db),
-    ));
+/// Resolves on Ctrl-C, or on `SIGTERM` where Unix signals are available
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        // If the handler can't be installed, never resolve rather than shutting down at once
+        if tokio::signal::ctrl_c().await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {}
+        () = terminate => {}
+    }
+}
+
+/// Read JSON-RPC requests line by line until EOF or `shutdown`, then close the pool
+async fn serve<I, F>(
+    server: ThingsMcpServer,
+    db: Arc<ThingsDatabase>,
+    mut io: I,
+    shutdown: F,
+) -> things3_core::Result<()>
+where
+    I: McpIo,
+    F: std::future::Future<Output = ()>,
+{
+    let result = serve_until(server, &mut io, shutdown).await;
+    db.pool().close().await;
+    result
+}
+
+async fn serve_until<I, F>(
+    server: ThingsMcpServer,
+    io: &mut I,
+    shutdown: F,
+) -> things3_core::Result<()>
+where
+    I: McpIo,
+    F: std::future::Future<Output = ()>,
+{
+    let server = Arc::new(tokio::sync::Mutex::new(server));
+    tokio::pin!(shutdown);
 
-    // Read JSON-RPC requests line by line
     loop {
-        // Read a line from input
-        let line = io.read_line().await.map_err(|e| {
-            things3_core::ThingsError::unknown(format!("Failed to read from input: {}", e))
-        })?;
+        // Read a line from input, unless asked to stop first
+        let line = tokio::select! {
+            () = &mut shutdown => break,
+            line = io.read_line() => line.map_err(|e| {
+                things3_core::ThingsError::unknown(format!("Failed to read from input: {}", e))
+            })?,
+        };
 
-        // EOF reached
+        // EOF reached: the client closed its end of the pipe
         let Some(line) = line else {
             break;
         };
@@ -743,9 +781,11 @@ pub async fn start_mcp_server_with_config_generic<I: McpIo>(
             things3_core::ThingsError::unknown(format!("Failed to parse JSON-RPC request: {}", e))
         })?;
 
-        // Handle the request. See note in `start_mcp_server_generic` — handler
-        // errors are converted to JSON-RPC error responses instead of being
-        // propagated with `?`, which would terminate the loop (#148).
+        // Handle the request. If the handler errors we MUST NOT propagate with
+        // `?` — that terminates the loop and drops the MCP connection (#148).
+        // Convert handler errors into JSON-RPC error responses instead.
+        // Extract `id` before consuming `request` so we can use it in the error
+        // path without cloning the entire request value on every hot-path call.
         let request_id = request.get("id").cloned();
         let server_clone = Arc::clone(&server);
         let response_opt = {
@@ -1172,6 +1212,10 @@ impl ThingsMcpServer {
                         "limit": {
                             "type": "integer",
                             "description": "Maximum number of tasks to return"
+                        },
+                        "include_canceled": {
+                            "type": "boolean",
+                            "description": "Include canceled tasks in results (default: false)"
                         }
                     },
                     "required": ["query"]
@@ -1179,13 +1223,13 @@ impl ThingsMcpServer {
             },
             Tool {
                 name: "get_recent_tasks".to_string(),
-                description: "Get recently created or modified tasks".to_string(),
+                description: "Get recently created tasks, newest first".to_string(),
                 input_schema: serde_json::json!({
                     "type": "object",
                     "properties": {
                         "limit": {
                             "type": "integer",
-                            "description": "Maximum number of tasks to return"
+                            "description": "Maximum number of tasks to return (default: 50)"
                         },
                         "hours": {
                             "type": "integer",
@@ -1233,8 +1277,11 @@ impl ThingsMcpServer {
                             "type": "integer",
                             "default": 50,
                             "minimum": 1,
-                            "maximum": 500,
-                            "description": "Maximum number of results to return (default: 50, max: 500)"
+                            "maximum": ThingsDatabase::MAX_QUERY_LIMIT,
+                            "description": format!(
+                                "Maximum number of results to return (default: 50, max: {})",
+                                ThingsDatabase::MAX_QUERY_LIMIT
+                            )
                         },
                         "offset": {
                             "type": "integer",
@@ -1310,7 +1357,8 @@ impl ThingsMcpServer {
             },
             Tool {
                 name: "update_task".to_string(),
-                description: "Update an existing task (only provided fields will be updated)"
+                description: "Update an existing task as a JSON merge patch: omitted fields are \
+                              unchanged, and null clears notes, dates, project, or area"
                     .to_string(),
                 input_schema: serde_json::json!({
                     "type": "object",
@@ -1325,18 +1373,18 @@ impl ThingsMcpServer {
                             "description": "New task title"
                         },
                         "notes": {
-                            "type": "string",
-                            "description": "New task notes"
+                            "type": ["string", "null"],
+                            "description": "New task notes (null clears)"
                         },
                         "start_date": {
-                            "type": "string",
+                            "type": ["string", "null"],
                             "format": "date",
-                            "description": "New start date (YYYY-MM-DD)"
+                            "description": "New start date (YYYY-MM-DD, null clears)"
                         },
                         "deadline": {
-                            "type": "string",
+                            "type": ["string", "null"],
                             "format": "date",
-                            "description": "New deadline (YYYY-MM-DD)"
+                            "description": "New deadline (YYYY-MM-DD, null clears)"
                         },
                         "status": {
                             "type": "string",
@@ -1344,14 +1392,14 @@ impl ThingsMcpServer {
                             "description": "New task status"
                         },
                         "project_uuid": {
-                            "type": "string",
+                            "type": ["string", "null"],
                             "format": "uuid",
-                            "description": "New project UUID"
+                            "description": "New project UUID (null removes from project)"
                         },
                         "area_uuid": {
-                            "type": "string",
+                            "type": ["string", "null"],
                             "format": "uuid",
-                            "description": "New area UUID"
+                            "description": "New area UUID (null removes from area)"
                         },
                         "tags": {
                             "type": "array",
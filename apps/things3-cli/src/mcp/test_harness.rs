@@ -351,10 +351,14 @@ impl McpTestHarness {
                 project TEXT,
                 area TEXT,
                 heading TEXT,
+                contact TEXT,
                 trashed INTEGER NOT NULL DEFAULT 0,
                 tags TEXT DEFAULT '[]',
                 cachedTags BLOB,
-                todayIndex INTEGER
+                todayIndex INTEGER,
+                start INTEGER,
+                startBucket INTEGER,
+                'index' INTEGER
             )
             ",
         )
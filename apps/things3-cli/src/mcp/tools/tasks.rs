@@ -62,9 +62,14 @@ impl ThingsMcpServer {
             .and_then(serde_json::Value::as_u64)
             .map(|v| usize::try_from(v).unwrap_or(usize::MAX));
 
+        let include_canceled = args
+            .get("include_canceled")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
         let tasks = self
             .db
-            .search_tasks(query)
+            .search_tasks_with_options(query, include_canceled)
             .await
             .map_err(|e| McpError::database_operation_failed("search_tasks", e))?;
 
@@ -606,24 +611,30 @@ impl ThingsMcpServer {
         let limit = args
             .get("limit")
             .and_then(serde_json::Value::as_u64)
-            .map(|v| usize::try_from(v).unwrap_or(usize::MAX));
+            .map_or(50, |v| usize::try_from(v).unwrap_or(usize::MAX));
         let hours = i64::try_from(
             args.get("hours")
                 .and_then(serde_json::Value::as_u64)
                 .unwrap_or(24),
         )
-        .unwrap_or(24);
+        .unwrap_or(i64::MAX);
 
-        // For now, return inbox tasks as a proxy for recent tasks
-        // In a real implementation, this would query by creation/modification date
-        let tasks = self
+        let mut tasks = self
             .db
-            .get_inbox(limit)
+            .get_recent_tasks(limit)
             .await
             .map_err(|e| McpError::database_operation_failed("get_recent_tasks", e))?;
 
+        // Results are newest first, so dropping older ones keeps the limit semantics.
+        // A lookback too large to represent reaches past every task, so nothing is dropped.
+        let cutoff = chrono::Duration::try_hours(hours)
+            .and_then(|lookback| chrono::Utc::now().checked_sub_signed(lookback));
+        if let Some(cutoff) = cutoff {
+            tasks.retain(|task| task.created >= cutoff);
+        }
+
         let response = serde_json::json!({
-            "message": "Recent tasks (using inbox as proxy)",
+            "message": "Recently created tasks",
             "hours_lookback": hours,
             "tasks": tasks
         });
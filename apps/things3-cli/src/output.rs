@@ -0,0 +1,279 @@
+//! Output formats for the task, project, and area listing commands
+
+use clap::ValueEnum;
+use std::io::Write;
+use things3_core::{Area, Project, Result, Task};
+
+/// Output format selected with `--format`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned columns, one row per item
+    Table,
+    /// Bulleted list with notes, deadline, and tags underneath each item
+    Plain,
+    /// Pretty-printed JSON array
+    #[default]
+    Json,
+}
+
+impl OutputFormat {
+    /// The renderer implementing this format
+    #[must_use]
+    pub fn renderer(self) -> Box<dyn TaskRenderer> {
+        match self {
+            Self::Table => Box::new(TableRenderer),
+            Self::Plain => Box::new(PlainRenderer),
+            Self::Json => Box::new(JsonRenderer),
+        }
+    }
+}
+
+/// Writes lists of tasks, projects, and areas in one output format
+///
+/// Implement this to add a format without touching the `print_*` functions.
+pub trait TaskRenderer {
+    /// Write `tasks` to `writer`
+    ///
+    /// # Errors
+    /// Returns an error if serialization or writing fails
+    fn render_tasks(&self, tasks: &[Task], writer: &mut dyn Write) -> Result<()>;
+
+    /// Write `projects` to `writer`
+    ///
+    /// # Errors
+    /// Returns an error if serialization or writing fails
+    fn render_projects(&self, projects: &[Project], writer: &mut dyn Write) -> Result<()>;
+
+    /// Write `areas` to `writer`
+    ///
+    /// # Errors
+    /// Returns an error if serialization or writing fails
+    fn render_areas(&self, areas: &[Area], writer: &mut dyn Write) -> Result<()>;
+}
+
+/// Bulleted list, the format used by [`crate::print_tasks`] and friends
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlainRenderer;
+
+impl TaskRenderer for PlainRenderer {
+    fn render_tasks(&self, tasks: &[Task], writer: &mut dyn Write) -> Result<()> {
+        if tasks.is_empty() {
+            writeln!(writer, "No tasks found")?;
+            return Ok(());
+        }
+
+        writeln!(writer, "Found {} tasks:", tasks.len())?;
+        for task in tasks {
+            writeln!(writer, "  • {} ({:?})", task.title, task.task_type)?;
+            if let Some(notes) = &task.notes {
+                writeln!(writer, "    Notes: {notes}")?;
+            }
+            if let Some(deadline) = &task.deadline {
+                writeln!(writer, "    Deadline: {deadline}")?;
+            }
+            if !task.tags.is_empty() {
+                writeln!(writer, "    Tags: {}", task.tags.join(", "))?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    fn render_projects(&self, projects: &[Project], writer: &mut dyn Write) -> Result<()> {
+        if projects.is_empty() {
+            writeln!(writer, "No projects found")?;
+            return Ok(());
+        }
+
+        writeln!(writer, "Found {} projects:", projects.len())?;
+        for project in projects {
+            writeln!(writer, "  • {} ({:?})", project.title, project.status)?;
+            if let Some(notes) = &project.notes {
+                writeln!(writer, "    Notes: {notes}")?;
+            }
+            if let Some(deadline) = &project.deadline {
+                writeln!(writer, "    Deadline: {deadline}")?;
+            }
+            if !project.tags.is_empty() {
+                writeln!(writer, "    Tags: {}", project.tags.join(", "))?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    fn render_areas(&self, areas: &[Area], writer: &mut dyn Write) -> Result<()> {
+        if areas.is_empty() {
+            writeln!(writer, "No areas found")?;
+            return Ok(());
+        }
+
+        writeln!(writer, "Found {} areas:", areas.len())?;
+        for area in areas {
+            writeln!(writer, "  • {}", area.title)?;
+            if let Some(notes) = &area.notes {
+                writeln!(writer, "    Notes: {notes}")?;
+            }
+            if !area.tags.is_empty() {
+                writeln!(writer, "    Tags: {}", area.tags.join(", "))?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Aligned columns with a header row
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TableRenderer;
+
+impl TaskRenderer for TableRenderer {
+    fn render_tasks(&self, tasks: &[Task], writer: &mut dyn Write) -> Result<()> {
+        let rows = tasks
+            .iter()
+            .map(|t| {
+                vec![
+                    t.title.clone(),
+                    format!("{:?}", t.task_type),
+                    format!("{:?}", t.status),
+                    t.deadline.map(|d| d.to_string()).unwrap_or_default(),
+                    t.tags.join(", "),
+                ]
+            })
+            .collect::<Vec<_>>();
+        write_table(
+            writer,
+            &["TITLE", "TYPE", "STATUS", "DEADLINE", "TAGS"],
+            &rows,
+        )
+    }
+
+    fn render_projects(&self, projects: &[Project], writer: &mut dyn Write) -> Result<()> {
+        let rows = projects
+            .iter()
+            .map(|p| {
+                vec![
+                    p.title.clone(),
+                    format!("{:?}", p.status),
+                    p.deadline.map(|d| d.to_string()).unwrap_or_default(),
+                    p.tags.join(", "),
+                ]
+            })
+            .collect::<Vec<_>>();
+        write_table(writer, &["TITLE", "STATUS", "DEADLINE", "TAGS"], &rows)
+    }
+
+    fn render_areas(&self, areas: &[Area], writer: &mut dyn Write) -> Result<()> {
+        let rows = areas
+            .iter()
+            .map(|a| vec![a.title.clone(), a.tags.join(", ")])
+            .collect::<Vec<_>>();
+        write_table(writer, &["TITLE", "TAGS"], &rows)
+    }
+}
+
+/// Write `rows` under `headers`, padding every column but the last to its widest cell
+fn write_table(writer: &mut dyn Write, headers: &[&str], rows: &[Vec<String>]) -> Result<()> {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let header_row: Vec<String> = headers.iter().map(|h| (*h).to_string()).collect();
+    for row in std::iter::once(&header_row).chain(rows) {
+        let last = row.len().saturating_sub(1);
+        let mut line = String::new();
+        for (i, (cell, width)) in row.iter().zip(&widths).enumerate() {
+            if i == last {
+                line.push_str(cell);
+            } else {
+                line.push_str(&format!("{cell:<width$}  "));
+            }
+        }
+        writeln!(writer, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+/// The listed items serialized as a pretty-printed JSON array
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonRenderer;
+
+impl TaskRenderer for JsonRenderer {
+    fn render_tasks(&self, tasks: &[Task], writer: &mut dyn Write) -> Result<()> {
+        write_json(writer, tasks)
+    }
+
+    fn render_projects(&self, projects: &[Project], writer: &mut dyn Write) -> Result<()> {
+        write_json(writer, projects)
+    }
+
+    fn render_areas(&self, areas: &[Area], writer: &mut dyn Write) -> Result<()> {
+        write_json(writer, areas)
+    }
+}
+
+fn write_json<T: serde::Serialize>(writer: &mut dyn Write, items: &[T]) -> Result<()> {
+    serde_json::to_writer_pretty(&mut *writer, items)?;
+    writeln!(writer)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use things3_core::test_utils::{create_mock_areas, create_mock_tasks};
+
+    fn render(format: OutputFormat, tasks: &[Task]) -> String {
+        let mut output = Vec::new();
+        format.renderer().render_tasks(tasks, &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_json_renderer_round_trips() {
+        let tasks = create_mock_tasks();
+        let output = render(OutputFormat::Json, &tasks);
+        let parsed: Vec<Task> = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed.len(), tasks.len());
+        assert_eq!(parsed[0].uuid, tasks[0].uuid);
+    }
+
+    #[test]
+    fn test_table_renderer_aligns_columns() {
+        let tasks = create_mock_tasks();
+        let output = render(OutputFormat::Table, &tasks);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), tasks.len() + 1);
+        assert!(lines[0].starts_with("TITLE"));
+
+        // Every row's TYPE column starts at the same offset as the header's
+        let type_col = lines[0].find("TYPE").unwrap();
+        let widest = tasks.iter().map(|t| t.title.chars().count()).max().unwrap();
+        assert_eq!(type_col, widest.max("TITLE".len()) + 2);
+        for (line, task) in lines[1..].iter().zip(&tasks) {
+            let rest: String = line.chars().skip(type_col).collect();
+            assert!(rest.starts_with(&format!("{:?}", task.task_type)), "{line}");
+        }
+    }
+
+    #[test]
+    fn test_table_renderer_empty_prints_header_only() {
+        let mut output = Vec::new();
+        TableRenderer
+            .render_areas(&create_mock_areas()[..0], &mut output)
+            .unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "TITLE  TAGS\n");
+    }
+
+    #[test]
+    fn test_plain_renderer_uses_bullets() {
+        let tasks = create_mock_tasks();
+        let output = render(OutputFormat::Plain, &tasks);
+        assert!(output.starts_with(&format!("Found {} tasks:", tasks.len())));
+        assert!(output.contains(&format!("  • {}", tasks[0].title)));
+        assert_eq!(render(OutputFormat::Plain, &[]), "No tasks found\n");
+    }
+}
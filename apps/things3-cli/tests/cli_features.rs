@@ -63,6 +63,60 @@ fn test_bulk_export_command() {
     assert!(cli.is_ok(), "Bulk export command should parse");
 }
 
+#[test]
+fn test_output_format_flag() {
+    use things3_cli::output::OutputFormat;
+
+    let cli = Cli::try_parse_from(["things3", "inbox"]).unwrap();
+    assert_eq!(cli.format, OutputFormat::Json);
+
+    for (arg, format) in [
+        ("table", OutputFormat::Table),
+        ("plain", OutputFormat::Plain),
+        ("json", OutputFormat::Json),
+    ] {
+        let cli = Cli::try_parse_from(["things3", "--format", arg, "projects"]).unwrap();
+        assert_eq!(cli.format, format);
+    }
+
+    assert!(Cli::try_parse_from(["things3", "--format", "xml", "inbox"]).is_err());
+}
+
+#[test]
+fn test_stats_command() {
+    let cli = Cli::try_parse_from(["things3", "stats", "--json"]).unwrap();
+    assert_eq!(cli.command, Commands::Stats { json: true });
+
+    let cli = Cli::try_parse_from(["things3", "stats"]).unwrap();
+    assert_eq!(cli.command, Commands::Stats { json: false });
+}
+
+#[test]
+fn test_export_command() {
+    let args = vec![
+        "things3", "export", "--format", "md", "--output", "out.md", "--force",
+    ];
+    let cli = Cli::try_parse_from(args).unwrap();
+
+    if let Commands::Export {
+        format,
+        output,
+        force,
+    } = cli.command
+    {
+        assert_eq!(format, things3_core::ExportFormat::Markdown);
+        assert_eq!(output, std::path::PathBuf::from("out.md"));
+        assert!(force);
+    } else {
+        panic!("Expected Export command");
+    }
+
+    let args = vec![
+        "things3", "export", "--format", "xml", "--output", "out.xml",
+    ];
+    assert!(Cli::try_parse_from(args).is_err());
+}
+
 #[cfg(feature = "export-csv")]
 #[test]
 fn test_csv_export_via_bulk() {
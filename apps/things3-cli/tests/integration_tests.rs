@@ -3,7 +3,7 @@
 use std::io::Cursor;
 use tempfile::NamedTempFile;
 use things3_core::{
-    config::ThingsConfig, database::ThingsDatabase, test_utils::create_test_database,
+    config::ThingsConfig, database::ThingsDatabase, test_utils::create_test_database, ExportFormat,
 };
 
 /// Test the `print_tasks` function with various inputs
@@ -106,6 +106,67 @@ async fn test_health_check_integration() {
     }
 }
 
+/// Test the `print_stats` function in table and JSON modes
+#[tokio::test]
+async fn test_print_stats_integration() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let mut output = Cursor::new(Vec::new());
+    things3_cli::print_stats(&db, false, &mut output)
+        .await
+        .unwrap();
+    let table = String::from_utf8(output.into_inner()).unwrap();
+    assert!(table
+        .lines()
+        .any(|line| line.starts_with("Completed today")));
+    assert_eq!(table.lines().count(), 7);
+
+    let mut output = Cursor::new(Vec::new());
+    things3_cli::print_stats(&db, true, &mut output)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.into_inner()).unwrap();
+    let stats = db.get_stats().await.unwrap();
+    assert_eq!(json["tasks"], stats.task_count);
+    assert_eq!(json["inbox"], db.get_inbox_count().await.unwrap());
+    assert!(json["overdue"].is_u64());
+}
+
+/// Test the `export_to_file` function's directory creation and overwrite guard
+#[tokio::test]
+async fn test_export_to_file_integration() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("nested").join("things.json");
+
+    let bytes = things3_cli::export_to_file(&db, ExportFormat::Json, &output, false)
+        .await
+        .unwrap();
+    let written = std::fs::read_to_string(&output).unwrap();
+    assert_eq!(bytes, written.len());
+    let json: serde_json::Value = serde_json::from_str(&written).unwrap();
+    let tasks = db.get_all_tasks_excluding_trashed().await.unwrap();
+    assert!(!tasks.is_empty());
+    assert_eq!(json["tasks"].as_array().unwrap().len(), tasks.len());
+
+    // Existing files are only replaced with force
+    let result = things3_cli::export_to_file(&db, ExportFormat::Markdown, &output, false).await;
+    assert!(result.is_err());
+    assert_eq!(std::fs::read_to_string(&output).unwrap(), written);
+
+    things3_cli::export_to_file(&db, ExportFormat::Markdown, &output, true)
+        .await
+        .unwrap();
+    assert_ne!(std::fs::read_to_string(&output).unwrap(), written);
+}
+
 /// Test MCP server creation and basic functionality
 #[tokio::test]
 #[cfg(feature = "mcp-server")]
@@ -1007,6 +1007,67 @@ async fn test_notification_no_response() {
     assert_eq!(response["id"], 13);
 }
 
+// ============================================================================
+// Shutdown Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_eof_stops_server_and_closes_pool() {
+    let (_temp, db) = create_test_db().await;
+    let config = ThingsConfig::default();
+
+    let (server_io, client_io) = MockIo::create_pair(4096);
+
+    let server_db = Arc::clone(&db);
+    let server_handle =
+        tokio::spawn(
+            async move { start_mcp_server_generic(server_db, config, server_io, true).await },
+        );
+
+    // Closing the pipe is how a client like Claude Desktop says goodbye
+    drop(client_io);
+
+    let result = timeout(Duration::from_secs(2), server_handle)
+        .await
+        .expect("server should stop on EOF");
+    assert!(result.unwrap().is_ok());
+    assert!(db.pool().is_closed());
+}
+
+#[tokio::test]
+async fn test_shutdown_future_stops_server_with_pipe_open() {
+    use things3_cli::mcp::start_mcp_server_generic_with_shutdown;
+
+    let (_temp, db) = create_test_db().await;
+    let config = ThingsConfig::default();
+
+    let (server_io, mut client_io) = MockIo::create_pair(4096);
+    let (stop_tx, stop_rx) = tokio::sync::oneshot::channel::<()>();
+
+    let server_db = Arc::clone(&db);
+    let server_handle = tokio::spawn(async move {
+        start_mcp_server_generic_with_shutdown(server_db, config, server_io, true, async {
+            let _ = stop_rx.await;
+        })
+        .await
+    });
+
+    // The server keeps answering until the shutdown future resolves
+    let response = send_request_read_response(
+        &mut client_io,
+        json!({"jsonrpc": "2.0", "id": 1, "method": "tools/list"}),
+    )
+    .await;
+    assert_eq!(response["id"], 1);
+
+    stop_tx.send(()).unwrap();
+    let result = timeout(Duration::from_secs(2), server_handle)
+        .await
+        .expect("server should stop on shutdown signal");
+    assert!(result.unwrap().is_ok());
+    assert!(db.pool().is_closed());
+}
+
 // ============================================================================
 // start_mcp_server_with_config_generic Tests
 // ============================================================================
@@ -452,6 +452,22 @@ async fn test_logbook_search_with_limit() {
     assert_eq!(tasks.len(), 5, "Should return exactly 5 results");
 }
 
+#[tokio::test]
+async fn test_logbook_search_limit_schema_matches_query_cap() {
+    let harness = create_harness();
+    let tools = harness.server().list_tools().unwrap();
+    let tool = tools
+        .tools
+        .iter()
+        .find(|t| t.name == "logbook_search")
+        .unwrap();
+
+    assert_eq!(
+        tool.input_schema["properties"]["limit"]["maximum"],
+        json!(things3_core::ThingsDatabase::MAX_QUERY_LIMIT)
+    );
+}
+
 #[tokio::test]
 async fn test_logbook_search_combined_filters() {
     let harness = create_harness();
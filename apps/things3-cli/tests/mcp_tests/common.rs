@@ -42,10 +42,14 @@ async fn create_test_schema(db: &ThingsDatabase) -> Result<(), Box<dyn std::erro
             project TEXT,
             area TEXT,
             heading TEXT,
+            contact TEXT,
             trashed INTEGER NOT NULL DEFAULT 0,
             tags TEXT DEFAULT '[]',
             cachedTags BLOB,
-            todayIndex INTEGER
+            todayIndex INTEGER,
+            start INTEGER,
+            startBucket INTEGER,
+            'index' INTEGER
         )
         ",
     )
@@ -672,6 +672,28 @@ async fn test_bulk_create_tasks_tool() {
     }
 }
 
+#[tokio::test]
+async fn test_bulk_create_tasks_tool_titles_only_skips_blank() {
+    let server = create_test_mcp_server().await;
+    let request = CallToolRequest {
+        name: "bulk_create_tasks".to_string(),
+        arguments: Some(json!({
+            "tasks": [{"title": "Idea 1"}, {"title": "  "}, {"title": "Idea 2"}]
+        })),
+    };
+
+    let result = server.call_tool(request).await.unwrap();
+    assert!(!result.is_error);
+
+    match &result.content[0] {
+        Content::Text { text } => {
+            let parsed: serde_json::Value = serde_json::from_str(text).unwrap();
+            assert_eq!(parsed["success"], true);
+            assert_eq!(parsed["processed_count"], 2);
+        }
+    }
+}
+
 #[tokio::test]
 async fn test_bulk_create_tasks_tool_missing_tasks() {
     let server = create_test_mcp_server().await;
@@ -711,6 +733,31 @@ async fn test_get_recent_tasks_tool() {
     }
 }
 
+#[tokio::test]
+async fn test_get_recent_tasks_tool_huge_hours() {
+    let server = create_test_mcp_server().await;
+    let recent = server.db.get_recent_tasks(1000).await.unwrap();
+    assert!(!recent.is_empty());
+
+    // Past `Duration::try_hours`, and past the earliest representable cutoff
+    for hours in [10_000_000_000_000_000_u64, 2_000_000_000_000_000] {
+        let result = server
+            .call_tool(CallToolRequest {
+                name: "get_recent_tasks".to_string(),
+                arguments: Some(json!({ "limit": 1000, "hours": hours })),
+            })
+            .await
+            .unwrap();
+        assert!(!result.is_error);
+
+        let Content::Text { text } = &result.content[0];
+        let parsed: serde_json::Value = serde_json::from_str(text).unwrap();
+        assert_eq!(parsed["hours_lookback"], hours);
+        // No representable cutoff, so every recent task is kept
+        assert_eq!(parsed["tasks"].as_array().unwrap().len(), recent.len());
+    }
+}
+
 #[tokio::test]
 async fn test_get_recent_tasks_tool_default_hours() {
     let server = create_test_mcp_server().await;
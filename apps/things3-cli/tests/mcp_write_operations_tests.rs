@@ -332,7 +332,7 @@ async fn test_update_nonexistent_task_error() {
 }
 
 // ============================================================================
-// End-to-End Tests (5 tests)
+// End-to-End Tests (6 tests)
 // ============================================================================
 
 #[tokio::test]
@@ -428,6 +428,43 @@ async fn test_e2e_update_status_verify_completion() {
 
 #[tokio::test]
 
+async fn test_e2e_update_merge_patch_null_clears_field() {
+    let harness = McpTestHarness::new().await;
+
+    let create_response = harness
+        .call_tool(
+            "create_task",
+            Some(json!({
+                "title": "Patch Target",
+                "notes": "Keep me",
+                "deadline": "2026-05-01"
+            })),
+        )
+        .await;
+    let uuid = create_response["uuid"].as_str().unwrap();
+
+    // Only the deadline key is sent, and null clears it
+    let update_response = harness
+        .call_tool(
+            "update_task",
+            Some(json!({
+                "uuid": uuid,
+                "deadline": null
+            })),
+        )
+        .await;
+    assert!(update_response.get("message").is_some());
+
+    let results = harness
+        .call_tool("search_tasks", Some(json!({"query": "Patch Target"})))
+        .await;
+    let task = &results.as_array().unwrap()[0];
+    assert!(task["deadline"].is_null(), "deadline should be cleared");
+    assert_eq!(task["notes"], "Keep me", "notes should be untouched");
+}
+
+#[tokio::test]
+
 async fn test_e2e_create_task_with_tags_search() {
     let harness = McpTestHarness::new().await;
 
@@ -158,6 +158,42 @@ fn bench_get_stats(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_full_scan(c: &mut Criterion) {
+    let mut group = c.benchmark_group("full_scan");
+    let rt = Runtime::new().unwrap();
+
+    for size in [100, 1000].iter() {
+        let (_temp, db) = create_test_db_with_data(*size);
+
+        group.bench_with_input(BenchmarkId::new("fetch", size), size, |b, _| {
+            b.to_async(&rt).iter_custom(|iters| {
+                let db = db.clone();
+                async move {
+                    let mut total = std::time::Duration::ZERO;
+                    for _ in 0..iters {
+                        total += db.time_full_scan().await.unwrap();
+                    }
+                    total
+                }
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("fetch_and_map", size), size, |b, _| {
+            b.to_async(&rt).iter_custom(|iters| {
+                let db = db.clone();
+                async move {
+                    let mut total = std::time::Duration::ZERO;
+                    for _ in 0..iters {
+                        total += db.time_full_scan_mapped().await.unwrap();
+                    }
+                    total
+                }
+            });
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_get_inbox,
@@ -165,6 +201,7 @@ criterion_group!(
     bench_search_tasks,
     bench_get_projects,
     bench_get_areas,
-    bench_get_stats
+    bench_get_stats,
+    bench_full_scan
 );
 criterion_main!(benches);
@@ -7,7 +7,9 @@
 //! - Creating and updating tasks
 
 use chrono::NaiveDate;
-use things3_core::{CreateTaskRequest, ThingsDatabase, ThingsError, UpdateTaskRequest};
+use things3_core::{
+    CreateTaskRequest, FieldUpdate, ThingsDatabase, ThingsError, UpdateTaskRequest,
+};
 
 #[tokio::main]
 async fn main() -> Result<(), ThingsError> {
@@ -81,11 +83,11 @@ async fn main() -> Result<(), ThingsError> {
     let update_request = UpdateTaskRequest {
         uuid: task_uuid,
         title: Some("Updated example task".to_string()),
-        notes: Some("This task was updated".to_string()),
-        start_date: None,
-        deadline: None,
-        project_uuid: None,
-        area_uuid: None,
+        notes: FieldUpdate::Set("This task was updated".to_string()),
+        start_date: FieldUpdate::Unchanged,
+        deadline: FieldUpdate::Unchanged,
+        project_uuid: FieldUpdate::Unchanged,
+        area_uuid: FieldUpdate::Unchanged,
         tags: None,
         status: None,
     };
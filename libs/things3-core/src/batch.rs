@@ -23,7 +23,7 @@ use std::collections::HashSet;
 
 use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
 
-use crate::database::mappers::{map_project_row, map_task_row};
+use crate::database::mappers::TASK_COLUMNS;
 use crate::database::ThingsDatabase;
 use crate::error::{Result as ThingsResult, ThingsError};
 use crate::models::{Project, Task, ThingsId};
@@ -52,19 +52,13 @@ impl ThingsDatabase {
         let mut tasks = fetch_in_chunks(
             &self.pool,
             uuids,
-            "SELECT uuid, title, type, status, notes, startDate, deadline, stopDate, \
-             creationDate, userModificationDate, project, area, heading, trashed, \
-             (SELECT GROUP_CONCAT(tg.title, char(31)) \
-                FROM TMTaskTag tt \
-                JOIN TMTag tg ON tg.uuid = tt.tags \
-               WHERE tt.tasks = TMTask.uuid) AS tags_csv \
-             FROM TMTask WHERE uuid IN ({placeholders})",
+            &format!("SELECT {TASK_COLUMNS}, trashed FROM TMTask WHERE uuid IN ({{placeholders}})"),
             |row| {
                 let trashed: i64 = row.get("trashed");
                 if trashed == 1 {
                     return Ok(None);
                 }
-                map_task_row(row).map(Some)
+                self.map_task(row).map(Some)
             },
         )
         .await?;
@@ -97,7 +91,7 @@ impl ThingsDatabase {
                 if trashed == 1 {
                     return Ok(None);
                 }
-                Ok(Some(map_project_row(row)))
+                self.map_project(row).map(Some)
             },
         )
         .await?;
@@ -1,6 +1,7 @@
 //! Configuration management for Things 3 integration
 
 use crate::error::{Result, ThingsError};
+use chrono_tz::Tz;
 use std::path::{Path, PathBuf};
 
 /// Configuration for Things 3 database access
@@ -10,6 +11,8 @@ pub struct ThingsConfig {
     pub database_path: PathBuf,
     /// Whether to use the default database path if the specified path doesn't exist
     pub fallback_to_default: bool,
+    /// Timezone used for day boundaries ("today", date-range filters)
+    pub timezone: Tz,
 }
 
 impl ThingsConfig {
@@ -23,45 +26,45 @@ impl ThingsConfig {
         Self {
             database_path: database_path.as_ref().to_path_buf(),
             fallback_to_default,
+            timezone: crate::database::system_timezone(),
         }
     }
 
+    /// Use the given timezone for day boundaries instead of the system zone
+    #[must_use]
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
     /// Create a configuration with the default database path
     #[must_use]
     pub fn with_default_path() -> Self {
-        Self {
-            database_path: Self::get_default_database_path(),
-            fallback_to_default: false,
-        }
+        Self::new(Self::get_default_database_path(), false)
     }
 
     /// Get the effective database path, falling back to default if needed
     ///
     /// # Errors
-    /// Returns `ThingsError::Message` if neither the specified path nor the default path exists
+    /// Returns `ThingsError::DatabaseNotFound` if neither the specified path nor
+    /// (with `fallback_to_default`) the default path is an existing file
     pub fn get_effective_database_path(&self) -> Result<PathBuf> {
-        // Check if the specified path exists
-        if self.database_path.exists() {
+        // Check if the specified path is an existing file (not a directory)
+        if self.database_path.is_file() {
             return Ok(self.database_path.clone());
         }
 
         // If fallback is enabled, try the default path
         if self.fallback_to_default {
             let default_path = Self::get_default_database_path();
-            if default_path.exists() {
+            if default_path.is_file() {
                 return Ok(default_path);
             }
         }
 
-        Err(ThingsError::configuration(format!(
-            "Database not found at {} and fallback is {}",
-            self.database_path.display(),
-            if self.fallback_to_default {
-                "enabled but default path also not found"
-            } else {
-                "disabled"
-            }
-        )))
+        Err(ThingsError::DatabaseNotFound {
+            path: self.database_path.display().to_string(),
+        })
     }
 
     /// Get the default Things 3 database path.
@@ -76,7 +79,8 @@ impl ThingsConfig {
     /// Create configuration from environment variables
     ///
     /// Reads the database path from `THINGS_DB_PATH` (preferred) or the legacy
-    /// `THINGS_DATABASE_PATH`, and the fallback flag from `THINGS_FALLBACK_TO_DEFAULT`.
+    /// `THINGS_DATABASE_PATH`, the fallback flag from `THINGS_FALLBACK_TO_DEFAULT`,
+    /// and an IANA timezone name (e.g. `Europe/Berlin`) from `THINGS_TIMEZONE`.
     #[must_use]
     pub fn from_env() -> Self {
         let database_path = match std::env::var("THINGS_DB_PATH") {
@@ -102,7 +106,17 @@ impl ThingsConfig {
             true
         };
 
-        Self::new(database_path, fallback_to_default)
+        let config = Self::new(database_path, fallback_to_default);
+        match std::env::var("THINGS_TIMEZONE") {
+            Ok(v) => match v.parse::<Tz>() {
+                Ok(tz) => config.with_timezone(tz),
+                Err(_) => {
+                    tracing::warn!("Ignoring invalid THINGS_TIMEZONE value: {v}");
+                    config
+                }
+            },
+            Err(_) => config,
+        }
     }
 
     /// Create configuration for testing with a temporary database
@@ -134,6 +148,34 @@ mod tests {
         let config = ThingsConfig::new("/path/to/db.sqlite", true);
         assert_eq!(config.database_path, PathBuf::from("/path/to/db.sqlite"));
         assert!(config.fallback_to_default);
+        assert_eq!(config.timezone, crate::database::system_timezone());
+    }
+
+    #[test]
+    fn test_config_with_timezone() {
+        let tz: Tz = "Europe/Berlin".parse().unwrap();
+        let config = ThingsConfig::new("/path/to/db.sqlite", false).with_timezone(tz);
+        assert_eq!(config.timezone, tz);
+    }
+
+    #[test]
+    #[serial]
+    fn test_from_env_reads_timezone() {
+        let original = std::env::var("THINGS_TIMEZONE").ok();
+
+        std::env::set_var("THINGS_TIMEZONE", "Asia/Tokyo");
+        let valid = ThingsConfig::from_env().timezone;
+        std::env::set_var("THINGS_TIMEZONE", "Not/AZone");
+        let invalid = ThingsConfig::from_env().timezone;
+
+        if let Some(v) = original {
+            std::env::set_var("THINGS_TIMEZONE", v);
+        } else {
+            std::env::remove_var("THINGS_TIMEZONE");
+        }
+
+        assert_eq!(valid, chrono_tz::Asia::Tokyo);
+        assert_eq!(invalid, crate::database::system_timezone());
     }
 
     #[test]
@@ -510,11 +552,10 @@ mod tests {
             assert!(result.is_err());
             let error = result.unwrap_err();
             match error {
-                ThingsError::Configuration { message } => {
-                    assert!(message.contains("Database not found at"));
-                    assert!(message.contains("fallback is enabled but default path also not found"));
+                ThingsError::DatabaseNotFound { path } => {
+                    assert_eq!(path, "/nonexistent/path.sqlite");
                 }
-                _ => panic!("Expected Configuration error, got: {error:?}"),
+                _ => panic!("Expected DatabaseNotFound error, got: {error:?}"),
             }
         }
     }
@@ -525,18 +566,26 @@ mod tests {
         let config = ThingsConfig::new("/nonexistent/path.sqlite", false);
         let result = config.get_effective_database_path();
 
-        // Should get an error with specific message about fallback being disabled
+        // Should get an error naming the configured path
         assert!(result.is_err());
         let error = result.unwrap_err();
         match error {
-            ThingsError::Configuration { message } => {
-                assert!(message.contains("Database not found at"));
-                assert!(message.contains("fallback is disabled"));
+            ThingsError::DatabaseNotFound { path } => {
+                assert_eq!(path, "/nonexistent/path.sqlite");
             }
-            _ => panic!("Expected Configuration error, got: {error:?}"),
+            _ => panic!("Expected DatabaseNotFound error, got: {error:?}"),
         }
     }
 
+    #[test]
+    fn test_effective_database_path_rejects_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ThingsConfig::new(dir.path(), false);
+
+        let error = config.get_effective_database_path().unwrap_err();
+        assert!(matches!(error, ThingsError::DatabaseNotFound { .. }));
+    }
+
     #[test]
     #[serial]
     fn test_from_env_without_variables() {
@@ -742,7 +791,7 @@ mod tests {
         let result = config.get_effective_database_path();
         assert!(result.is_err());
         let error = result.unwrap_err();
-        assert!(matches!(error, ThingsError::Configuration { .. }));
+        assert!(matches!(error, ThingsError::DatabaseNotFound { .. }));
     }
 
     #[test]
@@ -771,12 +820,11 @@ mod tests {
             "Expected error when both configured and default paths don't exist"
         );
         let error = result.unwrap_err();
-        assert!(matches!(error, ThingsError::Configuration { .. }));
+        assert!(matches!(error, ThingsError::DatabaseNotFound { .. }));
 
         // Check the error message contains the expected text
         let error_message = format!("{error}");
-        assert!(error_message.contains("Database not found at /nonexistent/path/db.sqlite"));
-        assert!(error_message.contains("fallback is enabled but default path also not found"));
+        assert!(error_message.contains("Database not found: /nonexistent/path/db.sqlite"));
     }
 
     #[test]
@@ -892,8 +940,8 @@ mod tests {
 
         let error_msg = result.unwrap_err().to_string();
         assert!(
-            error_msg.contains("fallback is disabled"),
-            "Error message should mention fallback is disabled"
+            error_msg.contains("/absolutely/non/existent/path/database.db"),
+            "Error message should name the missing path"
         );
     }
 
@@ -62,21 +62,93 @@ pub fn naive_date_to_things_timestamp(date: NaiveDate) -> i64 {
     date_time.timestamp() - base_date.timestamp()
 }
 
-/// Serialize tags to Things 3 binary format
-/// Note: This is a simplified implementation using JSON
-/// The actual Things 3 binary format is proprietary
+/// Decode a Things 3 `reminderTime` value into a wall-clock time
+///
+/// Things packs the reminder's time of day into bits: hours in bits 26-30
+/// and minutes in bits 20-25. Returns `None` for 0 or out-of-range values.
+pub(crate) fn things_time_to_naive_time(packed: i64) -> Option<chrono::NaiveTime> {
+    if packed <= 0 {
+        return None;
+    }
+    let hours = (packed >> 26) & 0x1F;
+    let minutes = (packed >> 20) & 0x3F;
+    chrono::NaiveTime::from_hms_opt(u32::try_from(hours).ok()?, u32::try_from(minutes).ok()?, 0)
+}
+
+/// Encoding used for a `cachedTags` blob
+///
+/// Tag membership is written to `TMTaskTag`, which is what Things 3 itself
+/// reads; `cachedTags` is only a denormalized cache. [`TagBlobFormat::Json`]
+/// is this crate's own encoding. The blob Things 3 writes is undocumented
+/// (a binary plist referencing tag UUIDs), so [`TagBlobFormat::ThingsNative`]
+/// is accepted as a choice but rejected until that encoding is verified.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TagBlobFormat {
+    /// JSON array of tag titles, e.g. `["work","urgent"]`
+    #[default]
+    Json,
+    /// The binary encoding Things 3 writes itself (not yet supported)
+    ThingsNative,
+}
+
+/// Leading magic bytes of a binary property list, as written by Things 3
+const BPLIST_MAGIC: &[u8] = b"bplist";
+
+/// Serialize tags to a `cachedTags` blob as JSON
+///
+/// Equivalent to [`serialize_tags_to_blob_as`] with [`TagBlobFormat::Json`].
 pub fn serialize_tags_to_blob(tags: &[String]) -> ThingsResult<Vec<u8>> {
-    serde_json::to_vec(tags)
-        .map_err(|e| ThingsError::unknown(format!("Failed to serialize tags: {e}")))
+    serialize_tags_to_blob_as(tags, TagBlobFormat::Json)
 }
 
-/// Deserialize tags from Things 3 binary format
+/// Serialize tags to a `cachedTags` blob in the given format
+///
+/// # Errors
+///
+/// Returns an error if serialization fails or `format` is
+/// [`TagBlobFormat::ThingsNative`], which is not supported yet
+pub fn serialize_tags_to_blob_as(tags: &[String], format: TagBlobFormat) -> ThingsResult<Vec<u8>> {
+    match format {
+        TagBlobFormat::Json => serde_json::to_vec(tags)
+            .map_err(|e| ThingsError::unknown(format!("Failed to serialize tags: {e}"))),
+        TagBlobFormat::ThingsNative => Err(ThingsError::unknown(
+            "Things-native cachedTags encoding is not supported yet",
+        )),
+    }
+}
+
+/// Deserialize tags from a JSON `cachedTags` blob
+///
+/// Equivalent to [`deserialize_tags_from_blob_as`] with [`TagBlobFormat::Json`].
 pub fn deserialize_tags_from_blob(blob: &[u8]) -> ThingsResult<Vec<String>> {
+    deserialize_tags_from_blob_as(blob, TagBlobFormat::Json)
+}
+
+/// Deserialize tags from a `cachedTags` blob in the given format
+///
+/// An empty blob yields no tags in either format.
+///
+/// # Errors
+///
+/// Returns an error if the blob is not valid for `format`, including a
+/// Things-written binary plist passed as [`TagBlobFormat::Json`]
+pub fn deserialize_tags_from_blob_as(
+    blob: &[u8],
+    format: TagBlobFormat,
+) -> ThingsResult<Vec<String>> {
     if blob.is_empty() {
         return Ok(Vec::new());
     }
-    serde_json::from_slice(blob)
-        .map_err(|e| ThingsError::unknown(format!("Failed to deserialize tags: {e}")))
+    match format {
+        TagBlobFormat::Json if blob.starts_with(BPLIST_MAGIC) => Err(ThingsError::unknown(
+            "cachedTags blob is in Things-native format; read tags via TMTaskTag instead",
+        )),
+        TagBlobFormat::Json => serde_json::from_slice(blob)
+            .map_err(|e| ThingsError::unknown(format!("Failed to deserialize tags: {e}"))),
+        TagBlobFormat::ThingsNative => Err(ThingsError::unknown(
+            "Things-native cachedTags encoding is not supported yet",
+        )),
+    }
 }
 
 impl TaskStatus {
@@ -88,6 +160,27 @@ impl TaskStatus {
             _ => None,
         }
     }
+
+    /// Map a `TMTask.status` code, keeping unrecognized codes as [`TaskStatus::Unknown`]
+    #[must_use]
+    pub fn from_code(code: i32) -> Self {
+        Self::from_i32(code).unwrap_or(Self::Unknown(code))
+    }
+
+    /// The `TMTask.status` code for this status
+    ///
+    /// `None` for [`TaskStatus::Trashed`], which is stored in the `trashed`
+    /// column rather than as a status code.
+    #[must_use]
+    pub const fn code(self) -> Option<i32> {
+        match self {
+            Self::Incomplete => Some(0),
+            Self::Canceled => Some(2),
+            Self::Completed => Some(3),
+            Self::Trashed => None,
+            Self::Unknown(code) => Some(code),
+        }
+    }
 }
 
 impl TaskType {
@@ -106,6 +199,18 @@ impl TaskType {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_things_time_to_naive_time() {
+        // 09:30 packed as hours << 26 | minutes << 20
+        let packed = (9 << 26) | (30 << 20);
+        assert_eq!(
+            things_time_to_naive_time(packed),
+            chrono::NaiveTime::from_hms_opt(9, 30, 0)
+        );
+        assert_eq!(things_time_to_naive_time(0), None);
+        assert_eq!(things_time_to_naive_time(25 << 26), None);
+    }
+
     #[test]
     fn test_task_status_from_i32() {
         assert_eq!(TaskStatus::from_i32(0), Some(TaskStatus::Incomplete));
@@ -116,6 +221,16 @@ mod tests {
         assert_eq!(TaskStatus::from_i32(-1), None);
     }
 
+    #[test]
+    fn test_task_status_from_code_round_trips() {
+        for code in [0, 1, 2, 3, 7, -1] {
+            assert_eq!(TaskStatus::from_code(code).code(), Some(code));
+        }
+        assert_eq!(TaskStatus::from_code(3), TaskStatus::Completed);
+        assert_eq!(TaskStatus::from_code(7), TaskStatus::Unknown(7));
+        assert_eq!(TaskStatus::Trashed.code(), None);
+    }
+
     #[test]
     fn test_task_type_from_i32() {
         assert_eq!(TaskType::from_i32(0), Some(TaskType::Todo));
@@ -1,17 +1,55 @@
 use crate::{
     database::{
+        mappers::{map_project_row_with_diagnostics, map_task_row_with_diagnostics},
         pool::{
-            ComprehensiveHealthStatus, DatabasePoolConfig, PoolHealthStatus, PoolMetrics,
-            SqliteOptimizations,
+            CheckpointMode, CheckpointResult, ComprehensiveHealthStatus, ConnectionState,
+            DatabasePoolConfig, PoolHealthStatus, PoolMetrics, SqliteOptimizations,
         },
         stats::DatabaseStats,
+        tag_utils::TagNormalizationConfig,
     },
     error::{Result as ThingsResult, ThingsError},
+    models::{Project, Task},
 };
 use chrono::Utc;
-use sqlx::{pool::PoolOptions, SqlitePool};
+use chrono_tz::Tz;
+use sqlx::{
+    pool::PoolOptions,
+    sqlite::{SqliteConnectOptions, SqliteRow},
+    Row, Sqlite, SqlitePool,
+};
 use std::path::Path;
-use tracing::{debug, error, info, instrument};
+use std::str::FromStr;
+use std::sync::Arc;
+use tempfile::TempDir;
+use tracing::{debug, error, info, instrument, warn};
+
+/// Copy `source` and its WAL files to `copy`, then fold the WAL into `copy`
+///
+/// The copy is switched to rollback-journal mode so it is a single,
+/// self-contained file that can be opened `immutable=1`.
+fn copy_and_checkpoint(source: &Path, copy: &Path) -> ThingsResult<()> {
+    std::fs::copy(source, copy)
+        .map_err(|e| ThingsError::unknown(format!("Failed to copy {}: {e}", source.display())))?;
+    for suffix in ["-wal", "-shm"] {
+        let mut sidecar = source.as_os_str().to_owned();
+        sidecar.push(suffix);
+        let sidecar = Path::new(&sidecar);
+        if sidecar.exists() {
+            let mut target = copy.as_os_str().to_owned();
+            target.push(suffix);
+            std::fs::copy(sidecar, Path::new(&target)).map_err(|e| {
+                ThingsError::unknown(format!("Failed to copy {}: {e}", sidecar.display()))
+            })?;
+        }
+    }
+
+    let conn = rusqlite::Connection::open(copy)
+        .map_err(|e| ThingsError::unknown(format!("Failed to open snapshot copy: {e}")))?;
+    conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))
+        .and_then(|()| conn.query_row("PRAGMA journal_mode = DELETE", [], |_| Ok(())))
+        .map_err(|e| ThingsError::unknown(format!("Failed to checkpoint snapshot copy: {e}")))
+}
 
 /// SQLx-based database implementation for Things 3 data
 /// This provides async, Send + Sync compatible database access
@@ -19,6 +57,24 @@ use tracing::{debug, error, info, instrument};
 pub struct ThingsDatabase {
     pub(crate) pool: SqlitePool,
     config: DatabasePoolConfig,
+    pub(crate) timezone: Tz,
+    pub(crate) tag_normalization: TagNormalizationConfig,
+    pub(crate) default_tags: Vec<String>,
+    query_timeout: Option<std::time::Duration>,
+    mapping_diagnostics: bool,
+    /// Temporary directory holding the copy opened by [`Self::open_snapshot`]
+    snapshot_dir: Option<Arc<TempDir>>,
+}
+
+/// Whether `err` is `SQLite` reporting a lock held by another connection
+///
+/// Matches the primary result codes `SQLITE_BUSY` (5) and `SQLITE_LOCKED` (6),
+/// including their extended variants such as `SQLITE_BUSY_SNAPSHOT`.
+fn is_busy_error(err: &sqlx::Error) -> bool {
+    err.as_database_error()
+        .and_then(|db_err| db_err.code())
+        .and_then(|code| code.parse::<i32>().ok())
+        .is_some_and(|code| matches!(code & 0xff, 5 | 6))
 }
 
 impl ThingsDatabase {
@@ -81,12 +137,14 @@ impl ThingsDatabase {
     ///
     /// # Errors
     ///
-    /// Returns an error if the database connection fails or if `SQLite` configuration fails
+    /// Returns [`ThingsError::DatabaseNotFound`] if `database_path` isn't an existing
+    /// file, or an error if the database connection or `SQLite` configuration fails
     #[instrument]
     pub async fn new_with_config(
         database_path: &Path,
         config: DatabasePoolConfig,
     ) -> ThingsResult<Self> {
+        Self::check_database_file(database_path)?;
         let database_url = format!("sqlite:{}", database_path.display());
 
         info!(
@@ -95,14 +153,8 @@ impl ThingsDatabase {
         );
 
         // Create optimized connection pool
-        let pool = PoolOptions::new()
-            .max_connections(config.max_connections)
-            .min_connections(config.min_connections)
-            .acquire_timeout(config.connect_timeout)
-            .idle_timeout(Some(config.idle_timeout))
-            .max_lifetime(Some(config.max_lifetime))
-            .test_before_acquire(config.test_before_acquire)
-            .connect(&database_url)
+        let pool = Self::pool_options(&config)
+            .connect_with(Self::connect_options(&database_url, &config)?)
             .await
             .map_err(|e| ThingsError::unknown(format!("Failed to connect to database: {e}")))?;
 
@@ -114,7 +166,63 @@ impl ThingsDatabase {
             config.max_connections
         );
 
-        Ok(Self { pool, config })
+        Ok(Self::from_pool(pool, config))
+    }
+
+    /// Pool settings from `config`, shared by every constructor
+    fn pool_options(config: &DatabasePoolConfig) -> PoolOptions<Sqlite> {
+        PoolOptions::new()
+            .max_connections(config.max_connections)
+            .min_connections(config.min_connections)
+            .acquire_timeout(config.connect_timeout)
+            .idle_timeout(Some(config.idle_timeout))
+            .max_lifetime(Some(config.max_lifetime))
+            .test_before_acquire(config.test_before_acquire)
+    }
+
+    /// Wrap a connected pool with default per-database settings
+    fn from_pool(pool: SqlitePool, config: DatabasePoolConfig) -> Self {
+        Self {
+            pool,
+            config,
+            timezone: super::system_timezone(),
+            tag_normalization: TagNormalizationConfig::default(),
+            default_tags: Vec::new(),
+            query_timeout: None,
+            mapping_diagnostics: false,
+            snapshot_dir: None,
+        }
+    }
+
+    /// Fail early with [`ThingsError::DatabaseNotFound`] unless `path` is a regular file
+    ///
+    /// Matches [`crate::ThingsConfig::get_effective_database_path`], so a missing path,
+    /// a directory, or anything else that isn't a file gets the same error. Without
+    /// this, `SQLite` reports only a generic "unable to open" error.
+    fn check_database_file(path: &Path) -> ThingsResult<()> {
+        if !path.is_file() {
+            return Err(ThingsError::DatabaseNotFound {
+                path: path.display().to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Parse connection options for `database_url`, applying per-connection settings
+    fn connect_options(
+        database_url: &str,
+        config: &DatabasePoolConfig,
+    ) -> ThingsResult<SqliteConnectOptions> {
+        let optimizations = &config.sqlite_optimizations;
+        let options = SqliteConnectOptions::from_str(database_url)
+            .map_err(|e| ThingsError::unknown(format!("Invalid database URL: {e}")))?;
+        // Wait on locks held by Things instead of failing with SQLITE_BUSY.
+        // Set per connection, since a PRAGMA run through the pool only reaches one.
+        Ok(options
+            .busy_timeout(std::time::Duration::from_millis(
+                optimizations.busy_timeout_ms,
+            ))
+            .statement_cache_capacity(optimizations.statement_cache_capacity))
     }
 
     /// Apply SQLite-specific optimizations
@@ -178,13 +286,14 @@ impl ThingsDatabase {
         }
 
         debug!(
-            "Applied SQLite optimizations: WAL={}, sync={}, cache={}KB, fk={}, temp={}, mmap={}MB",
+            "Applied SQLite optimizations: WAL={}, sync={}, cache={}KB, fk={}, temp={}, mmap={}MB, busy_timeout={}ms",
             optimizations.enable_wal_mode,
             optimizations.synchronous_mode,
             optimizations.cache_size.abs() / 1024,
             optimizations.enable_foreign_keys,
             optimizations.temp_store,
-            optimizations.mmap_size / 1024 / 1024
+            optimizations.mmap_size / 1024 / 1024,
+            optimizations.busy_timeout_ms
         );
 
         Ok(())
@@ -216,14 +325,8 @@ impl ThingsDatabase {
         );
 
         // Create optimized connection pool
-        let pool = PoolOptions::new()
-            .max_connections(config.max_connections)
-            .min_connections(config.min_connections)
-            .acquire_timeout(config.connect_timeout)
-            .idle_timeout(Some(config.idle_timeout))
-            .max_lifetime(Some(config.max_lifetime))
-            .test_before_acquire(config.test_before_acquire)
-            .connect(database_url)
+        let pool = Self::pool_options(&config)
+            .connect_with(Self::connect_options(database_url, &config)?)
             .await
             .map_err(|e| ThingsError::unknown(format!("Failed to connect to database: {e}")))?;
 
@@ -235,7 +338,262 @@ impl ThingsDatabase {
             config.max_connections
         );
 
-        Ok(Self { pool, config })
+        Ok(Self::from_pool(pool, config))
+    }
+
+    /// Open a read-only snapshot copy of the database at `source`
+    ///
+    /// Copies the database file along with its `-wal` and `-shm` files into a
+    /// temporary directory, checkpoints the copy so it includes every commit
+    /// from the WAL, then opens it with `immutable=1`. Queries against the
+    /// snapshot take no locks and never see later changes. Writes fail. The
+    /// copy is deleted when the last clone of the returned database is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `source` can't be copied or checkpointed, or if the
+    /// copy can't be opened
+    #[instrument]
+    pub async fn open_snapshot(source: &Path) -> ThingsResult<Self> {
+        Self::check_database_file(source)?;
+        let dir = tempfile::Builder::new()
+            .prefix("things3-snapshot-")
+            .tempdir()
+            .map_err(|e| ThingsError::unknown(format!("Failed to create snapshot dir: {e}")))?;
+        let copy = dir.path().join("main.sqlite");
+
+        let (source_path, copy_path) = (source.to_path_buf(), copy.clone());
+        tokio::task::spawn_blocking(move || copy_and_checkpoint(&source_path, &copy_path))
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Snapshot task failed: {e}")))??;
+
+        let config = DatabasePoolConfig::default();
+        let options = SqliteConnectOptions::new()
+            .filename(&copy)
+            .read_only(true)
+            .immutable(true)
+            .statement_cache_capacity(config.sqlite_optimizations.statement_cache_capacity);
+        let pool = Self::pool_options(&config)
+            .connect_with(options)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to open snapshot: {e}")))?;
+
+        info!(
+            "Opened snapshot of {} at {}",
+            source.display(),
+            copy.display()
+        );
+        Ok(Self {
+            snapshot_dir: Some(Arc::new(dir)),
+            ..Self::from_pool(pool, config)
+        })
+    }
+
+    /// Open the live database read-only for polling while Things is running
+    ///
+    /// Uses [`DatabasePoolConfig::default`]. See [`Self::open_readonly_wal_with_config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database can't be opened
+    #[instrument]
+    pub async fn open_readonly_wal(database_path: &Path) -> ThingsResult<Self> {
+        Self::open_readonly_wal_with_config(database_path, DatabasePoolConfig::default()).await
+    }
+
+    /// Open the live database read-only (`mode=ro`) with custom configuration
+    ///
+    /// In WAL mode readers don't block on a writer, so queries keep working
+    /// while Things holds a write transaction. Every connection gets the
+    /// configured `busy_timeout` to ride out the brief locks taken during
+    /// checkpoints. The journal mode is left alone, since changing it is a
+    /// write; Things keeps its database in WAL mode already. Writes fail.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThingsError::DatabaseNotFound`] if `database_path` isn't an existing
+    /// file, or an error if the database can't be opened
+    #[instrument]
+    pub async fn open_readonly_wal_with_config(
+        database_path: &Path,
+        config: DatabasePoolConfig,
+    ) -> ThingsResult<Self> {
+        Self::check_database_file(database_path)?;
+        let optimizations = &config.sqlite_optimizations;
+        let options = SqliteConnectOptions::new()
+            .filename(database_path)
+            .read_only(true)
+            .busy_timeout(std::time::Duration::from_millis(
+                optimizations.busy_timeout_ms,
+            ))
+            .pragma("cache_size", optimizations.cache_size.to_string())
+            .pragma("temp_store", optimizations.temp_store.clone())
+            .pragma("mmap_size", optimizations.mmap_size.to_string())
+            .statement_cache_capacity(optimizations.statement_cache_capacity);
+        let pool = Self::pool_options(&config)
+            .connect_with(options)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to open database read-only: {e}")))?;
+
+        info!(
+            "Opened {} read-only with busy_timeout={}ms",
+            database_path.display(),
+            config.sqlite_optimizations.busy_timeout_ms
+        );
+        Ok(Self::from_pool(pool, config))
+    }
+
+    /// Use the given timezone for day boundaries instead of the system zone
+    ///
+    /// Affects "today" and date-range filters such as the logbook's
+    /// `from_date`/`to_date`.
+    #[must_use]
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Use custom rules when comparing tag titles
+    ///
+    /// Affects smart tag creation, tag assignment by title, and duplicate
+    /// detection. See [`TagNormalizationConfig`].
+    #[must_use]
+    pub fn with_tag_normalization(mut self, config: TagNormalizationConfig) -> Self {
+        self.tag_normalization = config;
+        self
+    }
+
+    /// Get the rules used when comparing tag titles
+    #[must_use]
+    pub fn tag_normalization(&self) -> &TagNormalizationConfig {
+        &self.tag_normalization
+    }
+
+    /// Tags added to every task created with [`Self::create_task_with_defaults`]
+    #[must_use]
+    pub fn with_default_tags(mut self, tags: Vec<String>) -> Self {
+        self.default_tags = tags;
+        self
+    }
+
+    /// Get the tags added by [`Self::create_task_with_defaults`]
+    #[must_use]
+    pub fn default_tags(&self) -> &[String] {
+        &self.default_tags
+    }
+
+    /// Fail the main read queries with [`ThingsError::Timeout`] after `timeout`
+    ///
+    /// Applies to `get_inbox`, `get_today`, `get_all_tasks`, `search_tasks`,
+    /// `get_projects` and `get_areas`. The error names the method that timed
+    /// out. No timeout is applied by default.
+    #[must_use]
+    pub fn with_query_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.query_timeout = Some(timeout);
+        self
+    }
+
+    /// Get the timeout set with [`Self::with_query_timeout`], if any
+    #[must_use]
+    pub fn query_timeout(&self) -> Option<std::time::Duration> {
+        self.query_timeout
+    }
+
+    /// Log a warning whenever a task row holds a value that has to be replaced with a default
+    ///
+    /// Off by default. Mapped tasks are the same either way; each warning names
+    /// the task UUID, the column, and the replaced value. See
+    /// [`super::map_task_row_with_diagnostics`].
+    #[must_use]
+    pub fn with_mapping_diagnostics(mut self, enabled: bool) -> Self {
+        self.mapping_diagnostics = enabled;
+        self
+    }
+
+    /// Whether [`Self::with_mapping_diagnostics`] is on
+    #[must_use]
+    pub fn mapping_diagnostics(&self) -> bool {
+        self.mapping_diagnostics
+    }
+
+    /// Map a task row, logging substituted defaults if mapping diagnostics are on
+    pub(crate) fn map_task(&self, row: &SqliteRow) -> ThingsResult<Task> {
+        map_task_row_with_diagnostics(row, self.mapping_diagnostics)
+    }
+
+    /// Map a project row, logging substituted defaults if mapping diagnostics are on
+    pub(crate) fn map_project(&self, row: &SqliteRow) -> ThingsResult<Project> {
+        map_project_row_with_diagnostics(row, self.mapping_diagnostics)
+    }
+
+    /// Get the timezone used for day boundaries
+    #[must_use]
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Whether this database was opened with [`Self::open_snapshot`]
+    #[must_use]
+    pub fn is_snapshot(&self) -> bool {
+        self.snapshot_dir.is_some()
+    }
+
+    /// Run a statement, retrying while another writer holds the database lock
+    ///
+    /// `op` is re-run when it fails with `SQLITE_BUSY` or `SQLITE_LOCKED` (for
+    /// example while Things is saving), up to
+    /// [`SqliteOptimizations::busy_retries`] extra times with exponential
+    /// backoff starting at [`SqliteOptimizations::busy_retry_backoff_ms`].
+    /// Wrap a single statement or a whole transaction, never a sequence of
+    /// independent writes, so a retry can't repeat work that already committed.
+    pub(crate) async fn retry_busy<T, F, Fut>(&self, mut op: F) -> Result<T, sqlx::Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, sqlx::Error>>,
+    {
+        let optimizations = &self.config.sqlite_optimizations;
+        let mut attempt = 0;
+        loop {
+            match op().await {
+                Err(e) if is_busy_error(&e) && attempt < optimizations.busy_retries => {
+                    let delay = optimizations
+                        .busy_retry_backoff_ms
+                        .saturating_mul(1 << attempt.min(16));
+                    warn!(
+                        "Database busy, retrying in {}ms (attempt {}/{})",
+                        delay,
+                        attempt + 1,
+                        optimizations.busy_retries
+                    );
+                    tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Run `fut`, failing with [`ThingsError::Timeout`] for `operation` if it
+    /// outlasts the configured query timeout
+    pub(crate) async fn with_timeout<T, Fut>(
+        &self,
+        operation: &'static str,
+        fut: Fut,
+    ) -> ThingsResult<T>
+    where
+        Fut: std::future::Future<Output = ThingsResult<T>>,
+    {
+        let Some(timeout) = self.query_timeout else {
+            return fut.await;
+        };
+        let started = std::time::Instant::now();
+        tokio::time::timeout(timeout, fut)
+            .await
+            .unwrap_or_else(|_| {
+                let elapsed = started.elapsed();
+                warn!("{operation} timed out after {elapsed:?}");
+                Err(ThingsError::Timeout { operation, elapsed })
+            })
     }
 
     /// Get the underlying connection pool
@@ -245,16 +603,37 @@ impl ThingsDatabase {
     }
 
     /// Check if the database is connected
+    ///
+    /// A database that is only locked by another connection counts as
+    /// connected; use [`Self::connection_state`] to tell the two apart.
     #[instrument]
     pub async fn is_connected(&self) -> bool {
-        match sqlx::query("SELECT 1").fetch_one(&self.pool).await {
+        self.connection_state().await != ConnectionState::Down
+    }
+
+    /// Check whether the database can be queried, telling a lock apart from a failure
+    ///
+    /// Reads `sqlite_master`, which needs a shared lock, so a database held
+    /// exclusively by another connection reports [`ConnectionState::Busy`]
+    /// once the configured busy timeout runs out, instead of
+    /// [`ConnectionState::Down`].
+    #[instrument]
+    pub async fn connection_state(&self) -> ConnectionState {
+        match sqlx::query("SELECT 1 FROM sqlite_master LIMIT 1")
+            .fetch_optional(&self.pool)
+            .await
+        {
             Ok(_) => {
                 debug!("Database connection is healthy");
-                true
+                ConnectionState::Healthy
+            }
+            Err(e) if is_busy_error(&e) => {
+                warn!("Database is locked by another connection: {}", e);
+                ConnectionState::Busy
             }
             Err(e) => {
                 error!("Database connection check failed: {}", e);
-                false
+                ConnectionState::Down
             }
         }
     }
@@ -347,32 +726,65 @@ impl ThingsDatabase {
         })
     }
 
+    /// Run a WAL checkpoint without closing the pool
+    ///
+    /// Wraps `PRAGMA wal_checkpoint(<mode>)`. A [`CheckpointMode::Passive`]
+    /// checkpoint never blocks, so it is safe to call periodically from a
+    /// long-lived process to keep the WAL file from growing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the checkpoint pragma fails
+    #[instrument(skip(self))]
+    pub async fn checkpoint(&self, mode: CheckpointMode) -> ThingsResult<CheckpointResult> {
+        let (busy, log_frames, checkpointed_frames): (i64, i64, i64) =
+            sqlx::query_as(&format!("PRAGMA wal_checkpoint({})", mode.as_sql()))
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| ThingsError::unknown(format!("Failed to checkpoint WAL: {e}")))?;
+
+        debug!(
+            "WAL checkpoint ({}): busy={}, log={}, checkpointed={}",
+            mode.as_sql(),
+            busy,
+            log_frames,
+            checkpointed_frames
+        );
+        Ok(CheckpointResult {
+            busy: busy != 0,
+            log_frames,
+            checkpointed_frames,
+        })
+    }
+
     /// Get database statistics
     ///
+    /// Counts tasks, projects and areas in one round trip: a single pass over
+    /// `TMTask` plus a subquery for `TMArea`.
+    ///
     /// # Errors
     ///
     /// Returns an error if the database query fails
     #[instrument]
     pub async fn get_stats(&self) -> ThingsResult<DatabaseStats> {
-        let task_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM TMTask")
-            .fetch_one(&self.pool)
-            .await
-            .map_err(|e| ThingsError::unknown(format!("Failed to get task count: {e}")))?;
-
-        let project_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM TMTask WHERE type = 1")
-            .fetch_one(&self.pool)
-            .await
-            .map_err(|e| ThingsError::unknown(format!("Failed to get project count: {e}")))?;
-
-        let area_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM TMArea")
-            .fetch_one(&self.pool)
-            .await
-            .map_err(|e| ThingsError::unknown(format!("Failed to get area count: {e}")))?;
+        let row = sqlx::query(
+            r"
+            SELECT
+                COUNT(*) AS task_count,
+                COALESCE(SUM(CASE WHEN type = 1 THEN 1 ELSE 0 END), 0) AS project_count,
+                (SELECT COUNT(*) FROM TMArea) AS area_count
+            FROM TMTask
+            ",
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to get database stats: {e}")))?;
 
+        let count = |column: &str| -> u64 { row.get::<i64, _>(column).try_into().unwrap_or(0) };
         Ok(DatabaseStats {
-            task_count: task_count.try_into().unwrap_or(0),
-            project_count: project_count.try_into().unwrap_or(0),
-            area_count: area_count.try_into().unwrap_or(0),
+            task_count: count("task_count"),
+            project_count: count("project_count"),
+            area_count: count("area_count"),
         })
     }
 
@@ -418,6 +830,46 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_query_timeout_names_operation() {
+        let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+            .await
+            .unwrap();
+        assert_eq!(db.query_timeout(), None);
+
+        let db = db.with_query_timeout(Duration::from_millis(10));
+        let result = db
+            .with_timeout("slow_op", async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(())
+            })
+            .await;
+        match result {
+            Err(ThingsError::Timeout { operation, elapsed }) => {
+                assert_eq!(operation, "slow_op");
+                assert!(elapsed >= Duration::from_millis(10));
+            }
+            other => panic!("Expected Timeout, got {other:?}"),
+        }
+
+        let value = db.with_timeout("fast_op", async { Ok(7) }).await.unwrap();
+        assert_eq!(value, 7);
+    }
+
+    #[tokio::test]
+    async fn test_mapping_diagnostics_are_per_database() {
+        let quiet = ThingsDatabase::from_connection_string("sqlite::memory:")
+            .await
+            .unwrap();
+        let noisy = ThingsDatabase::from_connection_string("sqlite::memory:")
+            .await
+            .unwrap()
+            .with_mapping_diagnostics(true);
+
+        assert!(!quiet.mapping_diagnostics());
+        assert!(noisy.mapping_diagnostics());
+    }
+
     #[tokio::test]
     async fn test_database_new_with_config() {
         let temp_file = NamedTempFile::new().unwrap();
@@ -444,6 +896,322 @@ mod tests {
         assert!(!pool.is_closed());
     }
 
+    #[tokio::test]
+    async fn test_database_applies_busy_timeout() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let db_path = temp_file.path();
+
+        crate::test_utils::create_test_database(db_path)
+            .await
+            .unwrap();
+
+        let config = DatabasePoolConfig {
+            max_connections: 2,
+            min_connections: 0,
+            sqlite_optimizations: SqliteOptimizations {
+                busy_timeout_ms: 1234,
+                ..SqliteOptimizations::default()
+            },
+            ..DatabasePoolConfig::default()
+        };
+
+        let database = ThingsDatabase::new_with_config(db_path, config)
+            .await
+            .unwrap();
+
+        // Hold both connections at once so the second is a fresh one
+        let mut first = database.pool().acquire().await.unwrap();
+        let mut second = database.pool().acquire().await.unwrap();
+        assert_eq!(database.pool().size(), 2);
+        for conn in [&mut first, &mut second] {
+            let timeout: i64 = sqlx::query_scalar("PRAGMA busy_timeout")
+                .fetch_one(&mut **conn)
+                .await
+                .unwrap();
+            assert_eq!(timeout, 1234);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_writes_retry_while_database_is_locked() {
+        use sqlx::Connection;
+
+        let temp_file = NamedTempFile::new().unwrap();
+        let db_path = temp_file.path();
+
+        crate::test_utils::create_test_database(db_path)
+            .await
+            .unwrap();
+
+        // No SQLite-level waiting, so lock contention surfaces as SQLITE_BUSY
+        let config_with = |busy_retries| DatabasePoolConfig {
+            sqlite_optimizations: SqliteOptimizations {
+                busy_timeout_ms: 0,
+                busy_retries,
+                busy_retry_backoff_ms: 50,
+                ..SqliteOptimizations::default()
+            },
+            ..DatabasePoolConfig::default()
+        };
+        let request = || {
+            crate::test_utils::TaskRequestBuilder::new()
+                .title("Written while locked")
+                .build()
+        };
+
+        let no_retry = ThingsDatabase::new_with_config(db_path, config_with(0))
+            .await
+            .unwrap();
+        let retrying = ThingsDatabase::new_with_config(db_path, config_with(5))
+            .await
+            .unwrap();
+
+        let url = format!("sqlite:{}", db_path.display());
+        let mut holder = sqlx::SqliteConnection::connect(&url).await.unwrap();
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut holder)
+            .await
+            .unwrap();
+
+        assert!(no_retry.create_task(request()).await.is_err());
+
+        // Release the lock while the retrying write is backing off
+        let release = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(120)).await;
+            sqlx::query("COMMIT").execute(&mut holder).await.unwrap();
+        });
+        let id = retrying.create_task(request()).await.unwrap();
+        release.await.unwrap();
+
+        assert!(retrying.get_task_by_uuid(&id).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_connection_state_reports_busy_while_locked() {
+        use sqlx::Connection;
+
+        let temp_file = NamedTempFile::new().unwrap();
+        let db_path = temp_file.path();
+
+        crate::test_utils::create_test_database(db_path)
+            .await
+            .unwrap();
+
+        let config = DatabasePoolConfig {
+            max_connections: 1,
+            sqlite_optimizations: SqliteOptimizations {
+                busy_timeout_ms: 0,
+                // In WAL mode readers never wait on a writer
+                journal_mode: "DELETE".to_string(),
+                ..SqliteOptimizations::default()
+            },
+            ..DatabasePoolConfig::default()
+        };
+        let db = ThingsDatabase::new_with_config(db_path, config)
+            .await
+            .unwrap();
+        assert_eq!(db.connection_state().await, ConnectionState::Healthy);
+
+        let url = format!("sqlite:{}", db_path.display());
+        let mut holder = sqlx::SqliteConnection::connect(&url).await.unwrap();
+        sqlx::query("BEGIN EXCLUSIVE")
+            .execute(&mut holder)
+            .await
+            .unwrap();
+
+        assert_eq!(db.connection_state().await, ConnectionState::Busy);
+        assert!(db.is_connected().await);
+
+        holder.close().await.unwrap();
+        assert_eq!(db.connection_state().await, ConnectionState::Healthy);
+
+        db.pool().close().await;
+        assert_eq!(db.connection_state().await, ConnectionState::Down);
+        assert!(!db.is_connected().await);
+    }
+
+    #[tokio::test]
+    async fn test_new_rejects_missing_path_and_directory() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let missing = dir.path().join("missing.sqlite");
+        let error = ThingsDatabase::new(&missing).await.unwrap_err();
+        assert!(
+            matches!(&error, ThingsError::DatabaseNotFound { path } if path == &missing.display().to_string())
+        );
+
+        let error = ThingsDatabase::new(dir.path()).await.unwrap_err();
+        assert!(
+            matches!(&error, ThingsError::DatabaseNotFound { path } if path == &dir.path().display().to_string())
+        );
+
+        let error = ThingsDatabase::open_readonly_wal(dir.path())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, ThingsError::DatabaseNotFound { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_open_snapshot_includes_wal_and_is_isolated() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let db_path = temp_file.path();
+
+        crate::test_utils::create_test_database(db_path)
+            .await
+            .unwrap();
+
+        // One long-lived connection with auto-checkpointing off keeps new
+        // commits in the -wal file, like a database Things has open
+        let config = DatabasePoolConfig {
+            max_connections: 1,
+            ..DatabasePoolConfig::default()
+        };
+        let live = ThingsDatabase::new_with_config(db_path, config)
+            .await
+            .unwrap();
+        sqlx::query("PRAGMA wal_autocheckpoint = 0")
+            .execute(live.pool())
+            .await
+            .unwrap();
+        let request = |title: &str| {
+            crate::test_utils::TaskRequestBuilder::new()
+                .title(title)
+                .build()
+        };
+        let in_wal = live.create_task(request("Only in WAL")).await.unwrap();
+        assert!(Path::new(&format!("{}-wal", db_path.display())).exists());
+
+        let snapshot = ThingsDatabase::open_snapshot(db_path).await.unwrap();
+        assert!(snapshot.is_snapshot());
+        assert!(!live.is_snapshot());
+        assert!(snapshot.get_task_by_uuid(&in_wal).await.unwrap().is_some());
+
+        let later = live.create_task(request("After snapshot")).await.unwrap();
+        assert!(snapshot.get_task_by_uuid(&later).await.unwrap().is_none());
+        assert!(snapshot.create_task(request("Rejected")).await.is_err());
+
+        let copy_dir = snapshot
+            .pool()
+            .connect_options()
+            .get_filename()
+            .parent()
+            .unwrap()
+            .to_path_buf();
+        assert!(copy_dir.exists());
+        snapshot.pool().close().await;
+        drop(snapshot);
+        assert!(!copy_dir.exists());
+    }
+
+    #[tokio::test]
+    async fn test_open_readonly_wal_reads_while_writer_holds_lock() {
+        use sqlx::Connection;
+
+        let temp_file = NamedTempFile::new().unwrap();
+        let db_path = temp_file.path();
+
+        crate::test_utils::create_test_database(db_path)
+            .await
+            .unwrap();
+        // Puts the file in WAL mode, as Things does
+        let live = ThingsDatabase::new(db_path).await.unwrap();
+        let request = |title: &str| {
+            crate::test_utils::TaskRequestBuilder::new()
+                .title(title)
+                .build()
+        };
+        let committed = live.create_task(request("Committed")).await.unwrap();
+
+        // No SQLite-level waiting, so any lock contention fails immediately
+        let config = DatabasePoolConfig {
+            sqlite_optimizations: SqliteOptimizations {
+                busy_timeout_ms: 0,
+                ..SqliteOptimizations::default()
+            },
+            ..DatabasePoolConfig::default()
+        };
+        let reader = ThingsDatabase::open_readonly_wal_with_config(db_path, config)
+            .await
+            .unwrap();
+
+        let url = format!("sqlite:{}", db_path.display());
+        let mut writer = sqlx::SqliteConnection::connect(&url).await.unwrap();
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut writer)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE TMTask SET title = 'Uncommitted' WHERE uuid = ?")
+            .bind(committed.as_str())
+            .execute(&mut writer)
+            .await
+            .unwrap();
+
+        let task = reader.get_task_by_uuid(&committed).await.unwrap().unwrap();
+        assert_eq!(task.title, "Committed");
+        assert!(!reader.get_all_tasks().await.unwrap().is_empty());
+        assert!(reader.create_task(request("Rejected")).await.is_err());
+
+        sqlx::query("COMMIT").execute(&mut writer).await.unwrap();
+        let task = reader.get_task_by_uuid(&committed).await.unwrap().unwrap();
+        assert_eq!(task.title, "Uncommitted");
+    }
+
+    #[tokio::test]
+    async fn test_limited_queries_reuse_cached_statement() {
+        use sqlx::Connection;
+
+        let temp_file = NamedTempFile::new().unwrap();
+        let db_path = temp_file.path();
+
+        crate::test_utils::create_test_database(db_path)
+            .await
+            .unwrap();
+
+        // Single connection so every query shares one statement cache
+        let config = DatabasePoolConfig {
+            max_connections: 1,
+            ..DatabasePoolConfig::default()
+        };
+        let database = ThingsDatabase::new_with_config(db_path, config)
+            .await
+            .unwrap();
+
+        let cache_size = || async {
+            database
+                .pool()
+                .acquire()
+                .await
+                .unwrap()
+                .cached_statements_size()
+        };
+
+        let before = cache_size().await;
+        database.get_inbox(Some(1)).await.unwrap();
+        let after_inbox = cache_size().await;
+        assert!(after_inbox > before, "get_inbox should cache its statement");
+        database.get_inbox(Some(2)).await.unwrap();
+        database.get_inbox(Some(3)).await.unwrap();
+        assert_eq!(
+            cache_size().await,
+            after_inbox,
+            "get_inbox missed the cache"
+        );
+
+        database.get_today(Some(1)).await.unwrap();
+        let after_today = cache_size().await;
+        assert!(
+            after_today > after_inbox,
+            "get_today should cache its statement"
+        );
+        database.get_today(Some(2)).await.unwrap();
+        database.get_today(Some(3)).await.unwrap();
+        assert_eq!(
+            cache_size().await,
+            after_today,
+            "get_today missed the cache"
+        );
+    }
+
     #[tokio::test]
     async fn test_database_error_handling_invalid_path() {
         // Test with non-existent database path
@@ -465,6 +1233,51 @@ mod tests {
         assert!(stats.task_count > 0, "Should have test tasks");
         assert!(stats.area_count > 0, "Should have test areas");
         assert!(stats.total_items() > 0, "Should have total items");
+
+        let count = |sql: &'static str| {
+            let pool = database.pool().clone();
+            async move {
+                let n: i64 = sqlx::query_scalar(sql).fetch_one(&pool).await.unwrap();
+                u64::try_from(n).unwrap()
+            }
+        };
+        assert_eq!(stats.task_count, count("SELECT COUNT(*) FROM TMTask").await);
+        assert_eq!(
+            stats.project_count,
+            count("SELECT COUNT(*) FROM TMTask WHERE type = 1").await
+        );
+        assert_eq!(stats.area_count, count("SELECT COUNT(*) FROM TMArea").await);
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_truncates_wal() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let db_path = temp_file.path();
+
+        crate::test_utils::create_test_database(db_path)
+            .await
+            .unwrap();
+        let database = ThingsDatabase::new(db_path).await.unwrap();
+
+        sqlx::query("UPDATE TMTask SET title = title || ' (edited)'")
+            .execute(database.pool())
+            .await
+            .unwrap();
+
+        let passive = database.checkpoint(CheckpointMode::Passive).await.unwrap();
+        assert!(!passive.busy);
+        assert!(passive.log_frames > 0, "WAL should hold the update");
+        assert!(passive.checkpointed_frames <= passive.log_frames);
+
+        let truncated = database.checkpoint(CheckpointMode::Truncate).await.unwrap();
+        assert_eq!(
+            truncated,
+            CheckpointResult {
+                busy: false,
+                log_frames: 0,
+                checkpointed_frames: 0,
+            }
+        );
     }
 
     #[tokio::test]
@@ -955,12 +1768,7 @@ mod tests {
             status: TaskStatus,
         ) -> ThingsId {
             let raw_uuid = uuid::Uuid::new_v4();
-            let status_n: i64 = match status {
-                TaskStatus::Incomplete => 0,
-                TaskStatus::Canceled => 2,
-                TaskStatus::Completed => 3,
-                TaskStatus::Trashed => 0,
-            };
+            let status_n: i64 = status.code().unwrap_or(0).into();
             sqlx::query(
                 "INSERT INTO TMTask \
                  (uuid, title, notes, type, status, trashed, creationDate, userModificationDate) \
@@ -4,7 +4,8 @@
 //! (seconds since 2001-01-01) and standard date types, along with comprehensive
 //! validation to ensure date consistency.
 
-use chrono::{Datelike, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
 use thiserror::Error;
 
 /// Things 3 epoch: 2001-01-01 00:00:00 UTC
@@ -229,26 +230,78 @@ pub fn parse_date_from_string(s: &str) -> Result<NaiveDate, DateConversionError>
     })
 }
 
+/// Get the system's local timezone
+///
+/// Falls back to UTC if the zone can't be determined or isn't a known IANA name.
+#[must_use]
+pub fn system_timezone() -> Tz {
+    iana_time_zone::get_timezone()
+        .ok()
+        .and_then(|name| name.parse().ok())
+        .unwrap_or(Tz::UTC)
+}
+
+/// Get today's date in the given timezone
+#[must_use]
+pub fn today_in(tz: Tz) -> NaiveDate {
+    date_in(Utc::now(), tz)
+}
+
+/// Get the calendar date of an instant in the given timezone
+#[must_use]
+pub fn date_in(instant: DateTime<Utc>, tz: Tz) -> NaiveDate {
+    instant.with_timezone(&tz).date_naive()
+}
+
+/// Get the instant at which `date` begins in the given timezone
+///
+/// If local midnight doesn't exist (a DST gap), the first valid instant
+/// within the following hour is used.
+#[must_use]
+pub fn start_of_day_in(date: NaiveDate, tz: Tz) -> DateTime<Utc> {
+    local_datetime_in(date, NaiveTime::MIN, tz)
+}
+
+/// Get the instant of a local wall-clock `time` on `date` in the given timezone
+///
+/// Ambiguous times (DST fall-back) resolve to the earlier instant; times in a
+/// DST gap are shifted forward by an hour.
+#[must_use]
+pub fn local_datetime_in(date: NaiveDate, time: NaiveTime, tz: Tz) -> DateTime<Utc> {
+    let local = date.and_time(time);
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+        })
+        .map_or_else(|| local.and_utc(), |dt| dt.with_timezone(&Utc))
+}
+
 /// Check if a date is in the past
 ///
 /// # Arguments
 /// * `date` - Date to check
+/// * `tz` - Timezone that decides when today starts, usually [`crate::ThingsDatabase::timezone`]
 ///
 /// # Returns
-/// `true` if the date is before today (UTC), `false` otherwise
-pub fn is_date_in_past(date: NaiveDate) -> bool {
-    date < Utc::now().date_naive()
+/// `true` if the date is before today in `tz`, `false` otherwise
+#[must_use]
+pub fn is_date_in_past(date: NaiveDate, tz: Tz) -> bool {
+    date < today_in(tz)
 }
 
 /// Check if a date is in the future
 ///
 /// # Arguments
 /// * `date` - Date to check
+/// * `tz` - Timezone that decides when today starts, usually [`crate::ThingsDatabase::timezone`]
 ///
 /// # Returns
-/// `true` if the date is after today (UTC), `false` otherwise
-pub fn is_date_in_future(date: NaiveDate) -> bool {
-    date > Utc::now().date_naive()
+/// `true` if the date is after today in `tz`, `false` otherwise
+#[must_use]
+pub fn is_date_in_future(date: NaiveDate, tz: Tz) -> bool {
+    date > today_in(tz)
 }
 
 /// Add days to a date with overflow checking
@@ -268,6 +321,30 @@ pub fn add_days(date: NaiveDate, days: i64) -> Result<NaiveDate, DateConversionE
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_day_boundaries_respect_timezone() {
+        let tz: Tz = "America/Los_Angeles".parse().unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+
+        // Midnight in Los Angeles (PST, UTC-8) is 08:00 UTC
+        let start = start_of_day_in(date, tz);
+        assert_eq!(start.to_rfc3339(), "2026-01-15T08:00:00+00:00");
+
+        // 03:00 UTC on the 16th is still the 15th in Los Angeles
+        let late_evening = Utc.with_ymd_and_hms(2026, 1, 16, 3, 0, 0).unwrap();
+        assert_eq!(date_in(late_evening, tz), date);
+        assert_eq!(date_in(late_evening, Tz::UTC), date.succ_opt().unwrap());
+    }
+
+    #[test]
+    fn test_start_of_day_in_dst_gap() {
+        // Havana skips from 00:00 to 01:00 when DST starts
+        let tz: Tz = "America/Havana".parse().unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let start = start_of_day_in(date, tz);
+        assert_eq!(date_in(start, tz), date);
+    }
+
     #[test]
     fn test_is_valid_things_timestamp() {
         // Valid timestamps
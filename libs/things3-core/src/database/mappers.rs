@@ -5,15 +5,79 @@
 //! UUIDs that `SqlxBackend` generated for new entities. Both are valid
 //! [`ThingsId`] values; we wrap them via [`ThingsId::from_trusted`] without
 //! re-validating, since the DB is the source of truth.
+//!
+//! Where a column holds a value that can't be converted, [`map_task_row`]
+//! and [`map_project_row`] substitute a default rather than failing the whole
+//! query. [`map_task_row_with_diagnostics`] and
+//! [`map_project_row_with_diagnostics`] also log a warning each time that
+//! happens; `ThingsDatabase::with_mapping_diagnostics` turns it on for a
+//! database's queries.
 
 use crate::{
     database::{safe_timestamp_convert, things_date_to_naive_date},
-    error::Result as ThingsResult,
-    models::{Project, Task, TaskStatus, TaskType, ThingsId},
+    error::{Result as ThingsResult, ThingsError},
+    models::{Area, Project, Task, TaskStatus, TaskType, ThingsId},
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::sqlite::SqliteRow;
 use sqlx::Row;
+use std::fmt::Debug;
+use tracing::warn;
+
+/// Columns every task query selects, as expected by [`map_task_row`]
+pub(crate) const TASK_COLUMNS: &str = "uuid, title, type, status, notes, startDate, deadline, stopDate, creationDate, userModificationDate, project, area, heading, contact, \"index\", startBucket, (SELECT GROUP_CONCAT(tg.title, char(31)) FROM TMTaskTag tt JOIN TMTag tg ON tg.uuid = tt.tags WHERE tt.tasks = TMTask.uuid) AS tags_csv";
+
+/// Report that `column` of task `uuid` held `value` and was given a default
+fn note_default(diagnostics: bool, uuid: &ThingsId, column: &str, value: impl Debug) {
+    if diagnostics {
+        warn!(task = %uuid, column, value = ?value, "Substituted default for unexpected value");
+    }
+}
+
+/// Convert a Unix timestamp column, falling back to the epoch for NULL or out-of-range values
+fn task_timestamp(
+    diagnostics: bool,
+    uuid: &ThingsId,
+    column: &str,
+    ts: Option<f64>,
+) -> DateTime<Utc> {
+    let Some(ts) = ts else {
+        note_default(diagnostics, uuid, column, "NULL");
+        return DateTime::UNIX_EPOCH;
+    };
+    let secs = safe_timestamp_convert(ts);
+    if secs == 0 && ts.trunc() != 0.0 {
+        note_default(diagnostics, uuid, column, ts);
+    }
+    DateTime::from_timestamp(secs, 0).unwrap_or_else(|| {
+        note_default(diagnostics, uuid, column, ts);
+        Utc::now()
+    })
+}
+
+/// Convert a Things date column; a present value that isn't a valid date becomes `None`
+fn task_date(
+    diagnostics: bool,
+    uuid: &ThingsId,
+    column: &str,
+    value: Option<i64>,
+) -> Option<NaiveDate> {
+    let value = value?;
+    let date = things_date_to_naive_date(value);
+    if date.is_none() {
+        note_default(diagnostics, uuid, column, value);
+    }
+    date
+}
+
+/// Read `column`, failing if the query didn't select it or its value can't be decoded
+fn task_column<'r, T>(row: &'r SqliteRow, column: &str) -> ThingsResult<T>
+where
+    T: sqlx::Decode<'r, sqlx::Sqlite> + sqlx::Type<sqlx::Sqlite>,
+{
+    row.try_get(column)
+        .map_err(|e| ThingsError::unknown(format!("Failed to read task column {column}: {e}")))
+}
 
 /// Wrap a `uuid`-column string from the database as a [`ThingsId`].
 ///
@@ -36,50 +100,48 @@ fn optional_id_from_row(opt: Option<String>) -> Option<ThingsId> {
 ///
 /// Returns an error if required fields are missing or cannot be converted
 pub fn map_task_row(row: &SqliteRow) -> ThingsResult<Task> {
+    map_task_row_with_diagnostics(row, false)
+}
+
+/// Map a database row to a Task struct, optionally logging substituted defaults
+///
+/// Same as [`map_task_row`], but with `diagnostics` set each default
+/// substituted for an unconvertible value is logged with `warn!`, naming the
+/// task UUID, the column, and the value that was replaced. The mapped task is
+/// the same either way.
+///
+/// # Errors
+///
+/// Returns an error if required fields are missing or cannot be converted
+pub fn map_task_row_with_diagnostics(row: &SqliteRow, diagnostics: bool) -> ThingsResult<Task> {
     let uuid = id_from_row(row.get("uuid"));
 
     let title: String = row.get("title");
 
-    let status_i32: i32 = row.get("status");
-    let status = match status_i32 {
-        2 => TaskStatus::Canceled,
-        3 => TaskStatus::Completed,
-        _ => TaskStatus::Incomplete, // 0 = Incomplete; Trashed is filtered via trashed column, never a status value
-    };
+    // Trashed is filtered via the trashed column, never a status value
+    let status = TaskStatus::from_code(row.get("status"));
 
-    let type_i32: i32 = row.get("type");
-    let task_type = match type_i32 {
-        1 => TaskType::Project,
-        2 => TaskType::Heading,
-        _ => TaskType::Todo,
-    };
+    let task_type = TaskType::from_i32(row.get("type")).unwrap_or_else(|| {
+        note_default(diagnostics, &uuid, "type", row.get::<i32, _>("type"));
+        TaskType::Todo
+    });
 
     let notes: Option<String> = row.get("notes");
 
-    let start_date = row
-        .get::<Option<i64>, _>("startDate")
-        .and_then(things_date_to_naive_date);
-
-    let deadline = row
-        .get::<Option<i64>, _>("deadline")
-        .and_then(things_date_to_naive_date);
+    let start_date = task_date(diagnostics, &uuid, "startDate", row.get("startDate"));
+    let deadline = task_date(diagnostics, &uuid, "deadline", row.get("deadline"));
 
-    let creation_ts: f64 = row.get("creationDate");
-    let created = {
-        let ts = safe_timestamp_convert(creation_ts);
-        DateTime::from_timestamp(ts, 0).unwrap_or_else(Utc::now)
-    };
+    let created = task_timestamp(diagnostics, &uuid, "creationDate", row.get("creationDate"));
+    let modified = task_timestamp(
+        diagnostics,
+        &uuid,
+        "userModificationDate",
+        row.get("userModificationDate"),
+    );
 
-    let modification_ts: f64 = row.get("userModificationDate");
-    let modified = {
-        let ts = safe_timestamp_convert(modification_ts);
-        DateTime::from_timestamp(ts, 0).unwrap_or_else(Utc::now)
-    };
-
-    let stop_date = row.get::<Option<f64>, _>("stopDate").and_then(|ts| {
-        let ts_i64 = safe_timestamp_convert(ts);
-        DateTime::from_timestamp(ts_i64, 0)
-    });
+    let stop_date = row
+        .get::<Option<f64>, _>("stopDate")
+        .map(|ts| task_timestamp(diagnostics, &uuid, "stopDate", Some(ts)));
 
     let project_uuid = optional_id_from_row(row.get::<Option<String>, _>("project"));
     let area_uuid = optional_id_from_row(row.get::<Option<String>, _>("area"));
@@ -111,42 +173,107 @@ pub fn map_task_row(row: &SqliteRow) -> ThingsResult<Task> {
         area_uuid,
         parent_uuid,
         tags,
+        index: task_column(row, "index")?,
+        // `startBucket` is 1 for "This Evening"
+        evening: task_column::<Option<i64>>(row, "startBucket")? == Some(1),
+        assignee: task_column(row, "contact")?,
         children: Vec::new(),
     })
 }
 
 /// Map a `TMTask` row (where `type = 1`) into a [`Project`].
-pub fn map_project_row(row: &SqliteRow) -> Project {
-    Project {
+///
+/// Expects the columns `uuid`, `title`, `status`, `area`, `notes`,
+/// `startDate`, `deadline`, `creationDate` and `userModificationDate`.
+/// An optional `trashed` column sets [`Project::trashed`] (false if absent).
+/// Dates use the same conversions as [`map_task_row`], so a NULL timestamp
+/// maps to the Unix epoch.
+///
+/// # Errors
+///
+/// Returns an error if required fields are missing or cannot be converted
+pub fn map_project_row(row: &SqliteRow) -> ThingsResult<Project> {
+    map_project_row_with_diagnostics(row, false)
+}
+
+/// Map a `TMTask` project row, optionally logging substituted defaults
+///
+/// Same as [`map_project_row`], with `diagnostics` behaving as in
+/// [`map_task_row_with_diagnostics`].
+///
+/// # Errors
+///
+/// Returns an error if required fields are missing or cannot be converted
+pub fn map_project_row_with_diagnostics(
+    row: &SqliteRow,
+    diagnostics: bool,
+) -> ThingsResult<Project> {
+    let uuid = id_from_row(task_column(row, "uuid")?);
+
+    let status = TaskStatus::from_code(task_column(row, "status")?);
+
+    let start_date = task_date(
+        diagnostics,
+        &uuid,
+        "startDate",
+        task_column(row, "startDate")?,
+    );
+    let deadline = task_date(
+        diagnostics,
+        &uuid,
+        "deadline",
+        task_column(row, "deadline")?,
+    );
+
+    let created = task_timestamp(
+        diagnostics,
+        &uuid,
+        "creationDate",
+        task_column(row, "creationDate")?,
+    );
+    let modified = task_timestamp(
+        diagnostics,
+        &uuid,
+        "userModificationDate",
+        task_column(row, "userModificationDate")?,
+    );
+
+    Ok(Project {
+        uuid,
+        title: task_column(row, "title")?,
+        status,
+        area_uuid: optional_id_from_row(task_column(row, "area")?),
+        notes: task_column(row, "notes")?,
+        deadline,
+        start_date,
+        tags: Vec::new(),
+        tasks: Vec::new(),
+        created,
+        modified,
+        trashed: row.try_get::<i64, _>("trashed").unwrap_or(0) != 0,
+    })
+}
+
+/// Map a `TMArea` row into an [`Area`].
+///
+/// Expects the columns `uuid` and `title`. The Things 3 `TMArea` table has
+/// no creation or modification dates, so `created`/`modified` are set to the
+/// current time; change it here if that schema question is ever resolved.
+///
+/// # Errors
+///
+/// Returns an error if required fields are missing or cannot be converted
+pub fn map_area_row(row: &SqliteRow) -> ThingsResult<Area> {
+    let now = Utc::now();
+    Ok(Area {
         uuid: id_from_row(row.get("uuid")),
         title: row.get("title"),
-        status: match row.get::<i32, _>("status") {
-            1 => TaskStatus::Completed,
-            2 => TaskStatus::Canceled,
-            3 => TaskStatus::Trashed,
-            _ => TaskStatus::Incomplete,
-        },
-        area_uuid: optional_id_from_row(row.get::<Option<String>, _>("area")),
-        notes: row.get("notes"),
-        deadline: row
-            .get::<Option<i64>, _>("deadline")
-            .and_then(|ts| DateTime::from_timestamp(ts, 0))
-            .map(|dt| dt.date_naive()),
-        start_date: row
-            .get::<Option<i64>, _>("startDate")
-            .and_then(|ts| DateTime::from_timestamp(ts, 0))
-            .map(|dt| dt.date_naive()),
+        notes: None, // Notes not stored in TMArea table
+        projects: Vec::new(),
         tags: Vec::new(),
-        tasks: Vec::new(),
-        created: {
-            let ts = safe_timestamp_convert(row.get::<f64, _>("creationDate"));
-            DateTime::from_timestamp(ts, 0).unwrap_or_else(Utc::now)
-        },
-        modified: {
-            let ts = safe_timestamp_convert(row.get::<f64, _>("userModificationDate"));
-            DateTime::from_timestamp(ts, 0).unwrap_or_else(Utc::now)
-        },
-    }
+        created: now,
+        modified: now,
+    })
 }
 
 #[cfg(test)]
@@ -175,4 +302,131 @@ mod tests {
         let opt = optional_id_from_row(Some("ABC123XYZ456789012345".to_string()));
         assert_eq!(opt.unwrap().as_str(), "ABC123XYZ456789012345");
     }
+
+    #[tokio::test]
+    async fn mapping_diagnostics_warn_without_changing_defaults() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone, Default)]
+        struct Capture(Arc<Mutex<Vec<u8>>>);
+        impl std::io::Write for Capture {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let row = sqlx::query(
+            "SELECT 'bad-task' AS uuid, 'Bad' AS title, 0 AS status, 7 AS type, NULL AS notes, \
+             -5 AS startDate, NULL AS deadline, NULL AS creationDate, 1e12 AS userModificationDate, \
+             NULL AS stopDate, NULL AS project, NULL AS area, NULL AS heading, NULL AS contact, NULL AS \"index\", \
+             NULL AS startBucket, NULL AS tags_csv",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let map_capturing = |diagnostics: bool| {
+            let capture = Capture::default();
+            let writer = capture.clone();
+            let subscriber = tracing_subscriber::fmt()
+                .with_writer(move || writer.clone())
+                .with_ansi(false)
+                .finish();
+            let task = tracing::subscriber::with_default(subscriber, || {
+                map_task_row_with_diagnostics(&row, diagnostics)
+            })
+            .unwrap();
+            let logs = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+            (task, logs)
+        };
+
+        let (quiet, logs) = map_capturing(false);
+        assert!(logs.is_empty());
+
+        let (task, logs) = map_capturing(true);
+
+        assert_eq!(
+            serde_json::to_value(&task).unwrap(),
+            serde_json::to_value(&quiet).unwrap()
+        );
+        assert_eq!(task.task_type, TaskType::Todo);
+        assert_eq!(task.start_date, None);
+        assert_eq!(task.created, DateTime::UNIX_EPOCH);
+        assert_eq!(task.modified, DateTime::UNIX_EPOCH);
+        for column in ["type", "startDate", "creationDate", "userModificationDate"] {
+            assert!(
+                logs.contains(&format!("column=\"{column}\"")),
+                "no warning for {column}: {logs}"
+            );
+        }
+        assert!(logs.contains("task=bad-task"));
+        assert!(!logs.contains("deadline"));
+    }
+
+    #[tokio::test]
+    async fn map_project_row_maps_null_timestamps_to_epoch() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let row = sqlx::query(
+            "SELECT 'project' AS uuid, 'Project' AS title, 0 AS status, NULL AS area, \
+             NULL AS notes, NULL AS startDate, NULL AS deadline, NULL AS creationDate, \
+             NULL AS userModificationDate",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let project = map_project_row_with_diagnostics(&row, true).unwrap();
+        assert_eq!(project.created, DateTime::UNIX_EPOCH);
+        assert_eq!(project.modified, DateTime::UNIX_EPOCH);
+        assert!(!project.trashed);
+    }
+
+    #[tokio::test]
+    async fn map_project_row_rejects_missing_and_mistyped_columns() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let missing = sqlx::query(
+            "SELECT 'project' AS uuid, 'Project' AS title, 0 AS status, NULL AS area, \
+             NULL AS notes, NULL AS startDate, NULL AS deadline, 0.0 AS creationDate",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let error = map_project_row(&missing).unwrap_err();
+        assert!(
+            error.to_string().contains("userModificationDate"),
+            "{error}"
+        );
+
+        let mistyped = sqlx::query(
+            "SELECT 'project' AS uuid, 'Project' AS title, 'open' AS status, NULL AS area, \
+             NULL AS notes, NULL AS startDate, NULL AS deadline, 0.0 AS creationDate, \
+             0.0 AS userModificationDate",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let error = map_project_row(&mistyped).unwrap_err();
+        assert!(error.to_string().contains("status"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn map_task_row_rejects_missing_list_columns() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let row = sqlx::query(
+            "SELECT 'task' AS uuid, 'Task' AS title, 0 AS status, 0 AS type, NULL AS notes, \
+             NULL AS startDate, NULL AS deadline, 0.0 AS creationDate, 0.0 AS userModificationDate, \
+             NULL AS stopDate, NULL AS project, NULL AS area, NULL AS heading, NULL AS tags_csv",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let error = map_task_row(&row).unwrap_err();
+        assert!(error.to_string().contains("index"), "{error}");
+    }
 }
@@ -4,7 +4,9 @@ pub(crate) mod conversions;
 mod core;
 pub mod date_utils;
 pub mod mappers;
+mod multi;
 mod mutations;
+pub mod pagination;
 pub(crate) mod path_discovery;
 pub(crate) mod pool;
 mod queries;
@@ -18,35 +20,49 @@ pub use core::*;
 
 // Re-export conversions
 pub use conversions::{
-    deserialize_tags_from_blob, naive_date_to_things_timestamp, serialize_tags_to_blob,
+    deserialize_tags_from_blob, deserialize_tags_from_blob_as, naive_date_to_things_timestamp,
+    serialize_tags_to_blob, serialize_tags_to_blob_as, TagBlobFormat,
 };
 // Crate-internal helpers used by sibling submodules (mappers.rs, core.rs).
 pub(crate) use conversions::{safe_timestamp_convert, things_date_to_naive_date};
 
+// Re-export multi-database fan-out
+pub use multi::{MultiDatabaseResults, ThingsMultiDatabase};
+
 // Re-export path discovery
-pub use path_discovery::get_default_database_path;
+pub use path_discovery::{discover_database_paths, get_default_database_path};
 
 // Re-export pool/health types
 pub use pool::{
-    ComprehensiveHealthStatus, DatabasePoolConfig, PoolHealthStatus, PoolMetrics,
-    SqliteOptimizations,
+    CheckpointMode, CheckpointResult, ComprehensiveHealthStatus, ConnectionState,
+    DatabasePoolConfig, PoolHealthStatus, PoolMetrics, SqliteOptimizations,
 };
 
 // Re-export stats
-pub use stats::DatabaseStats;
+pub use stats::{DatabaseStats, InboxAgeStats};
 
 // Re-export mapper functions for easy access
-pub use mappers::{map_project_row, map_task_row};
+pub use mappers::{
+    map_area_row, map_project_row, map_project_row_with_diagnostics, map_task_row,
+    map_task_row_with_diagnostics,
+};
+
+// Re-export pagination helper
+pub use pagination::{paginate, Paginated};
 
 // Re-export query builders
 pub use query_builders::TaskUpdateBuilder;
 
+// Re-export tag normalization rules
+pub use tag_utils::TagNormalizationConfig;
+
 // Re-export validators
 pub use validators::{validate_area_exists, validate_project_exists, validate_task_exists};
 
 // Re-export date utilities
 pub use date_utils::{
-    add_days, format_date_for_display, is_date_in_future, is_date_in_past,
-    is_valid_things_timestamp, parse_date_from_string, safe_naive_date_to_things_timestamp,
-    safe_things_date_to_naive_date, validate_date_range, DateConversionError, DateValidationError,
+    add_days, date_in, format_date_for_display, is_date_in_future, is_date_in_past,
+    is_valid_things_timestamp, local_datetime_in, parse_date_from_string,
+    safe_naive_date_to_things_timestamp, safe_things_date_to_naive_date, start_of_day_in,
+    system_timezone, today_in, validate_date_range, DateConversionError, DateValidationError,
 };
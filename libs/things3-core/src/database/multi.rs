@@ -0,0 +1,203 @@
+//! Querying several named Things databases side by side.
+
+use std::future::Future;
+
+use tracing::instrument;
+
+use crate::{
+    database::ThingsDatabase,
+    error::{Result as ThingsResult, ThingsError},
+    models::Task,
+};
+
+/// Results of a fan-out query, one entry per database in insertion order
+///
+/// A failure in one database is reported in its own entry and does not
+/// affect the others.
+pub type MultiDatabaseResults<T> = Vec<(String, ThingsResult<T>)>;
+
+/// A set of named [`ThingsDatabase`] instances queried together
+///
+/// # Examples
+///
+/// ```no_run
+/// use things3_core::{ThingsDatabase, ThingsMultiDatabase};
+/// use std::path::Path;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let multi = ThingsMultiDatabase::new()
+///     .with_database("personal", ThingsDatabase::new(Path::new("personal.sqlite")).await?)
+///     .with_database("work", ThingsDatabase::new(Path::new("work.sqlite")).await?);
+///
+/// for (name, inbox) in multi.get_inbox_all(None).await {
+///     match inbox {
+///         Ok(tasks) => println!("{name}: {} inbox tasks", tasks.len()),
+///         Err(e) => eprintln!("{name}: {e}"),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ThingsMultiDatabase {
+    databases: Vec<(String, ThingsDatabase)>,
+}
+
+impl ThingsMultiDatabase {
+    /// Create an empty set of databases
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a database under `name`, replacing any database already using that name
+    #[must_use]
+    pub fn with_database(mut self, name: impl Into<String>, db: ThingsDatabase) -> Self {
+        let name = name.into();
+        match self.databases.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 = db,
+            None => self.databases.push((name, db)),
+        }
+        self
+    }
+
+    /// Get the database registered under `name`
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&ThingsDatabase> {
+        self.databases
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, db)| db)
+    }
+
+    /// Names of the registered databases, in insertion order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.databases.iter().map(|(n, _)| n.as_str())
+    }
+
+    /// Number of registered databases
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.databases.len()
+    }
+
+    /// Whether no databases are registered
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.databases.is_empty()
+    }
+
+    /// Get the inbox of every database
+    #[instrument(skip(self))]
+    pub async fn get_inbox_all(&self, limit: Option<usize>) -> MultiDatabaseResults<Vec<Task>> {
+        self.fan_out(move |db| async move { db.get_inbox(limit).await })
+            .await
+    }
+
+    /// Get the Today list of every database
+    #[instrument(skip(self))]
+    pub async fn get_today_all(&self, limit: Option<usize>) -> MultiDatabaseResults<Vec<Task>> {
+        self.fan_out(move |db| async move { db.get_today(limit).await })
+            .await
+    }
+
+    /// Search tasks in every database
+    #[instrument(skip(self))]
+    pub async fn search_tasks_all(&self, query: &str) -> MultiDatabaseResults<Vec<Task>> {
+        self.fan_out(|db| {
+            let query = query.to_string();
+            async move { db.search_tasks(&query).await }
+        })
+        .await
+    }
+
+    /// Run `op` against every database concurrently, keeping insertion order
+    async fn fan_out<T, F, Fut>(&self, op: F) -> MultiDatabaseResults<T>
+    where
+        F: Fn(ThingsDatabase) -> Fut,
+        Fut: Future<Output = ThingsResult<T>> + Send + 'static,
+        T: Send + 'static,
+    {
+        let handles: Vec<_> = self
+            .databases
+            .iter()
+            .map(|(name, db)| (name.clone(), tokio::spawn(op(db.clone()))))
+            .collect();
+
+        let mut results = Vec::with_capacity(handles.len());
+        for (name, handle) in handles {
+            let result = handle.await.unwrap_or_else(|e| {
+                Err(ThingsError::unknown(format!(
+                    "Query against database '{name}' panicked: {e}"
+                )))
+            });
+            results.push((name, result));
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::create_test_database;
+    use tempfile::NamedTempFile;
+
+    async fn open_test_db() -> (ThingsDatabase, NamedTempFile) {
+        let file = NamedTempFile::new().unwrap();
+        create_test_database(file.path()).await.unwrap();
+        let db = ThingsDatabase::new(file.path()).await.unwrap();
+        (db, file)
+    }
+
+    #[tokio::test]
+    async fn test_get_inbox_all_labels_results_by_name() {
+        let (personal, _personal_file) = open_test_db().await;
+        let (work, _work_file) = open_test_db().await;
+        let expected = personal.get_inbox(None).await.unwrap().len();
+
+        let multi = ThingsMultiDatabase::new()
+            .with_database("personal", personal)
+            .with_database("work", work);
+        assert_eq!(multi.names().collect::<Vec<_>>(), ["personal", "work"]);
+
+        let results = multi.get_inbox_all(None).await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "personal");
+        assert_eq!(results[1].0, "work");
+        for (_, inbox) in &results {
+            assert_eq!(inbox.as_ref().unwrap().len(), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failing_database_does_not_fail_the_others() {
+        let (personal, _personal_file) = open_test_db().await;
+        let (work, _work_file) = open_test_db().await;
+        sqlx::query("DROP TABLE TMTask")
+            .execute(work.pool())
+            .await
+            .unwrap();
+
+        let multi = ThingsMultiDatabase::new()
+            .with_database("personal", personal)
+            .with_database("work", work);
+
+        let results = multi.search_tasks_all("a").await;
+        assert!(results[0].1.is_ok());
+        assert!(results[1].1.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_with_database_replaces_same_name() {
+        let (first, _first_file) = open_test_db().await;
+        let (second, _second_file) = open_test_db().await;
+
+        let multi = ThingsMultiDatabase::new()
+            .with_database("personal", first)
+            .with_database("personal", second);
+        assert_eq!(multi.len(), 1);
+        assert!(multi.get("personal").is_some());
+        assert!(multi.get("work").is_none());
+    }
+}
@@ -93,21 +93,23 @@ impl ThingsDatabase {
         let now = Utc::now().timestamp() as f64;
 
         // Orphan all projects in this area (set area to NULL)
-        sqlx::query(
+        self.retry_busy(|| sqlx::query(
             "UPDATE TMTask SET area = NULL, userModificationDate = ? WHERE area = ? AND type = 1 AND trashed = 0",
         )
         .bind(now)
         .bind(id.as_str())
-        .execute(&self.pool)
+        .execute(&self.pool))
         .await
         .map_err(|e| ThingsError::unknown(format!("Failed to orphan projects in area: {e}")))?;
 
         // Delete the area (hard delete)
-        sqlx::query("DELETE FROM TMArea WHERE uuid = ?")
-            .bind(id.as_str())
-            .execute(&self.pool)
-            .await
-            .map_err(|e| ThingsError::unknown(format!("Failed to delete area: {e}")))?;
+        self.retry_busy(|| {
+            sqlx::query("DELETE FROM TMArea WHERE uuid = ?")
+                .bind(id.as_str())
+                .execute(&self.pool)
+        })
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to delete area: {e}")))?;
 
         info!("Deleted area with UUID: {}", id);
         Ok(())
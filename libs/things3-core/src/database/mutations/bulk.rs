@@ -4,13 +4,61 @@ use crate::{
 };
 use chrono::Utc;
 use sqlx::Row;
-use tracing::{info, instrument};
+use std::collections::HashSet;
+use tracing::{debug, info, instrument};
 
 impl ThingsDatabase {
     /// Maximum number of tasks that can be processed in a single bulk operation
     /// This prevents abuse and ensures reasonable transaction sizes
     const MAX_BULK_BATCH_SIZE: usize = 1000;
 
+    /// Return the task UUIDs from `uuids` that don't exist or are trashed
+    ///
+    /// Checks the whole batch with `WHERE uuid IN (...)` queries instead of one
+    /// lookup per task, so bulk callers can reject bad input before writing.
+    /// Missing UUIDs are returned once each, in input order; an empty result
+    /// means every UUID is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn validate_tasks_exist(
+        &self,
+        uuids: &[crate::models::ThingsId],
+    ) -> ThingsResult<Vec<crate::models::ThingsId>> {
+        let mut found = HashSet::new();
+        for chunk in uuids.chunks(Self::MAX_BULK_BATCH_SIZE) {
+            let placeholders = vec!["?"; chunk.len()].join(",");
+            let query_str =
+                format!("SELECT uuid FROM TMTask WHERE uuid IN ({placeholders}) AND trashed = 0");
+            let mut query = sqlx::query_scalar::<_, String>(&query_str);
+            for id in chunk {
+                query = query.bind(id.as_str());
+            }
+            found.extend(
+                query
+                    .fetch_all(&self.pool)
+                    .await
+                    .map_err(|e| ThingsError::unknown(format!("Failed to validate tasks: {e}")))?,
+            );
+        }
+
+        let mut seen = HashSet::new();
+        let missing: Vec<crate::models::ThingsId> = uuids
+            .iter()
+            .filter(|id| !found.contains(id.as_str()) && seen.insert(id.as_str()))
+            .cloned()
+            .collect();
+
+        debug!(
+            "{} of {} task UUID(s) not found",
+            missing.len(),
+            uuids.len()
+        );
+        Ok(missing)
+    }
+
     /// Move multiple tasks to a project or area (transactional)
     ///
     /// All tasks must exist and be valid, or the entire operation will be rolled back.
@@ -299,6 +347,109 @@ impl ThingsDatabase {
         })
     }
 
+    /// Set the same start date on multiple tasks (transactional)
+    ///
+    /// Meant for sprint planning: every task is validated to exist and to keep
+    /// its existing deadline on or after `date`, then all start dates are
+    /// written in one transaction. Deadlines are left untouched. Duplicate IDs
+    /// are scheduled once. Returns the number of tasks updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Task UUIDs array is empty or exceeds the bulk batch size
+    /// - Any task doesn't exist or is trashed
+    /// - `date` would fall after a task's deadline
+    /// - Database operation fails
+    #[instrument(skip(self))]
+    pub async fn schedule_tasks(
+        &self,
+        task_uuids: &[crate::models::ThingsId],
+        date: chrono::NaiveDate,
+    ) -> ThingsResult<usize> {
+        use crate::database::{safe_things_date_to_naive_date, validate_date_range};
+
+        let mut ids: Vec<&crate::models::ThingsId> = Vec::with_capacity(task_uuids.len());
+        for id in task_uuids {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+
+        if ids.is_empty() {
+            return Err(ThingsError::validation("Task UUIDs cannot be empty"));
+        }
+        if ids.len() > Self::MAX_BULK_BATCH_SIZE {
+            return Err(ThingsError::validation(format!(
+                "Batch size {} exceeds maximum of {}",
+                ids.len(),
+                Self::MAX_BULK_BATCH_SIZE
+            )));
+        }
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to begin transaction: {e}")))?;
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let query_str = format!(
+            "SELECT uuid, deadline FROM TMTask WHERE uuid IN ({placeholders}) AND trashed = 0"
+        );
+        let mut query = sqlx::query(&query_str);
+        for id in &ids {
+            query = query.bind(id.as_str());
+        }
+        let rows = query
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to validate tasks: {e}")))?;
+
+        if rows.len() != ids.len() {
+            let found_uuids: Vec<String> = rows.iter().map(|row| row.get("uuid")).collect();
+            if let Some(missing) = ids
+                .iter()
+                .find(|id| !found_uuids.iter().any(|found| found == id.as_str()))
+            {
+                tx.rollback().await.ok();
+                return Err(ThingsError::TaskNotFound {
+                    uuid: missing.to_string(),
+                });
+            }
+        }
+
+        for row in &rows {
+            let deadline = row
+                .get::<Option<i64>, _>("deadline")
+                .and_then(|ts| safe_things_date_to_naive_date(ts).ok());
+            validate_date_range(Some(date), deadline)?;
+        }
+
+        let now = Utc::now().timestamp() as f64;
+        let query_str = format!(
+            "UPDATE TMTask SET startDate = ?, userModificationDate = ? WHERE uuid IN ({placeholders})"
+        );
+        let mut query = sqlx::query(&query_str)
+            .bind(naive_date_to_things_timestamp(date))
+            .bind(now);
+        for id in &ids {
+            query = query.bind(id.as_str());
+        }
+        let updated = query
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to schedule tasks: {e}")))?
+            .rows_affected();
+
+        tx.commit()
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to commit transaction: {e}")))?;
+
+        info!("Scheduled {} task(s) to start {}", updated, date);
+        Ok(usize::try_from(updated).unwrap_or(usize::MAX))
+    }
+
     /// Complete multiple tasks (transactional)
     ///
     /// All tasks must exist, or the entire operation will be rolled back.
@@ -0,0 +1,120 @@
+use crate::{
+    database::{validators, ThingsDatabase},
+    error::{Result as ThingsResult, ThingsError},
+    models::{ChecklistItemInput, ThingsId},
+};
+use chrono::Utc;
+use sqlx::Row;
+use std::collections::HashMap;
+use tracing::{info, instrument};
+
+impl ThingsDatabase {
+    /// Replace a task's checklist items (transactional)
+    ///
+    /// Deletes every existing `TMChecklistItem` row for the task and inserts
+    /// `items` in order with sequential `index` values. Items with
+    /// `completed: None` keep the status of an existing item with the same
+    /// title; unmatched items start incomplete. The task's
+    /// `checklistItemsCount` and `openChecklistItemsCount` are updated in the
+    /// same transaction so Things shows the new counts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task doesn't exist or if the database operation fails
+    #[instrument(skip(self))]
+    pub async fn set_checklist_items(
+        &self,
+        task_uuid: &ThingsId,
+        items: Vec<ChecklistItemInput>,
+    ) -> ThingsResult<()> {
+        validators::validate_task_exists(&self.pool, task_uuid).await?;
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to begin transaction: {e}")))?;
+
+        // Remember the completion state of current items so it survives the replace
+        let rows =
+            sqlx::query("SELECT title, status, stopDate FROM TMChecklistItem WHERE task = ?")
+                .bind(task_uuid.as_str())
+                .fetch_all(&mut *tx)
+                .await
+                .map_err(|e| {
+                    ThingsError::unknown(format!("Failed to fetch checklist items: {e}"))
+                })?;
+
+        let mut existing: HashMap<String, (i32, Option<f64>)> = HashMap::new();
+        for row in &rows {
+            let title: Option<String> = row.get("title");
+            if let Some(title) = title {
+                existing
+                    .entry(title)
+                    .or_insert((row.get("status"), row.get("stopDate")));
+            }
+        }
+
+        sqlx::query("DELETE FROM TMChecklistItem WHERE task = ?")
+            .bind(task_uuid.as_str())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to delete checklist items: {e}")))?;
+
+        let now = Utc::now().timestamp() as f64;
+        let mut open_count = 0_i64;
+        for (index, item) in items.iter().enumerate() {
+            let (status, stop_date) = match item.completed {
+                Some(true) => (3, Some(now)),
+                Some(false) => (0, None),
+                None => existing.get(&item.title).copied().unwrap_or((0, None)),
+            };
+            if status == 0 {
+                open_count += 1;
+            }
+
+            sqlx::query(
+                r"
+                INSERT INTO TMChecklistItem (
+                    uuid, title, status, stopDate, task, `index`,
+                    creationDate, userModificationDate, leavesTombstone
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, 0)
+                ",
+            )
+            .bind(ThingsId::new_things_native().as_str())
+            .bind(&item.title)
+            .bind(status)
+            .bind(stop_date)
+            .bind(task_uuid.as_str())
+            .bind(index as i64)
+            .bind(now)
+            .bind(now)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to insert checklist item: {e}")))?;
+        }
+
+        sqlx::query(
+            "UPDATE TMTask SET userModificationDate = ?, checklistItemsCount = ?, \
+             openChecklistItemsCount = ? WHERE uuid = ?",
+        )
+        .bind(now)
+        .bind(items.len() as i64)
+        .bind(open_count)
+        .bind(task_uuid.as_str())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to update task: {e}")))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to commit transaction: {e}")))?;
+
+        info!(
+            "Set {} checklist item(s) on task with UUID: {}",
+            items.len(),
+            task_uuid
+        );
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 mod areas;
 mod bulk;
+mod checklist;
 mod projects;
 mod tags;
 mod tasks;
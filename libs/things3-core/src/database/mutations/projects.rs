@@ -246,6 +246,37 @@ impl ThingsDatabase {
         Ok(())
     }
 
+    /// Put a project on hold, or make it active again
+    ///
+    /// Things has no paused status for projects; it parks them in Someday.
+    /// `on_hold` moves the project to Someday (`start = 2`) and clears its
+    /// start date, as Things does; `false` moves it back to Anytime
+    /// (`start = 1`). Use [`Self::get_all_projects_with_options`] to leave
+    /// on-hold projects out.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project doesn't exist or if the database update fails
+    #[instrument(skip(self))]
+    pub async fn set_project_on_hold(&self, id: &ThingsId, on_hold: bool) -> ThingsResult<()> {
+        validators::validate_project_exists(&self.pool, id).await?;
+
+        let sql = if on_hold {
+            "UPDATE TMTask SET start = 2, startDate = NULL, userModificationDate = ? WHERE uuid = ?"
+        } else {
+            "UPDATE TMTask SET start = 1, userModificationDate = ? WHERE uuid = ?"
+        };
+        sqlx::query(sql)
+            .bind(Utc::now().timestamp() as f64)
+            .bind(id.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to update project start: {e}")))?;
+
+        info!("Set project {} on hold: {}", id, on_hold);
+        Ok(())
+    }
+
     /// Soft delete a project and handle its child tasks
     ///
     /// # Errors
@@ -285,37 +316,137 @@ impl ThingsDatabase {
             }
             crate::models::ProjectChildHandling::Cascade => {
                 // Delete all child tasks
-                sqlx::query(
+                self.retry_busy(|| sqlx::query(
                     "UPDATE TMTask SET trashed = 1, userModificationDate = ? WHERE project = ? AND trashed = 0",
                 )
                 .bind(now)
                 .bind(id.as_str())
-                .execute(&self.pool)
+                .execute(&self.pool))
                 .await
                 .map_err(|e| ThingsError::unknown(format!("Failed to delete child tasks: {e}")))?;
             }
             crate::models::ProjectChildHandling::Orphan => {
                 // Move child tasks to inbox (set project to NULL)
-                sqlx::query(
+                self.retry_busy(|| sqlx::query(
                     "UPDATE TMTask SET project = NULL, userModificationDate = ? WHERE project = ? AND trashed = 0",
                 )
                 .bind(now)
                 .bind(id.as_str())
-                .execute(&self.pool)
+                .execute(&self.pool))
                 .await
                 .map_err(|e| ThingsError::unknown(format!("Failed to orphan child tasks: {e}")))?;
             }
         }
 
         // Delete the project
-        sqlx::query("UPDATE TMTask SET trashed = 1, userModificationDate = ? WHERE uuid = ?")
+        self.retry_busy(|| {
+            sqlx::query("UPDATE TMTask SET trashed = 1, userModificationDate = ? WHERE uuid = ?")
+                .bind(now)
+                .bind(id.as_str())
+                .execute(&self.pool)
+        })
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to delete project: {e}")))?;
+
+        info!("Deleted project with UUID: {}", id);
+        Ok(())
+    }
+
+    /// Restore a soft-deleted project and handle its trashed child tasks
+    ///
+    /// Inverse of [`Self::delete_project`]. Only children trashed together
+    /// with the project count: those whose `userModificationDate` is at or
+    /// after the project's, which is when it was trashed. Tasks the user
+    /// trashed before deleting the project stay in the Trash in every mode.
+    /// With `Cascade`, the project's trashed children are restored too; with
+    /// `Orphan`, they stay in the Trash; with `Error`, the restore is refused
+    /// if any exist. Runs in a single transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project doesn't exist or isn't trashed, if child
+    /// handling fails, or if the database update fails
+    #[instrument(skip(self))]
+    pub async fn restore_project(
+        &self,
+        id: &ThingsId,
+        child_handling: crate::models::ProjectChildHandling,
+    ) -> ThingsResult<()> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to begin transaction: {e}")))?;
+
+        let project: Option<(i64, f64)> = sqlx::query_as(
+            "SELECT trashed, userModificationDate FROM TMTask WHERE uuid = ? AND type = 1",
+        )
+        .bind(id.as_str())
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch project: {e}")))?;
+
+        let trashed_at = match project {
+            None => {
+                return Err(ThingsError::ProjectNotFound {
+                    uuid: id.to_string(),
+                })
+            }
+            Some((0, _)) => {
+                return Err(ThingsError::validation(format!(
+                    "Project {id} is not in the Trash"
+                )))
+            }
+            Some((_, trashed_at)) => trashed_at,
+        };
+
+        let now = Utc::now().timestamp() as f64;
+
+        match child_handling {
+            crate::models::ProjectChildHandling::Error => {
+                let child_count: i64 = sqlx::query_scalar(
+                    "SELECT COUNT(*) FROM TMTask WHERE project = ? AND trashed = 1 AND userModificationDate >= ?",
+                )
+                .bind(id.as_str())
+                .bind(trashed_at)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| {
+                    ThingsError::unknown(format!("Failed to check for child tasks: {e}"))
+                })?;
+
+                if child_count > 0 {
+                    return Err(ThingsError::validation(format!(
+                        "Project {id} has {child_count} trashed child task(s). Use cascade to restore them or orphan to leave them in the Trash."
+                    )));
+                }
+            }
+            crate::models::ProjectChildHandling::Cascade => {
+                sqlx::query(
+                    "UPDATE TMTask SET trashed = 0, userModificationDate = ? WHERE project = ? AND trashed = 1 AND userModificationDate >= ?",
+                )
+                .bind(now)
+                .bind(id.as_str())
+                .bind(trashed_at)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| ThingsError::unknown(format!("Failed to restore child tasks: {e}")))?;
+            }
+            crate::models::ProjectChildHandling::Orphan => {}
+        }
+
+        sqlx::query("UPDATE TMTask SET trashed = 0, userModificationDate = ? WHERE uuid = ?")
             .bind(now)
             .bind(id.as_str())
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await
-            .map_err(|e| ThingsError::unknown(format!("Failed to delete project: {e}")))?;
+            .map_err(|e| ThingsError::unknown(format!("Failed to restore project: {e}")))?;
 
-        info!("Deleted project with UUID: {}", id);
+        tx.commit()
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to commit transaction: {e}")))?;
+
+        info!("Restored project with UUID: {}", id);
         Ok(())
     }
 }
@@ -1,11 +1,26 @@
 use crate::{
-    database::{validators, ThingsDatabase},
+    database::{
+        conversions::safe_timestamp_convert, tag_utils::DEFAULT_TAG_SIMILARITY, validators,
+        ThingsDatabase,
+    },
     error::{Result as ThingsResult, ThingsError},
-    models::ThingsId,
+    models::{TagUsageChange, TagUsageReport, ThingsId},
 };
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
 use tracing::{info, instrument};
 
+/// Reject similarity thresholds outside `0.0..=1.0`
+fn check_similarity_threshold(min_similarity: f32) -> ThingsResult<()> {
+    if (0.0..=1.0).contains(&min_similarity) {
+        Ok(())
+    } else {
+        Err(ThingsError::validation(format!(
+            "Tag similarity threshold must be between 0.0 and 1.0, got {min_similarity}"
+        )))
+    }
+}
+
 impl ThingsDatabase {
     /// Create a tag with smart duplicate detection
     ///
@@ -14,6 +29,9 @@ impl ThingsDatabase {
     /// - `Existing`: Exact match found (case-insensitive)
     /// - `SimilarFound`: Similar tags found (user decision needed)
     ///
+    /// Tags at least [`DEFAULT_TAG_SIMILARITY`] similar count as near-duplicates;
+    /// see [`Self::create_tag_smart_with_threshold`] to choose another cutoff.
+    ///
     /// # Errors
     ///
     /// Returns an error if the database operation fails
@@ -22,11 +40,31 @@ impl ThingsDatabase {
         &self,
         request: crate::models::CreateTagRequest,
     ) -> ThingsResult<crate::models::TagCreationResult> {
-        use crate::database::tag_utils::normalize_tag_title;
+        self.create_tag_smart_with_threshold(request, DEFAULT_TAG_SIMILARITY)
+            .await
+    }
+
+    /// [`Self::create_tag_smart`] with a custom similarity threshold
+    ///
+    /// Existing tags scoring at least `min_similarity` (0.0 to 1.0) are returned
+    /// as `SimilarFound`. Raise it to merge less eagerly, lower it to suggest more.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `min_similarity` is outside `0.0..=1.0`,
+    /// or an error if the database operation fails
+    #[instrument(skip(self))]
+    pub async fn create_tag_smart_with_threshold(
+        &self,
+        request: crate::models::CreateTagRequest,
+        min_similarity: f32,
+    ) -> ThingsResult<crate::models::TagCreationResult> {
         use crate::models::TagCreationResult;
 
+        check_similarity_threshold(min_similarity)?;
+
         // 1. Normalize the title
-        let normalized = normalize_tag_title(&request.title);
+        let normalized = self.tag_normalization.normalize(&request.title);
 
         // 2. Check for exact match (case-insensitive)
         if let Some(existing) = self.find_tag_by_normalized_title(&normalized).await? {
@@ -36,8 +74,8 @@ impl ThingsDatabase {
             });
         }
 
-        // 3. Find similar tags (fuzzy matching with 80% threshold)
-        let similar_tags = self.find_similar_tags(&normalized, 0.8).await?;
+        // 3. Find similar tags (fuzzy matching)
+        let similar_tags = self.find_similar_tags(&normalized, min_similarity).await?;
 
         // 4. If similar tags found, return them for user decision
         if !similar_tags.is_empty() {
@@ -48,6 +86,9 @@ impl ThingsDatabase {
         }
 
         // 5. No duplicates, safe to create
+        if let Some(shortcut) = &request.shortcut {
+            self.ensure_tag_shortcut_available(shortcut, None).await?;
+        }
         let id = ThingsId::new_things_native();
 
         sqlx::query(
@@ -79,6 +120,9 @@ impl ThingsDatabase {
         &self,
         request: crate::models::CreateTagRequest,
     ) -> ThingsResult<ThingsId> {
+        if let Some(shortcut) = &request.shortcut {
+            self.ensure_tag_shortcut_available(shortcut, None).await?;
+        }
         let id = ThingsId::new_things_native();
 
         sqlx::query(
@@ -104,8 +148,6 @@ impl ThingsDatabase {
     /// Returns an error if the tag doesn't exist or database operation fails
     #[instrument(skip(self))]
     pub async fn update_tag(&self, request: crate::models::UpdateTagRequest) -> ThingsResult<()> {
-        use crate::database::tag_utils::normalize_tag_title;
-
         // Verify tag exists
         let existing = self
             .find_tag_by_normalized_title(request.uuid.as_str())
@@ -128,7 +170,7 @@ impl ThingsDatabase {
 
         // If renaming, check for duplicates with new name
         if let Some(new_title) = &request.title {
-            let normalized = normalize_tag_title(new_title);
+            let normalized = self.tag_normalization.normalize(new_title);
             if let Some(duplicate) = self.find_tag_by_normalized_title(&normalized).await? {
                 if duplicate.uuid != request.uuid {
                     return Err(ThingsError::unknown(format!(
@@ -139,6 +181,11 @@ impl ThingsDatabase {
             }
         }
 
+        if let Some(shortcut) = &request.shortcut {
+            self.ensure_tag_shortcut_available(shortcut, Some(&request.uuid))
+                .await?;
+        }
+
         // Build dynamic UPDATE query
         let mut updates = Vec::new();
         let mut params: Vec<String> = Vec::new();
@@ -177,6 +224,34 @@ impl ThingsDatabase {
         Ok(())
     }
 
+    /// Fail if another tag already uses `shortcut` (case-insensitive)
+    ///
+    /// Things only resolves a shortcut when it is unique, so a duplicate
+    /// silently breaks tag-typing. `exclude` skips the tag being updated.
+    async fn ensure_tag_shortcut_available(
+        &self,
+        shortcut: &str,
+        exclude: Option<&ThingsId>,
+    ) -> ThingsResult<()> {
+        let conflict = sqlx::query(
+            "SELECT uuid, title FROM TMTag WHERE shortcut = ? COLLATE NOCASE AND uuid != ? LIMIT 1",
+        )
+        .bind(shortcut)
+        .bind(exclude.map_or("", ThingsId::as_str))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to check tag shortcut: {e}")))?;
+
+        if let Some(row) = conflict {
+            let uuid: String = row.get("uuid");
+            let title: String = row.get("title");
+            return Err(ThingsError::validation(format!(
+                "Shortcut '{shortcut}' is already used by tag '{title}' ({uuid})"
+            )));
+        }
+        Ok(())
+    }
+
     /// Delete a tag
     ///
     /// # Arguments
@@ -212,11 +287,13 @@ impl ThingsDatabase {
         }
 
         // Delete the tag
-        sqlx::query("DELETE FROM TMTag WHERE uuid = ?")
-            .bind(id.as_str())
-            .execute(&self.pool)
-            .await
-            .map_err(|e| ThingsError::unknown(format!("Failed to delete tag: {e}")))?;
+        self.retry_busy(|| {
+            sqlx::query("DELETE FROM TMTag WHERE uuid = ?")
+                .bind(id.as_str())
+                .execute(&self.pool)
+        })
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to delete tag: {e}")))?;
 
         info!("Deleted tag with UUID: {}", id);
         Ok(())
@@ -288,12 +365,94 @@ impl ThingsDatabase {
         Ok(())
     }
 
+    /// Reset every tag's `usedDate` to match the tasks that carry it
+    ///
+    /// Tallies tag usage across non-trashed tasks in a single `TMTaskTag`
+    /// scan. A tag's `usedDate` becomes the latest `userModificationDate` of
+    /// those tasks, or NULL when none use it. Only tags whose stored value
+    /// differs (to the second) are written, in one transaction, and they are
+    /// listed in the returned report.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query or update fails
+    #[instrument(skip(self))]
+    pub async fn recompute_tag_usage(&self) -> ThingsResult<TagUsageReport> {
+        let rows = sqlx::query(
+            r"
+            SELECT tg.uuid, tg.title, tg.usedDate,
+                   COUNT(t.uuid) AS usage_count,
+                   MAX(t.userModificationDate) AS last_used
+            FROM TMTag tg
+            LEFT JOIN TMTaskTag tt ON tt.tags = tg.uuid
+            LEFT JOIN TMTask t ON t.uuid = tt.tasks AND t.trashed = 0
+            GROUP BY tg.uuid
+            ORDER BY tg.title
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to tally tag usage: {e}")))?;
+
+        let to_datetime = |ts: Option<f64>| {
+            ts.and_then(|ts| DateTime::from_timestamp(safe_timestamp_convert(ts), 0))
+        };
+        let mut updates: Vec<(String, Option<f64>)> = Vec::new();
+        let mut changed = Vec::new();
+        for row in &rows {
+            let stored: Option<f64> = row.get("usedDate");
+            let actual: Option<f64> = row.get("last_used");
+            if stored.map(safe_timestamp_convert) == actual.map(safe_timestamp_convert) {
+                continue;
+            }
+            let uuid: String = row.get("uuid");
+            let usage_count: i64 = row.get("usage_count");
+            changed.push(TagUsageChange {
+                uuid: ThingsId::from_trusted(uuid.clone()),
+                title: row.get::<Option<String>, _>("title").unwrap_or_default(),
+                usage_count: usage_count.try_into().unwrap_or(0),
+                previous_last_used: to_datetime(stored),
+                last_used: to_datetime(actual),
+            });
+            updates.push((uuid, actual));
+        }
+
+        if !updates.is_empty() {
+            self.retry_busy(|| async {
+                let mut tx = self.pool.begin().await?;
+                for (uuid, used) in &updates {
+                    sqlx::query("UPDATE TMTag SET usedDate = ? WHERE uuid = ?")
+                        .bind(used)
+                        .bind(uuid)
+                        .execute(&mut *tx)
+                        .await?;
+                }
+                tx.commit().await
+            })
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to update tag usage: {e}")))?;
+        }
+
+        info!(
+            "Recomputed usage for {} tags, corrected {}",
+            rows.len(),
+            changed.len()
+        );
+        Ok(TagUsageReport {
+            tags_scanned: rows.len(),
+            changed,
+        })
+    }
+
     /// Add a tag to a task (with duplicate prevention)
     ///
     /// Returns:
     /// - `Assigned`: Tag was successfully assigned
     /// - `Suggestions`: Similar tags found (user decision needed)
     ///
+    /// Tags at least [`DEFAULT_TAG_SIMILARITY`] similar are suggested instead of
+    /// creating a new one; see [`Self::add_tag_to_task_with_threshold`].
+    ///
     /// # Errors
     ///
     /// Returns an error if the task doesn't exist or database operation fails
@@ -303,14 +462,35 @@ impl ThingsDatabase {
         task_id: &ThingsId,
         tag_title: &str,
     ) -> ThingsResult<crate::models::TagAssignmentResult> {
-        use crate::database::tag_utils::normalize_tag_title;
+        self.add_tag_to_task_with_threshold(task_id, tag_title, DEFAULT_TAG_SIMILARITY)
+            .await
+    }
+
+    /// [`Self::add_tag_to_task`] with a custom similarity threshold
+    ///
+    /// When no tag matches exactly, existing tags scoring at least
+    /// `min_similarity` (0.0 to 1.0) are returned as `Suggestions`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `min_similarity` is outside `0.0..=1.0`,
+    /// or an error if the task doesn't exist or the database operation fails
+    #[instrument(skip(self))]
+    pub async fn add_tag_to_task_with_threshold(
+        &self,
+        task_id: &ThingsId,
+        tag_title: &str,
+        min_similarity: f32,
+    ) -> ThingsResult<crate::models::TagAssignmentResult> {
         use crate::models::TagAssignmentResult;
 
+        check_similarity_threshold(min_similarity)?;
+
         // 1. Verify task exists
         validators::validate_task_exists(&self.pool, task_id).await?;
 
         // 2. Normalize and find tag
-        let normalized = normalize_tag_title(tag_title);
+        let normalized = self.tag_normalization.normalize(tag_title);
 
         // 3. Check for exact match first
         let tag = if let Some(existing_tag) = self.find_tag_by_normalized_title(&normalized).await?
@@ -318,7 +498,7 @@ impl ThingsDatabase {
             existing_tag
         } else {
             // 4. Find similar tags
-            let similar_tags = self.find_similar_tags(&normalized, 0.8).await?;
+            let similar_tags = self.find_similar_tags(&normalized, min_similarity).await?;
 
             if !similar_tags.is_empty() {
                 return Ok(TagAssignmentResult::Suggestions { similar_tags });
@@ -370,6 +550,104 @@ impl ThingsDatabase {
         Ok(TagAssignmentResult::Assigned { tag_uuid: tag.uuid })
     }
 
+    /// Tag every to-do matching a search query in one transaction
+    ///
+    /// Matches the same to-dos as [`Self::search_tasks`] (title or notes,
+    /// trashed and canceled excluded). The tag is looked up by normalized
+    /// title and created if it doesn't exist yet. Returns how many to-dos
+    /// gained the tag; to-dos that already had it are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the search, tag creation, or database write fails;
+    /// on a write failure no to-do is tagged and no new tag is left behind
+    #[instrument(skip(self))]
+    pub async fn tag_search_results(&self, query: &str, tag_title: &str) -> ThingsResult<usize> {
+        use crate::models::TaskType;
+
+        let tasks = self.search_tasks(query).await?;
+        let task_ids: Vec<&ThingsId> = tasks
+            .iter()
+            .filter(|t| t.task_type == TaskType::Todo)
+            .map(|t| &t.uuid)
+            .collect();
+        if task_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let normalized = self.tag_normalization.normalize(tag_title);
+        let existing_tag = self.find_tag_by_normalized_title(&normalized).await?;
+
+        let now = Utc::now().timestamp() as f64;
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to begin transaction: {e}")))?;
+
+        // A new tag is created inside the transaction, so a failed write leaves no stray tag
+        let (tag_uuid, applied_title) = if let Some(existing_tag) = existing_tag {
+            (existing_tag.uuid, existing_tag.title)
+        } else {
+            let id = ThingsId::new_things_native();
+            sqlx::query(
+                "INSERT INTO TMTag (uuid, title, shortcut, parent, usedDate, `index`) \
+                 VALUES (?, ?, NULL, NULL, NULL, 0)",
+            )
+            .bind(id.as_str())
+            .bind(tag_title)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to create tag: {e}")))?;
+            (id, tag_title.to_string())
+        };
+
+        let mut tagged = 0;
+        for task_id in &task_ids {
+            let result = sqlx::query("INSERT OR IGNORE INTO TMTaskTag (tasks, tags) VALUES (?, ?)")
+                .bind(task_id.as_str())
+                .bind(tag_uuid.as_str())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| ThingsError::unknown(format!("Failed to assign tag: {e}")))?;
+            if result.rows_affected() == 0 {
+                continue;
+            }
+
+            sqlx::query("UPDATE TMTask SET userModificationDate = ? WHERE uuid = ?")
+                .bind(now)
+                .bind(task_id.as_str())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    ThingsError::unknown(format!("Failed to update modification date: {e}"))
+                })?;
+            tagged += 1;
+        }
+
+        if tagged > 0 {
+            sqlx::query("UPDATE TMTag SET usedDate = ? WHERE uuid = ?")
+                .bind(now)
+                .bind(tag_uuid.as_str())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| ThingsError::unknown(format!("Failed to update tag usedDate: {e}")))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to commit transaction: {e}")))?;
+
+        info!(
+            "Tagged {} of {} task(s) matching {:?} with '{}'",
+            tagged,
+            task_ids.len(),
+            query,
+            applied_title
+        );
+        Ok(tagged)
+    }
+
     /// Remove a tag from a task
     ///
     /// # Errors
@@ -381,13 +659,11 @@ impl ThingsDatabase {
         task_id: &ThingsId,
         tag_title: &str,
     ) -> ThingsResult<()> {
-        use crate::database::tag_utils::normalize_tag_title;
-
         // 1. Verify task exists
         validators::validate_task_exists(&self.pool, task_id).await?;
 
         // 2. Find the tag UUID
-        let normalized = normalize_tag_title(tag_title);
+        let normalized = self.tag_normalization.normalize(tag_title);
         let Some(tag) = self.find_tag_by_normalized_title(&normalized).await? else {
             return Ok(()); // Tag doesn't exist, nothing to remove
         };
@@ -418,9 +694,53 @@ impl ThingsDatabase {
         Ok(())
     }
 
+    /// Remove tag assignments that point at tags no longer in `TMTag`
+    ///
+    /// Strips what [`Self::find_tasks_with_orphan_tags`] reports, plus any
+    /// dangling assignments on trashed tasks. Affected tasks get a new
+    /// modification date. Returns the number of assignments removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails; on failure nothing is removed
+    #[instrument(skip(self))]
+    pub async fn clean_orphan_tags(&self) -> ThingsResult<u64> {
+        const ORPHAN_WHERE: &str =
+            "NOT EXISTS (SELECT 1 FROM TMTag tg WHERE tg.uuid = TMTaskTag.tags)";
+        let now = Utc::now().timestamp() as f64;
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to begin transaction: {e}")))?;
+
+        sqlx::query(&format!(
+            "UPDATE TMTask SET userModificationDate = ? \
+             WHERE uuid IN (SELECT tasks FROM TMTaskTag WHERE {ORPHAN_WHERE})"
+        ))
+        .bind(now)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to update modification date: {e}")))?;
+
+        let removed = sqlx::query(&format!("DELETE FROM TMTaskTag WHERE {ORPHAN_WHERE}"))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to remove orphan tags: {e}")))?
+            .rows_affected();
+
+        tx.commit()
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to commit transaction: {e}")))?;
+
+        info!("Removed {} orphan tag assignment(s)", removed);
+        Ok(removed)
+    }
+
     /// Replace all tags on a task (with duplicate prevention)
     ///
-    /// Returns any tag titles that had similar matches for user confirmation
+    /// Returns any tag titles that had similar matches for user confirmation,
+    /// using [`DEFAULT_TAG_SIMILARITY`]; see [`Self::set_task_tags_with_threshold`].
     ///
     /// # Errors
     ///
@@ -431,7 +751,27 @@ impl ThingsDatabase {
         task_id: &ThingsId,
         tag_titles: Vec<String>,
     ) -> ThingsResult<Vec<crate::models::TagMatch>> {
-        use crate::database::tag_utils::normalize_tag_title;
+        self.set_task_tags_with_threshold(task_id, tag_titles, DEFAULT_TAG_SIMILARITY)
+            .await
+    }
+
+    /// [`Self::set_task_tags`] with a custom similarity threshold
+    ///
+    /// New titles are reported alongside existing tags scoring at least
+    /// `min_similarity` (0.0 to 1.0). The titles are assigned either way.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `min_similarity` is outside `0.0..=1.0`,
+    /// or an error if the task doesn't exist or the database operation fails
+    #[instrument(skip(self))]
+    pub async fn set_task_tags_with_threshold(
+        &self,
+        task_id: &ThingsId,
+        tag_titles: Vec<String>,
+        min_similarity: f32,
+    ) -> ThingsResult<Vec<crate::models::TagMatch>> {
+        check_similarity_threshold(min_similarity)?;
 
         // 1. Verify task exists
         validators::validate_task_exists(&self.pool, task_id).await?;
@@ -441,14 +781,14 @@ impl ThingsDatabase {
 
         // 2. Resolve each tag title
         for title in tag_titles {
-            let normalized = normalize_tag_title(&title);
+            let normalized = self.tag_normalization.normalize(&title);
 
             // Try to find exact match
             if let Some(existing_tag) = self.find_tag_by_normalized_title(&normalized).await? {
                 resolved_tags.push(existing_tag.title);
             } else {
                 // Check for similar tags
-                let similar_tags = self.find_similar_tags(&normalized, 0.8).await?;
+                let similar_tags = self.find_similar_tags(&normalized, min_similarity).await?;
 
                 if !similar_tags.is_empty() {
                     suggestions.extend(similar_tags);
@@ -461,7 +801,7 @@ impl ThingsDatabase {
 
         // 3. For any tags that don't exist yet, create them
         for title in &resolved_tags {
-            let normalized = normalize_tag_title(title);
+            let normalized = self.tag_normalization.normalize(title);
             if self
                 .find_tag_by_normalized_title(&normalized)
                 .await?
@@ -486,7 +826,7 @@ impl ThingsDatabase {
 
         // 5. Insert new tag assignments
         for title in &resolved_tags {
-            let normalized = normalize_tag_title(title);
+            let normalized = self.tag_normalization.normalize(title);
             if let Some(tag) = self.find_tag_by_normalized_title(&normalized).await? {
                 sqlx::query("INSERT OR IGNORE INTO TMTaskTag (tasks, tags) VALUES (?, ?)")
                     .bind(task_id.as_str())
@@ -509,7 +849,7 @@ impl ThingsDatabase {
 
         // 6. Update usedDate for all tags
         for title in &resolved_tags {
-            let normalized = normalize_tag_title(title);
+            let normalized = self.tag_normalization.normalize(title);
             if let Some(tag) = self.find_tag_by_normalized_title(&normalized).await? {
                 sqlx::query("UPDATE TMTag SET usedDate = ? WHERE uuid = ?")
                     .bind(now)
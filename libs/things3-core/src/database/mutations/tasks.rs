@@ -1,15 +1,19 @@
 use crate::{
     database::{
-        conversions::naive_date_to_things_timestamp, query_builders::TaskUpdateBuilder, validators,
-        ThingsDatabase,
+        conversions::naive_date_to_things_timestamp,
+        date_utils::{add_days, today_in},
+        query_builders::TaskUpdateBuilder,
+        validators, ThingsDatabase,
     },
     error::{Result as ThingsResult, ThingsError},
     models::{
-        CreateTaskRequest, DeleteChildHandling, TaskStatus, TaskType, ThingsId, UpdateTaskRequest,
+        CreateTaskRequest, DeleteChildHandling, FieldUpdate, TaskStatus, TaskType, ThingsId,
+        UpdateTaskRequest,
     },
 };
 use chrono::Utc;
 use sqlx::Row;
+use std::collections::HashSet;
 use tracing::{info, instrument};
 
 impl ThingsDatabase {
@@ -84,31 +88,40 @@ impl ThingsDatabase {
         // Get current timestamp for creation/modification dates
         let now = Utc::now().timestamp() as f64;
 
-        // Insert into TMTask table
-        sqlx::query(
-            r"
+        // Insert into TMTask table, placed after the last item in its container
+        // (the Inbox when project, area, and heading are all unset)
+        self.retry_busy(|| {
+            sqlx::query(
+                r#"
             INSERT INTO TMTask (
                 uuid, title, type, status, notes,
                 startDate, deadline, project, area, heading,
                 creationDate, userModificationDate,
-                trashed
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-            ",
-        )
-        .bind(id.as_str())
-        .bind(&request.title)
-        .bind(request.task_type.unwrap_or(TaskType::Todo) as i32)
-        .bind(request.status.unwrap_or(TaskStatus::Incomplete) as i32)
-        .bind(request.notes.as_ref())
-        .bind(start_date_ts)
-        .bind(deadline_ts)
-        .bind(request.project_uuid.map(|u| u.into_string()))
-        .bind(request.area_uuid.map(|u| u.into_string()))
-        .bind(request.parent_uuid.map(|u| u.into_string()))
-        .bind(now)
-        .bind(now)
-        .bind(0) // not trashed
-        .execute(&self.pool)
+                trashed, "index"
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, (
+                SELECT COALESCE(MAX("index"), -1) + 1 FROM TMTask
+                WHERE project IS ? AND area IS ? AND heading IS ?
+            ))
+            "#,
+            )
+            .bind(id.as_str())
+            .bind(&request.title)
+            .bind(request.task_type.unwrap_or(TaskType::Todo) as i32)
+            .bind(request.status.and_then(TaskStatus::code).unwrap_or(0))
+            .bind(request.notes.as_ref())
+            .bind(start_date_ts)
+            .bind(deadline_ts)
+            .bind(request.project_uuid.as_ref().map(ThingsId::as_str))
+            .bind(request.area_uuid.as_ref().map(ThingsId::as_str))
+            .bind(request.parent_uuid.as_ref().map(ThingsId::as_str))
+            .bind(now)
+            .bind(now)
+            .bind(0) // not trashed
+            .bind(request.project_uuid.as_ref().map(ThingsId::as_str))
+            .bind(request.area_uuid.as_ref().map(ThingsId::as_str))
+            .bind(request.parent_uuid.as_ref().map(ThingsId::as_str))
+            .execute(&self.pool)
+        })
         .await
         .map_err(|e| ThingsError::unknown(format!("Failed to create task: {e}")))?;
 
@@ -121,9 +134,108 @@ impl ThingsDatabase {
         Ok(id)
     }
 
+    /// Create a task with the configured default tags added to its own
+    ///
+    /// The request's tags come first, followed by the default tags (see
+    /// [`Self::with_default_tags`]), dropping any title that repeats an earlier
+    /// one under the database's tag normalization rules. Tags are resolved to
+    /// existing ones the same way as in [`Self::set_task_tags`], so defaults
+    /// don't create duplicates.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::create_task`]
+    #[instrument(skip(self))]
+    pub async fn create_task_with_defaults(
+        &self,
+        mut request: CreateTaskRequest,
+    ) -> ThingsResult<ThingsId> {
+        if !self.default_tags.is_empty() {
+            let requested = request.tags.take().unwrap_or_default();
+            let mut seen = HashSet::new();
+            let tags = requested
+                .into_iter()
+                .chain(self.default_tags.iter().cloned())
+                .filter(|tag| seen.insert(self.tag_normalization.normalize(tag)))
+                .collect();
+            request.tags = Some(tags);
+        }
+        self.create_task(request).await
+    }
+
+    /// Create one to-do per title, all in a single transaction
+    ///
+    /// Titles are trimmed and blank ones skipped, so pasted lines can be passed
+    /// as-is. The to-dos go into `project_uuid`, or the Inbox when `None`, after
+    /// any existing items and in the order given. Returns the new IDs in that
+    /// order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project doesn't exist or if the insert fails, in
+    /// which case no to-dos are created
+    #[instrument(skip(self, titles), fields(count = titles.len()))]
+    pub async fn create_tasks_from_titles(
+        &self,
+        titles: &[String],
+        project_uuid: Option<ThingsId>,
+    ) -> ThingsResult<Vec<ThingsId>> {
+        if let Some(project_uuid) = &project_uuid {
+            validators::validate_project_exists(&self.pool, project_uuid).await?;
+        }
+
+        let tasks: Vec<(ThingsId, &str)> = titles
+            .iter()
+            .map(|title| title.trim())
+            .filter(|title| !title.is_empty())
+            .map(|title| (ThingsId::new_things_native(), title))
+            .collect();
+        if tasks.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let project = project_uuid.as_ref().map(ThingsId::as_str);
+        let now = Utc::now().timestamp() as f64;
+        self.retry_busy(|| async {
+            let mut tx = self.pool.begin().await?;
+            let first_index: i64 = sqlx::query_scalar(
+                r#"SELECT COALESCE(MAX("index"), -1) + 1 FROM TMTask WHERE project IS ? AND area IS NULL AND heading IS NULL"#,
+            )
+            .bind(project)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            for (index, (id, title)) in (first_index..).zip(&tasks) {
+                sqlx::query(
+                    r#"
+                    INSERT INTO TMTask (
+                        uuid, title, type, status, project,
+                        creationDate, userModificationDate, trashed, "index"
+                    ) VALUES (?, ?, 0, 0, ?, ?, ?, 0, ?)
+                    "#,
+                )
+                .bind(id.as_str())
+                .bind(*title)
+                .bind(project)
+                .bind(now)
+                .bind(now)
+                .bind(index)
+                .execute(&mut *tx)
+                .await?;
+            }
+            tx.commit().await
+        })
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to create tasks: {e}")))?;
+
+        info!("Created {} tasks from titles", tasks.len());
+        Ok(tasks.into_iter().map(|(id, _)| id).collect())
+    }
+
     /// Update an existing task
     ///
-    /// Only updates fields that are provided (Some(_))
+    /// Only updates fields that are provided (`Some(_)` / `FieldUpdate::Set`);
+    /// `FieldUpdate::Clear` sets a nullable field to NULL.
     /// Validates existence of referenced entities
     ///
     /// # Errors
@@ -135,21 +247,21 @@ impl ThingsDatabase {
         validators::validate_task_exists(&self.pool, &request.uuid).await?;
 
         // Validate dates if either is being updated
-        if request.start_date.is_some() || request.deadline.is_some() {
+        if !request.start_date.is_unchanged() || !request.deadline.is_unchanged() {
             // Get current task to merge dates
             if let Some(current_task) = self.get_task_by_uuid(&request.uuid).await? {
-                let final_start = request.start_date.or(current_task.start_date);
-                let final_deadline = request.deadline.or(current_task.deadline);
+                let final_start = request.start_date.resolve(current_task.start_date);
+                let final_deadline = request.deadline.resolve(current_task.deadline);
                 crate::database::validate_date_range(final_start, final_deadline)?;
             }
         }
 
         // Validate referenced entities if being updated
-        if let Some(project_uuid) = &request.project_uuid {
+        if let FieldUpdate::Set(project_uuid) = &request.project_uuid {
             validators::validate_project_exists(&self.pool, project_uuid).await?;
         }
 
-        if let Some(area_uuid) = &request.area_uuid {
+        if let FieldUpdate::Set(area_uuid) = &request.area_uuid {
             validators::validate_area_exists(&self.pool, area_uuid).await?;
         }
 
@@ -162,44 +274,45 @@ impl ThingsDatabase {
         }
 
         let query_string = builder.build_query_string();
-        let mut q = sqlx::query(&query_string);
+        let now = Utc::now().timestamp() as f64;
 
-        // Bind values in the same order as the builder added fields
-        if let Some(title) = &request.title {
-            q = q.bind(title);
-        }
+        self.retry_busy(|| {
+            let mut q = sqlx::query(&query_string);
 
-        if let Some(notes) = &request.notes {
-            q = q.bind(notes);
-        }
+            // Bind values in the same order as the builder added fields
+            if let Some(title) = &request.title {
+                q = q.bind(title);
+            }
 
-        if let Some(start_date) = request.start_date {
-            q = q.bind(naive_date_to_things_timestamp(start_date));
-        }
+            if let Some(notes) = request.notes.as_change() {
+                q = q.bind(notes);
+            }
 
-        if let Some(deadline) = request.deadline {
-            q = q.bind(naive_date_to_things_timestamp(deadline));
-        }
+            if let Some(start_date) = request.start_date.as_change() {
+                q = q.bind(start_date.map(|d| naive_date_to_things_timestamp(*d)));
+            }
 
-        if let Some(status) = request.status {
-            q = q.bind(status as i32);
-        }
+            if let Some(deadline) = request.deadline.as_change() {
+                q = q.bind(deadline.map(|d| naive_date_to_things_timestamp(*d)));
+            }
 
-        if let Some(project_uuid) = request.project_uuid {
-            q = q.bind(project_uuid.into_string());
-        }
+            if let Some(status) = request.status {
+                q = q.bind(status.code().unwrap_or(0));
+            }
 
-        if let Some(area_uuid) = request.area_uuid {
-            q = q.bind(area_uuid.into_string());
-        }
+            if let Some(project_uuid) = request.project_uuid.as_change() {
+                q = q.bind(project_uuid.map(ThingsId::as_str));
+            }
 
-        // Bind modification date and UUID (always added by builder)
-        let now = Utc::now().timestamp() as f64;
-        q = q.bind(now).bind(request.uuid.as_str());
+            if let Some(area_uuid) = request.area_uuid.as_change() {
+                q = q.bind(area_uuid.map(ThingsId::as_str));
+            }
 
-        q.execute(&self.pool)
-            .await
-            .map_err(|e| ThingsError::unknown(format!("Failed to update task: {e}")))?;
+            // Bind modification date and UUID (always added by builder)
+            q.bind(now).bind(request.uuid.as_str()).execute(&self.pool)
+        })
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to update task: {e}")))?;
 
         // Handle tags via TMTaskTag (separate from the UPDATE query)
         if let Some(tags) = request.tags {
@@ -222,13 +335,15 @@ impl ThingsDatabase {
 
         let now = Utc::now().timestamp() as f64;
 
-        sqlx::query(
+        self.retry_busy(|| {
+            sqlx::query(
             "UPDATE TMTask SET status = 3, stopDate = ?, userModificationDate = ? WHERE uuid = ?",
         )
         .bind(now)
         .bind(now)
         .bind(id.as_str())
         .execute(&self.pool)
+        })
         .await
         .map_err(|e| ThingsError::unknown(format!("Failed to complete task: {e}")))?;
 
@@ -248,12 +363,12 @@ impl ThingsDatabase {
 
         let now = Utc::now().timestamp() as f64;
 
-        sqlx::query(
+        self.retry_busy(|| sqlx::query(
             "UPDATE TMTask SET status = 0, stopDate = NULL, userModificationDate = ? WHERE uuid = ?",
         )
         .bind(now)
         .bind(id.as_str())
-        .execute(&self.pool)
+        .execute(&self.pool))
         .await
         .map_err(|e| ThingsError::unknown(format!("Failed to uncomplete task: {e}")))?;
 
@@ -261,6 +376,40 @@ impl ThingsDatabase {
         Ok(())
     }
 
+    /// Defer a task by setting its start date to today plus `days`
+    ///
+    /// `days` may be negative to backdate the start. Today is taken in the
+    /// database's configured timezone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task does not exist, the new start date falls
+    /// after the task's deadline, or the database update fails
+    #[instrument(skip(self))]
+    pub async fn defer_task(&self, id: &ThingsId, days: i64) -> ThingsResult<()> {
+        validators::validate_task_exists(&self.pool, id).await?;
+
+        let start_date = add_days(today_in(self.timezone), days)?;
+        if let Some(current_task) = self.get_task_by_uuid(id).await? {
+            crate::database::validate_date_range(Some(start_date), current_task.deadline)?;
+        }
+
+        let now = Utc::now().timestamp() as f64;
+
+        self.retry_busy(|| {
+            sqlx::query("UPDATE TMTask SET startDate = ?, userModificationDate = ? WHERE uuid = ?")
+                .bind(naive_date_to_things_timestamp(start_date))
+                .bind(now)
+                .bind(id.as_str())
+                .execute(&self.pool)
+        })
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to defer task: {e}")))?;
+
+        info!("Deferred task with UUID: {} to {}", id, start_date);
+        Ok(())
+    }
+
     /// Soft delete a task (set trashed flag)
     ///
     /// # Errors
@@ -298,12 +447,12 @@ impl ThingsDatabase {
                     let now = Utc::now().timestamp() as f64;
                     for child_row in &children {
                         let child_uuid: String = child_row.get("uuid");
-                        sqlx::query(
+                        self.retry_busy(|| sqlx::query(
                             "UPDATE TMTask SET trashed = 1, userModificationDate = ? WHERE uuid = ?",
                         )
                         .bind(now)
                         .bind(&child_uuid)
-                        .execute(&self.pool)
+                        .execute(&self.pool))
                         .await
                         .map_err(|e| {
                             ThingsError::unknown(format!("Failed to delete child task: {e}"))
@@ -316,12 +465,12 @@ impl ThingsDatabase {
                     let now = Utc::now().timestamp() as f64;
                     for child_row in &children {
                         let child_uuid: String = child_row.get("uuid");
-                        sqlx::query(
+                        self.retry_busy(|| sqlx::query(
                             "UPDATE TMTask SET heading = NULL, userModificationDate = ? WHERE uuid = ?",
                         )
                         .bind(now)
                         .bind(&child_uuid)
-                        .execute(&self.pool)
+                        .execute(&self.pool))
                         .await
                         .map_err(|e| {
                             ThingsError::unknown(format!("Failed to orphan child task: {e}"))
@@ -334,12 +483,14 @@ impl ThingsDatabase {
 
         // Delete the parent task
         let now = Utc::now().timestamp() as f64;
-        sqlx::query("UPDATE TMTask SET trashed = 1, userModificationDate = ? WHERE uuid = ?")
-            .bind(now)
-            .bind(id.as_str())
-            .execute(&self.pool)
-            .await
-            .map_err(|e| ThingsError::unknown(format!("Failed to delete task: {e}")))?;
+        self.retry_busy(|| {
+            sqlx::query("UPDATE TMTask SET trashed = 1, userModificationDate = ? WHERE uuid = ?")
+                .bind(now)
+                .bind(id.as_str())
+                .execute(&self.pool)
+        })
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to delete task: {e}")))?;
 
         info!("Deleted task with UUID: {}", id);
         Ok(())
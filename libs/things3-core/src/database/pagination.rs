@@ -0,0 +1,93 @@
+//! Offset pagination helper shared by the `*_page` query methods
+
+use crate::error::Result as ThingsResult;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+/// One page of an offset-paginated list
+///
+/// Built by [`paginate`]. Unlike the keyset `cursor::Page`
+/// (`batch-operations` feature), it carries the total count so UIs can show
+/// positions and `has_more` is exact.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Paginated<T> {
+    /// Items on this page
+    pub items: Vec<T>,
+    /// Total number of items across all pages
+    pub total: u64,
+    /// Offset of the first item on this page
+    pub offset: u32,
+    /// Requested page size
+    pub limit: u32,
+    /// Whether items exist past this page
+    pub has_more: bool,
+}
+
+impl<T> Paginated<T> {
+    /// Create a page, deriving `has_more` from `total`
+    #[must_use]
+    pub fn new(items: Vec<T>, total: u64, offset: u32, limit: u32) -> Self {
+        let has_more = u64::from(offset) + (items.len() as u64) < total;
+        Self {
+            items,
+            total,
+            offset,
+            limit,
+            has_more,
+        }
+    }
+}
+
+/// Build a [`Paginated`] from a count query and a windowed fetch
+///
+/// `fetch` is called with `(offset, limit)` and must return at most `limit`
+/// items from the same result set that `total` counts, so `has_more` is
+/// accurate.
+///
+/// # Errors
+///
+/// Returns the first error from `total` or `fetch`
+pub async fn paginate<T, C, F, Fut>(
+    offset: u32,
+    limit: u32,
+    total: C,
+    fetch: F,
+) -> ThingsResult<Paginated<T>>
+where
+    C: Future<Output = ThingsResult<u64>>,
+    F: FnOnce(u32, u32) -> Fut,
+    Fut: Future<Output = ThingsResult<Vec<T>>>,
+{
+    let total = total.await?;
+    let items = fetch(offset, limit).await?;
+    Ok(Paginated::new(items, total, offset, limit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_paginate_sets_has_more() {
+        let data: Vec<u32> = (0..5).collect();
+        let fetch = |offset: u32, limit: u32| {
+            let data = data.clone();
+            async move {
+                Ok(data
+                    .into_iter()
+                    .skip(offset as usize)
+                    .take(limit as usize)
+                    .collect())
+            }
+        };
+
+        let first = paginate(0, 2, async { Ok(5) }, fetch).await.unwrap();
+        assert_eq!(first.items, vec![0, 1]);
+        assert!(first.has_more);
+
+        let last = paginate(4, 2, async { Ok(5) }, fetch).await.unwrap();
+        assert_eq!(last.items, vec![4]);
+        assert_eq!(last.total, 5);
+        assert!(!last.has_more);
+    }
+}
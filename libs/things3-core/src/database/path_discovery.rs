@@ -5,6 +5,7 @@
 //! possibly tied to iCloud account). This module scans the group container at
 //! runtime and picks the most-recently-modified candidate, falling back to the
 //! historical literal `ThingsData-0Z0Z2` path when nothing is found.
+//! [`discover_database_paths`] exposes every candidate so callers can choose.
 
 use std::path::PathBuf;
 
@@ -36,8 +37,7 @@ const THINGS_DB_RELATIVE: &str = "Things Database.thingsdatabase/main.sqlite";
 /// ```
 #[must_use]
 pub fn get_default_database_path() -> PathBuf {
-    let home = std::env::var("HOME").unwrap_or_else(|_| "~".to_string());
-    let group_container = PathBuf::from(&home).join(THINGS_GROUP_CONTAINER);
+    let group_container = things_group_container();
 
     if let Some(found) = discover_things_database(&group_container) {
         return found;
@@ -48,12 +48,49 @@ pub fn get_default_database_path() -> PathBuf {
         .join(THINGS_DB_RELATIVE)
 }
 
+/// List every Things 3 database found on disk, most recently modified first.
+///
+/// Scans the group container for `ThingsData-*/Things Database.thingsdatabase/main.sqlite`
+/// (which covers the historical `ThingsData-0Z0Z2` location) and returns only
+/// candidates that exist as files. The first entry is what
+/// [`get_default_database_path`] returns; the list is empty when Things 3
+/// isn't installed or its container can't be read.
+///
+/// # Examples
+///
+/// ```
+/// use things3_core::discover_database_paths;
+///
+/// for path in discover_database_paths() {
+///     assert!(path.is_file());
+/// }
+/// ```
+#[must_use]
+pub fn discover_database_paths() -> Vec<PathBuf> {
+    discover_things_databases(&things_group_container())
+}
+
+fn things_group_container() -> PathBuf {
+    let home = std::env::var("HOME").unwrap_or_else(|_| "~".to_string());
+    PathBuf::from(&home).join(THINGS_GROUP_CONTAINER)
+}
+
 /// Scan `group_container` for `ThingsData-*/Things Database.thingsdatabase/main.sqlite`
 /// and return the most-recently-modified candidate, if any.
 fn discover_things_database(group_container: &std::path::Path) -> Option<PathBuf> {
-    let entries = std::fs::read_dir(group_container).ok()?;
+    discover_things_databases(group_container)
+        .into_iter()
+        .next()
+}
 
-    let mut best: Option<(PathBuf, std::time::SystemTime)> = None;
+/// Scan `group_container` for every `ThingsData-*` database file, most recently
+/// modified first (ties broken by path).
+fn discover_things_databases(group_container: &std::path::Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(group_container) else {
+        return Vec::new();
+    };
+
+    let mut found: Vec<(PathBuf, std::time::SystemTime)> = Vec::new();
     for entry in entries.flatten() {
         let name = entry.file_name();
         let Some(name_str) = name.to_str() else {
@@ -71,14 +108,13 @@ fn discover_things_database(group_container: &std::path::Path) -> Option<PathBuf
             continue;
         }
         let mtime = meta.modified().unwrap_or(std::time::UNIX_EPOCH);
-
-        match &best {
-            Some((_, best_mtime)) if mtime <= *best_mtime => {}
-            _ => best = Some((candidate, mtime)),
-        }
+        found.push((candidate, mtime));
     }
 
-    best.map(|(path, _)| path)
+    found.sort_by(|(a_path, a_mtime), (b_path, b_mtime)| {
+        b_mtime.cmp(a_mtime).then_with(|| a_path.cmp(b_path))
+    });
+    found.into_iter().map(|(path, _)| path).collect()
 }
 
 #[cfg(test)]
@@ -133,6 +169,41 @@ mod tests {
         assert_eq!(found, newer);
     }
 
+    #[test]
+    fn test_discover_things_databases_lists_all_candidates() {
+        let group_container = TempDir::new().unwrap();
+
+        let make = |suffix: &str| {
+            let dir = group_container
+                .path()
+                .join(format!("ThingsData-{suffix}"))
+                .join("Things Database.thingsdatabase");
+            std::fs::create_dir_all(&dir).unwrap();
+            let db = dir.join("main.sqlite");
+            std::fs::write(&db, b"").unwrap();
+            db
+        };
+
+        let default = make("0Z0Z2");
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        let moved = make("01AEF");
+        std::fs::create_dir_all(group_container.path().join("ThingsData-EMPTY")).unwrap();
+
+        let found = discover_things_databases(group_container.path());
+        assert_eq!(found, vec![moved.clone(), default]);
+        assert_eq!(
+            discover_things_database(group_container.path()),
+            Some(moved)
+        );
+    }
+
+    #[test]
+    fn test_discover_things_databases_missing_container() {
+        let group_container = TempDir::new().unwrap();
+        let missing = group_container.path().join("does-not-exist");
+        assert!(discover_things_databases(&missing).is_empty());
+    }
+
     #[test]
     fn test_discover_things_database_returns_none_when_empty() {
         let group_container = TempDir::new().unwrap();
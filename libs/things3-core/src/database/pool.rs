@@ -43,6 +43,35 @@ pub struct SqliteOptimizations {
     pub mmap_size: i64,
     /// Enable query planner optimizations
     pub enable_query_planner: bool,
+    /// How long `SQLite` waits on a locked database before returning `SQLITE_BUSY`
+    #[serde(default = "default_busy_timeout_ms")]
+    pub busy_timeout_ms: u64,
+    /// Prepared statements cached per connection, keyed by SQL string
+    #[serde(default = "default_statement_cache_capacity")]
+    pub statement_cache_capacity: usize,
+    /// Extra attempts for a write that still fails with `SQLITE_BUSY`/`SQLITE_LOCKED`
+    /// after `busy_timeout_ms`; 0 disables retrying
+    #[serde(default = "default_busy_retries")]
+    pub busy_retries: u32,
+    /// Delay before the first busy retry, doubled on each further attempt
+    #[serde(default = "default_busy_retry_backoff_ms")]
+    pub busy_retry_backoff_ms: u64,
+}
+
+const fn default_busy_timeout_ms() -> u64 {
+    5000
+}
+
+const fn default_statement_cache_capacity() -> usize {
+    100
+}
+
+const fn default_busy_retries() -> u32 {
+    3
+}
+
+const fn default_busy_retry_backoff_ms() -> u64 {
+    50
 }
 
 impl Default for DatabasePoolConfig {
@@ -70,10 +99,26 @@ impl Default for SqliteOptimizations {
             temp_store: "MEMORY".to_string(),
             mmap_size: 268_435_456, // 256MB
             enable_query_planner: true,
+            busy_timeout_ms: default_busy_timeout_ms(),
+            statement_cache_capacity: default_statement_cache_capacity(),
+            busy_retries: default_busy_retries(),
+            busy_retry_backoff_ms: default_busy_retry_backoff_ms(),
         }
     }
 }
 
+/// Reachability of the database, reported by [`crate::ThingsDatabase::connection_state`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectionState {
+    /// The database answered a query
+    Healthy,
+    /// The database is reachable but locked by another connection, e.g. while Things saves
+    Busy,
+    /// The database could not be queried
+    Down,
+}
+
 /// Connection pool health status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolHealthStatus {
@@ -104,6 +149,44 @@ pub struct PoolMetrics {
     pub max_lifetime: Option<Duration>,
 }
 
+/// WAL checkpoint mode, mirroring `PRAGMA wal_checkpoint(<mode>)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckpointMode {
+    /// Checkpoint as many frames as possible without waiting on readers or writers
+    Passive,
+    /// Wait for writers, then checkpoint every frame
+    Full,
+    /// Like [`Self::Full`], then truncate the WAL file to zero bytes
+    Truncate,
+}
+
+impl CheckpointMode {
+    /// The mode keyword as `SQLite` spells it
+    #[must_use]
+    pub const fn as_sql(self) -> &'static str {
+        match self {
+            Self::Passive => "PASSIVE",
+            Self::Full => "FULL",
+            Self::Truncate => "TRUNCATE",
+        }
+    }
+}
+
+/// Frame counts reported by a WAL checkpoint
+///
+/// `log_frames` and `checkpointed_frames` are `-1` when the database is not
+/// in WAL mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointResult {
+    /// Whether the checkpoint was blocked by a concurrent reader or writer
+    pub busy: bool,
+    /// Number of frames in the WAL file
+    pub log_frames: i64,
+    /// Number of WAL frames copied back into the database file
+    pub checkpointed_frames: i64,
+}
+
 /// Comprehensive health status including pool and database
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComprehensiveHealthStatus {
@@ -140,6 +223,7 @@ mod tests {
         assert_eq!(opts.mmap_size, 268_435_456);
         assert!(opts.enable_foreign_keys);
         assert!(opts.enable_query_planner);
+        assert_eq!(opts.busy_timeout_ms, 5000);
     }
 
     #[test]
@@ -1,10 +1,10 @@
 use crate::{
-    database::ThingsDatabase,
+    database::{mappers::map_area_row, ThingsDatabase},
     error::{Result as ThingsResult, ThingsError},
-    models::{Area, ThingsId},
+    models::{Area, ListItem, ListItemKind, ThingsId},
 };
-use chrono::Utc;
 use sqlx::Row;
+use std::collections::HashMap;
 use tracing::{debug, instrument};
 
 impl ThingsDatabase {
@@ -28,20 +28,10 @@ impl ThingsDatabase {
         .await
         .map_err(|e| ThingsError::unknown(format!("Failed to fetch areas: {e}")))?;
 
-        let mut areas = Vec::new();
-        for row in rows {
-            let uuid_str: String = row.get("uuid");
-            let area = Area {
-                uuid: ThingsId::from_trusted(uuid_str),
-                title: row.get("title"),
-                notes: None,          // Notes not stored in TMArea table
-                projects: Vec::new(), // TODO: Load projects separately
-                tags: Vec::new(),     // TODO: Load tags separately
-                created: Utc::now(),  // Creation date not available in TMArea
-                modified: Utc::now(), // Modification date not available in TMArea
-            };
-            areas.push(area);
-        }
+        let areas = rows
+            .iter()
+            .map(map_area_row)
+            .collect::<ThingsResult<Vec<Area>>>()?;
 
         debug!("Fetched {} areas", areas.len());
         Ok(areas)
@@ -54,6 +44,131 @@ impl ThingsDatabase {
     /// Returns an error if the database query fails or if area data is invalid
     #[instrument(skip(self))]
     pub async fn get_areas(&self) -> ThingsResult<Vec<Area>> {
-        self.get_all_areas().await
+        self.with_timeout("get_areas", self.get_all_areas()).await
+    }
+
+    /// Get a single area by UUID
+    ///
+    /// Returns `None` if the area doesn't exist
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_area_by_uuid(&self, id: &ThingsId) -> ThingsResult<Option<Area>> {
+        let row = sqlx::query("SELECT uuid, title, visible, `index` FROM TMArea WHERE uuid = ?")
+            .bind(id.as_str())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to fetch area: {e}")))?;
+
+        row.as_ref().map(map_area_row).transpose()
+    }
+
+    /// Get every area and open project as one flat list, ready for a "move to" picker
+    ///
+    /// Projects without an area come first, then each area (in sidebar order)
+    /// followed by its projects. Projects are ordered by their `index`, then title.
+    /// Trashed, completed and canceled projects are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if an ID is invalid
+    #[instrument(skip(self))]
+    pub async fn get_list_picker_items(&self) -> ThingsResult<Vec<ListItem>> {
+        let areas = self.get_all_areas().await?;
+
+        let rows = sqlx::query(
+            r"
+            SELECT uuid, title, area
+            FROM TMTask
+            WHERE type = 1 AND status = 0 AND trashed = 0
+            ORDER BY `index` ASC, title ASC
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch projects: {e}")))?;
+
+        let mut loose = Vec::new();
+        let mut by_area: HashMap<ThingsId, Vec<ListItem>> = HashMap::new();
+        for row in &rows {
+            let area_uuid = row
+                .get::<Option<String>, _>("area")
+                .map(ThingsId::from_trusted);
+            let item = ListItem {
+                uuid: ThingsId::from_trusted(row.get("uuid")),
+                title: row.get("title"),
+                kind: ListItemKind::Project,
+                area_uuid: area_uuid.clone(),
+            };
+            match area_uuid {
+                // Projects pointing at a missing area are listed with the loose ones
+                Some(area) if areas.iter().any(|a| a.uuid == area) => {
+                    by_area.entry(area).or_default().push(item);
+                }
+                _ => loose.push(item),
+            }
+        }
+
+        let mut items = loose;
+        for area in areas {
+            let projects = by_area.remove(&area.uuid).unwrap_or_default();
+            items.push(ListItem {
+                uuid: area.uuid,
+                title: area.title,
+                kind: ListItemKind::Area,
+                area_uuid: None,
+            });
+            items.extend(projects);
+        }
+
+        debug!("Built {} list picker items", items.len());
+        Ok(items)
+    }
+
+    /// Count open to-dos in each area, including those in the area's projects
+    ///
+    /// A to-do counts toward its own area, or when it has none, its project's
+    /// area (the project is taken from the heading for to-dos under a heading),
+    /// as in [`Self::get_effective_area`]. Every area is listed in sidebar
+    /// order, with 0 when nothing open falls under it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if area data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_workload_by_area(&self) -> ThingsResult<Vec<(Area, u64)>> {
+        let rows = sqlx::query(
+            r"
+            SELECT
+                a.uuid, a.title, a.visible, a.`index`,
+                COUNT(w.uuid) AS open_count
+            FROM TMArea a
+            LEFT JOIN (
+                SELECT t.uuid, COALESCE(t.area, p.area) AS area
+                FROM TMTask t
+                LEFT JOIN TMTask h ON h.uuid = t.heading
+                LEFT JOIN TMTask p ON p.uuid = COALESCE(t.project, h.project)
+                WHERE t.type = 0 AND t.status = 0 AND t.trashed = 0
+            ) w ON w.area = a.uuid
+            GROUP BY a.uuid
+            ORDER BY a.`index` ASC
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch area workload: {e}")))?;
+
+        let workload = rows
+            .iter()
+            .map(|row| {
+                let count: i64 = row.get("open_count");
+                Ok((map_area_row(row)?, count.try_into().unwrap_or(0)))
+            })
+            .collect::<ThingsResult<Vec<_>>>()?;
+
+        debug!("Computed workload for {} areas", workload.len());
+        Ok(workload)
     }
 }
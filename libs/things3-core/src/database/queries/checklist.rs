@@ -0,0 +1,47 @@
+use crate::{
+    database::ThingsDatabase,
+    error::{Result as ThingsResult, ThingsError},
+    models::{ChecklistItem, ThingsId},
+};
+use sqlx::Row;
+use tracing::{debug, instrument};
+
+impl ThingsDatabase {
+    /// Get a task's checklist items in display order
+    ///
+    /// An item counts as completed when its status is anything other than
+    /// incomplete (Things uses 3 for checked and 2 for canceled).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_checklist_items(
+        &self,
+        task_uuid: &ThingsId,
+    ) -> ThingsResult<Vec<ChecklistItem>> {
+        let rows = sqlx::query(
+            "SELECT uuid, title, status FROM TMChecklistItem WHERE task = ? ORDER BY `index`, creationDate",
+        )
+        .bind(task_uuid.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch checklist items: {e}")))?;
+
+        let items: Vec<ChecklistItem> = rows
+            .iter()
+            .map(|row| ChecklistItem {
+                uuid: ThingsId::from_trusted(row.get("uuid")),
+                title: row.get::<Option<String>, _>("title").unwrap_or_default(),
+                completed: row.get::<Option<i64>, _>("status").unwrap_or(0) != 0,
+            })
+            .collect();
+
+        debug!(
+            "Fetched {} checklist item(s) for task {}",
+            items.len(),
+            task_uuid
+        );
+        Ok(items)
+    }
+}
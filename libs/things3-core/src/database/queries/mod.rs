@@ -1,4 +1,9 @@
 mod areas;
+mod checklist;
+#[cfg(feature = "test-utils")]
+mod profiling;
 mod projects;
+mod search;
+mod snapshot;
 mod tags;
 mod tasks;
@@ -0,0 +1,98 @@
+//! Timing helpers for separating `SQLite` fetch cost from row mapping.
+
+use crate::database::mappers::TASK_COLUMNS;
+use crate::{
+    database::ThingsDatabase,
+    error::{Result as ThingsResult, ThingsError},
+    models::Task,
+};
+use std::time::{Duration, Instant};
+use tracing::{debug, instrument};
+
+impl ThingsDatabase {
+    /// Count every row in `TMTask`, including projects, headings and trashed rows
+    ///
+    /// This is the number of rows [`Self::time_full_scan`] fetches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_all_task_rows_count(&self) -> ThingsResult<u64> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM TMTask")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to count task rows: {e}")))?;
+        Ok(count.try_into().unwrap_or(0))
+    }
+
+    /// Time fetching every `TMTask` row without mapping it
+    ///
+    /// Selects the same columns the task queries pass to [`crate::database::map_task_row`], so
+    /// comparing against [`Self::time_full_scan_mapped`] isolates the mapping cost.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn time_full_scan(&self) -> ThingsResult<Duration> {
+        let started = Instant::now();
+        let rows = sqlx::query(&format!("SELECT {TASK_COLUMNS} FROM TMTask"))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to scan task rows: {e}")))?;
+        let elapsed = started.elapsed();
+        drop(rows);
+
+        debug!("Full scan took {elapsed:?}");
+        Ok(elapsed)
+    }
+
+    /// Time fetching every `TMTask` row and mapping it with [`crate::database::map_task_row`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if a row can't be mapped
+    #[instrument(skip(self))]
+    pub async fn time_full_scan_mapped(&self) -> ThingsResult<Duration> {
+        let started = Instant::now();
+        let rows = sqlx::query(&format!("SELECT {TASK_COLUMNS} FROM TMTask"))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to scan task rows: {e}")))?;
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+        let elapsed = started.elapsed();
+        drop(tasks);
+
+        debug!("Full scan with mapping took {elapsed:?}");
+        Ok(elapsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::create_test_database;
+    use crate::ThingsDatabase;
+    use tempfile::NamedTempFile;
+
+    #[tokio::test]
+    async fn test_full_scan_helpers() {
+        let temp_file = NamedTempFile::new().unwrap();
+        create_test_database(temp_file.path()).await.unwrap();
+        let db = ThingsDatabase::new(temp_file.path()).await.unwrap();
+
+        let count = db.get_all_task_rows_count().await.unwrap();
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM TMTask")
+            .fetch_one(db.pool())
+            .await
+            .unwrap();
+        assert!(count > 0);
+        assert_eq!(count, u64::try_from(total).unwrap());
+
+        db.time_full_scan().await.unwrap();
+        db.time_full_scan_mapped().await.unwrap();
+    }
+}
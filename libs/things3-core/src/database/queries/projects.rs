@@ -1,9 +1,12 @@
 use crate::{
-    database::{conversions::safe_timestamp_convert, mappers::map_project_row, ThingsDatabase},
+    database::{
+        date_utils::{date_in, start_of_day_in, today_in},
+        naive_date_to_things_timestamp, validators, ThingsDatabase,
+    },
     error::{Result as ThingsResult, ThingsError},
-    models::{Project, TaskStatus, ThingsId},
+    models::{BucketSize, Project, ThingsId},
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate};
 use sqlx::Row;
 use tracing::{debug, instrument};
 
@@ -15,57 +18,164 @@ impl ThingsDatabase {
     /// Returns an error if the database query fails or if project data is invalid
     #[instrument]
     pub async fn get_all_projects(&self) -> ThingsResult<Vec<Project>> {
-        let rows = sqlx::query(
+        self.fetch_projects(false, true).await
+    }
+
+    /// Get all non-trashed projects, optionally leaving out those in Someday
+    ///
+    /// Someday (`start = 2` with no start date) is where Things parks on-hold
+    /// projects; see [`Self::set_project_on_hold`]. Projects scheduled for a
+    /// later date are kept. With `include_someday` set this matches
+    /// [`Self::get_all_projects`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if project data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_all_projects_with_options(
+        &self,
+        include_someday: bool,
+    ) -> ThingsResult<Vec<Project>> {
+        self.fetch_projects(false, include_someday).await
+    }
+
+    /// Get all projects, including those in the Trash
+    ///
+    /// Trashed projects have [`Project::trashed`] set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if project data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_all_projects_including_trashed(&self) -> ThingsResult<Vec<Project>> {
+        self.fetch_projects(true, true).await
+    }
+
+    async fn fetch_projects(
+        &self,
+        include_trashed: bool,
+        include_someday: bool,
+    ) -> ThingsResult<Vec<Project>> {
+        let trashed_filter = if include_trashed {
+            ""
+        } else {
+            " AND trashed = 0"
+        };
+        let someday_filter = if include_someday {
+            ""
+        } else {
+            " AND NOT (IFNULL(start, 0) = 2 AND startDate IS NULL)"
+        };
+        let sql = format!(
             r"
             SELECT 
                 uuid, title, status, 
                 area, notes, 
                 creationDate, userModificationDate,
-                startDate, deadline
+                startDate, deadline, trashed
             FROM TMTask
-            WHERE type = 1 AND trashed = 0
+            WHERE type = 1{trashed_filter}{someday_filter}
             ORDER BY creationDate DESC
+            "
+        );
+        let rows = sqlx::query(&sql)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to fetch projects: {e}")))?;
+
+        let projects = rows
+            .iter()
+            .map(|row| self.map_project(row))
+            .collect::<ThingsResult<Vec<Project>>>()?;
+
+        debug!("Fetched {} projects", projects.len());
+        Ok(projects)
+    }
+
+    /// Get all non-trashed projects paired with their area's title
+    ///
+    /// The area title is fetched in the same query via a `LEFT JOIN` on
+    /// `TMArea`, so rendering a project list needs no per-project lookup.
+    /// Projects without an area (or whose area no longer exists) pair with `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if project data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_projects_with_area_names(
+        &self,
+    ) -> ThingsResult<Vec<(Project, Option<String>)>> {
+        let rows = sqlx::query(
+            r"
+            SELECT
+                p.uuid, p.title, p.status,
+                p.area, p.notes,
+                p.creationDate, p.userModificationDate,
+                p.startDate, p.deadline, p.trashed,
+                a.title AS area_title
+            FROM TMTask p
+            LEFT JOIN TMArea a ON a.uuid = p.area
+            WHERE p.type = 1 AND p.trashed = 0
+            ORDER BY p.creationDate DESC
             ",
         )
         .fetch_all(&self.pool)
         .await
         .map_err(|e| ThingsError::unknown(format!("Failed to fetch projects: {e}")))?;
 
-        let mut projects = Vec::new();
-        for row in rows {
-            let project = Project {
-                uuid: ThingsId::from_trusted(row.get::<String, _>("uuid")),
-                title: row.get("title"),
-                status: TaskStatus::from_i32(row.get("status")).unwrap_or(TaskStatus::Incomplete),
-                area_uuid: row
-                    .get::<Option<String>, _>("area")
-                    .map(ThingsId::from_trusted),
-                notes: row.get("notes"),
-                deadline: row
-                    .get::<Option<i64>, _>("deadline")
-                    .and_then(|ts| DateTime::from_timestamp(ts, 0))
-                    .map(|dt| dt.date_naive()),
-                start_date: row
-                    .get::<Option<i64>, _>("startDate")
-                    .and_then(|ts| DateTime::from_timestamp(ts, 0))
-                    .map(|dt| dt.date_naive()),
-                tags: Vec::new(),  // TODO: Load tags separately
-                tasks: Vec::new(), // TODO: Load child tasks separately
-                created: {
-                    let ts_f64 = row.get::<f64, _>("creationDate");
-                    let ts = safe_timestamp_convert(ts_f64);
-                    DateTime::from_timestamp(ts, 0).unwrap_or_else(Utc::now)
-                },
-                modified: {
-                    let ts_f64 = row.get::<f64, _>("userModificationDate");
-                    let ts = safe_timestamp_convert(ts_f64);
-                    DateTime::from_timestamp(ts, 0).unwrap_or_else(Utc::now)
-                },
-            };
-            projects.push(project);
-        }
+        let projects = rows
+            .iter()
+            .map(|row| Ok((self.map_project(row)?, row.get("area_title"))))
+            .collect::<ThingsResult<Vec<_>>>()?;
 
-        debug!("Fetched {} projects", projects.len());
+        debug!("Fetched {} projects with area names", projects.len());
+        Ok(projects)
+    }
+
+    /// Get the largest non-trashed projects by child-task count
+    ///
+    /// Counts non-trashed to-dos per project in a single `GROUP BY project`
+    /// and returns up to `limit` projects, largest first (ties by title).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if project data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_projects_by_size(&self, limit: usize) -> ThingsResult<Vec<(Project, u64)>> {
+        let rows = sqlx::query(
+            r"
+            SELECT
+                p.uuid, p.title, p.status,
+                p.area, p.notes,
+                p.creationDate, p.userModificationDate,
+                p.startDate, p.deadline, p.trashed,
+                COALESCE(c.task_count, 0) AS task_count
+            FROM TMTask p
+            LEFT JOIN (
+                SELECT project, COUNT(*) AS task_count
+                FROM TMTask
+                WHERE type = 0 AND trashed = 0 AND project IS NOT NULL
+                GROUP BY project
+            ) c ON c.project = p.uuid
+            WHERE p.type = 1 AND p.trashed = 0
+            ORDER BY task_count DESC, p.title
+            LIMIT ?
+            ",
+        )
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch projects by size: {e}")))?;
+
+        let projects = rows
+            .iter()
+            .map(|row| {
+                let count: i64 = row.get("task_count");
+                Ok((self.map_project(row)?, count.try_into().unwrap_or(0)))
+            })
+            .collect::<ThingsResult<Vec<_>>>()?;
+
+        debug!("Fetched {} projects by size", projects.len());
         Ok(projects)
     }
 
@@ -77,7 +187,202 @@ impl ThingsDatabase {
     #[instrument(skip(self))]
     pub async fn get_projects(&self, limit: Option<usize>) -> ThingsResult<Vec<Project>> {
         let _ = limit; // Currently unused but kept for API compatibility
-        self.get_all_projects().await
+        self.with_timeout("get_projects", self.get_all_projects())
+            .await
+    }
+
+    /// Get completed projects, most recently completed first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if project data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_completed_projects(&self) -> ThingsResult<Vec<Project>> {
+        let rows = sqlx::query(
+            r"
+            SELECT
+                uuid, title, status,
+                area, notes,
+                creationDate, userModificationDate,
+                startDate, deadline
+            FROM TMTask
+            WHERE type = 1 AND status = 3 AND trashed = 0
+            ORDER BY stopDate DESC
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch completed projects: {e}")))?;
+
+        let projects = rows
+            .iter()
+            .map(|row| self.map_project(row))
+            .collect::<ThingsResult<Vec<Project>>>()?;
+
+        debug!("Fetched {} completed projects", projects.len());
+        Ok(projects)
+    }
+
+    /// Get stalled projects
+    ///
+    /// Returns non-trashed, incomplete projects with no non-trashed incomplete
+    /// to-dos, either directly in the project or under one of its headings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if project data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_stalled_projects(&self) -> ThingsResult<Vec<Project>> {
+        let rows = sqlx::query(
+            r"
+            SELECT
+                p.uuid, p.title, p.status,
+                p.area, p.notes,
+                p.creationDate, p.userModificationDate,
+                p.startDate, p.deadline
+            FROM TMTask p
+            WHERE p.type = 1 AND p.status = 0 AND p.trashed = 0
+              AND NOT EXISTS (
+                SELECT 1 FROM TMTask t
+                WHERE t.type = 0 AND t.status = 0 AND t.trashed = 0
+                  AND (t.project = p.uuid
+                       OR t.heading IN (SELECT h.uuid FROM TMTask h
+                                        WHERE h.type = 2 AND h.project = p.uuid))
+              )
+            ORDER BY p.creationDate DESC
+            ",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch stalled projects: {e}")))?;
+
+        let projects = rows
+            .iter()
+            .map(|row| self.map_project(row))
+            .collect::<ThingsResult<Vec<Project>>>()?;
+
+        debug!("Found {} stalled projects", projects.len());
+        Ok(projects)
+    }
+
+    /// Get open projects due within `within_days` days that still have open to-dos
+    ///
+    /// Returns each project with its count of non-trashed incomplete to-dos,
+    /// including those under its headings, ordered by deadline (soonest first).
+    /// Overdue projects are included. "Today" is taken in the database's
+    /// configured timezone; `within_days = 0` means due today or earlier.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if project data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_at_risk_projects(
+        &self,
+        within_days: u32,
+    ) -> ThingsResult<Vec<(Project, u64)>> {
+        let cutoff = today_in(self.timezone)
+            .checked_add_signed(Duration::days(i64::from(within_days)))
+            .unwrap_or(NaiveDate::MAX);
+        let rows = sqlx::query(
+            r"
+            SELECT
+                p.uuid, p.title, p.status,
+                p.area, p.notes,
+                p.creationDate, p.userModificationDate,
+                p.startDate, p.deadline, p.trashed,
+                c.open_count
+            FROM TMTask p
+            JOIN (
+                SELECT COALESCE(t.project, h.project) AS project, COUNT(*) AS open_count
+                FROM TMTask t
+                LEFT JOIN TMTask h ON h.uuid = t.heading AND h.type = 2
+                WHERE t.type = 0 AND t.status = 0 AND t.trashed = 0
+                GROUP BY COALESCE(t.project, h.project)
+            ) c ON c.project = p.uuid
+            WHERE p.type = 1 AND p.status = 0 AND p.trashed = 0
+              AND p.deadline IS NOT NULL AND p.deadline <= ?
+            ORDER BY p.deadline ASC, p.title
+            ",
+        )
+        .bind(naive_date_to_things_timestamp(cutoff))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch at-risk projects: {e}")))?;
+
+        let projects = rows
+            .iter()
+            .map(|row| {
+                let count: i64 = row.get("open_count");
+                Ok((self.map_project(row)?, count.try_into().unwrap_or(0)))
+            })
+            .collect::<ThingsResult<Vec<_>>>()?;
+
+        debug!("Found {} at-risk projects", projects.len());
+        Ok(projects)
+    }
+
+    /// Count to-dos completed in a project per week, over the last `weeks` weeks
+    ///
+    /// Weeks start on Monday in the database's timezone (see
+    /// [`Self::with_timezone`]) and the current, partial week is the last entry.
+    /// Returns one `(week start, completed)` pair per week, oldest first, with
+    /// zero for weeks without completions. To-dos filed under the project's
+    /// headings count toward the project. `weeks` is clamped to
+    /// [`Self::MAX_BUCKETS`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project doesn't exist or if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_project_velocity(
+        &self,
+        id: &ThingsId,
+        weeks: u32,
+    ) -> ThingsResult<Vec<(NaiveDate, u32)>> {
+        validators::validate_project_exists(&self.pool, id).await?;
+        let weeks = weeks.min(Self::MAX_BUCKETS);
+        if weeks == 0 {
+            return Ok(Vec::new());
+        }
+
+        let this_week = BucketSize::Week.start_of(today_in(self.timezone));
+        let first_week = BucketSize::Week.rewind(this_week, weeks - 1);
+        let since = start_of_day_in(first_week, self.timezone).timestamp() as f64;
+
+        let stop_dates: Vec<f64> = sqlx::query_scalar(
+            r"
+            SELECT t.stopDate FROM TMTask t
+            WHERE t.type = 0 AND t.status = 3 AND t.trashed = 0
+              AND t.stopDate >= ?
+              AND (t.project = ?
+                   OR t.heading IN (SELECT h.uuid FROM TMTask h
+                                    WHERE h.type = 2 AND h.project = ?))
+            ",
+        )
+        .bind(since)
+        .bind(id.as_str())
+        .bind(id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch project completions: {e}")))?;
+
+        let mut velocity: Vec<(NaiveDate, u32)> = (0..weeks)
+            .map(|week| (BucketSize::Week.advance(first_week, week), 0))
+            .collect();
+        for stop_date in stop_dates {
+            let Some(day) =
+                DateTime::from_timestamp(stop_date as i64, 0).map(|dt| date_in(dt, self.timezone))
+            else {
+                continue;
+            };
+            let week = (day - first_week).num_days() / 7;
+            if let Some((_, count)) = usize::try_from(week).ok().and_then(|w| velocity.get_mut(w)) {
+                *count += 1;
+            }
+        }
+
+        debug!("Computed {} week(s) of velocity for project {}", weeks, id);
+        Ok(velocity)
     }
 
     /// Get a single project by UUID
@@ -111,9 +416,40 @@ impl ThingsDatabase {
             if trashed == 1 {
                 return Ok(None);
             }
-            Ok(Some(map_project_row(&row)))
+            self.map_project(&row).map(Some)
         } else {
             Ok(None)
         }
     }
+
+    /// Get the tag titles attached to a single project, sorted by title
+    ///
+    /// Reads the `TMTaskTag` join for just this project rather than the legacy
+    /// `cachedTags` blob, which real Things 3 databases don't reliably populate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project doesn't exist or if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_project_tags(&self, id: &ThingsId) -> ThingsResult<Vec<String>> {
+        validators::validate_project_exists(&self.pool, id).await?;
+
+        let rows = sqlx::query(
+            r"
+            SELECT tg.title
+            FROM TMTaskTag tt
+            JOIN TMTag tg ON tg.uuid = tt.tags
+            WHERE tt.tasks = ?
+            ORDER BY tg.title COLLATE NOCASE
+            ",
+        )
+        .bind(id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch project tags: {e}")))?;
+
+        let tags: Vec<String> = rows.iter().map(|row| row.get("title")).collect();
+        debug!("Fetched {} tags for project {}", tags.len(), id);
+        Ok(tags)
+    }
 }
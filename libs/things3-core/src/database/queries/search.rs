@@ -0,0 +1,106 @@
+use crate::database::mappers::TASK_COLUMNS;
+use crate::{
+    database::{mappers::map_area_row, ThingsDatabase},
+    error::{Result as ThingsResult, ThingsError},
+    models::SearchHit,
+};
+use sqlx::{sqlite::SqliteRow, Row};
+use tracing::{debug, instrument};
+
+/// Match quality of `title` against the query: 3 for an exact (case-insensitive)
+/// title, 2 for a title prefix, 1 for a title substring, 0 otherwise (e.g. a
+/// notes-only match). Binds the exact, prefix and substring values in that order.
+const MATCH_RANK: &str = "CASE WHEN title = ? COLLATE NOCASE THEN 3 \
+                          WHEN title LIKE ? THEN 2 \
+                          WHEN title LIKE ? THEN 1 \
+                          ELSE 0 END AS match_rank";
+
+impl ThingsDatabase {
+    /// Search to-dos, projects and areas at once, best matches first
+    ///
+    /// Matches titles (and notes, for to-dos and projects) containing `query`,
+    /// case-insensitively, skipping trashed and canceled items. Results are
+    /// ranked exact title, then title prefix, then title substring, then
+    /// notes-only; within a rank, areas come before projects before to-dos.
+    /// At most `limit` hits are returned in total.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if item data is invalid
+    #[instrument(skip(self))]
+    pub async fn global_search(&self, query: &str, limit: usize) -> ThingsResult<Vec<SearchHit>> {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+        let limit_i64 = i64::try_from(limit).unwrap_or(i64::MAX);
+        let prefix = format!("{query}%");
+        let contains = format!("%{query}%");
+
+        // Each kind's own top `limit` is enough to find the overall top `limit`
+        let task_sql = format!(
+            "SELECT {TASK_COLUMNS}, {MATCH_RANK} FROM TMTask \
+             WHERE type = 0 AND trashed = 0 AND status != 2 AND (title LIKE ? OR notes LIKE ?) \
+             ORDER BY match_rank DESC, creationDate DESC LIMIT ?"
+        );
+        let task_rows = sqlx::query(&task_sql)
+            .bind(query)
+            .bind(&prefix)
+            .bind(&contains)
+            .bind(&contains)
+            .bind(&contains)
+            .bind(limit_i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to search tasks: {e}")))?;
+
+        let project_sql = format!(
+            "SELECT uuid, title, status, area, notes, creationDate, userModificationDate, \
+                    startDate, deadline, {MATCH_RANK} FROM TMTask \
+             WHERE type = 1 AND trashed = 0 AND status != 2 AND (title LIKE ? OR notes LIKE ?) \
+             ORDER BY match_rank DESC, creationDate DESC LIMIT ?"
+        );
+        let project_rows = sqlx::query(&project_sql)
+            .bind(query)
+            .bind(&prefix)
+            .bind(&contains)
+            .bind(&contains)
+            .bind(&contains)
+            .bind(limit_i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to search projects: {e}")))?;
+
+        let area_sql = format!(
+            "SELECT uuid, title, {MATCH_RANK} FROM TMArea WHERE title LIKE ? \
+             ORDER BY match_rank DESC, `index` ASC LIMIT ?"
+        );
+        let area_rows = sqlx::query(&area_sql)
+            .bind(query)
+            .bind(&prefix)
+            .bind(&contains)
+            .bind(&contains)
+            .bind(limit_i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to search areas: {e}")))?;
+
+        let rank = |row: &SqliteRow| row.get::<i64, _>("match_rank");
+        let mut hits: Vec<(i64, SearchHit)> = Vec::new();
+        for row in &area_rows {
+            hits.push((rank(row), SearchHit::Area(map_area_row(row)?)));
+        }
+        for row in &project_rows {
+            hits.push((rank(row), SearchHit::Project(self.map_project(row)?)));
+        }
+        for row in &task_rows {
+            hits.push((rank(row), SearchHit::Task(self.map_task(row)?)));
+        }
+
+        // Stable sort keeps areas before projects before to-dos within a rank
+        hits.sort_by_key(|(rank, _)| std::cmp::Reverse(*rank));
+        hits.truncate(limit);
+
+        debug!("Global search for {query:?} found {} hits", hits.len());
+        Ok(hits.into_iter().map(|(_, hit)| hit).collect())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::database::mappers::TASK_COLUMNS;
+use crate::{
+    database::ThingsDatabase,
+    error::{Result as ThingsResult, ThingsError},
+    models::{Project, Task, TaskType, ThingsId, ThingsSnapshot},
+};
+use chrono::DateTime;
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, instrument};
+
+impl ThingsDatabase {
+    /// Read the whole database into a nested [`ThingsSnapshot`]
+    ///
+    /// Intended for tests that compare two database states. Trashed items are
+    /// left out; an item whose parent is trashed or missing appears at the top
+    /// level instead. Things doesn't store area timestamps, so areas carry the
+    /// Unix epoch rather than the time of the call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the underlying queries fail
+    #[instrument(skip(self))]
+    pub async fn snapshot(&self) -> ThingsResult<ThingsSnapshot> {
+        let mut areas = self.get_all_areas().await?;
+        let projects = self.get_all_projects().await?;
+        let mut tags = self.get_all_tags().await?;
+
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE type IN (0, 2) AND trashed = 0"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch snapshot tasks: {e}")))?;
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        // To-dos under a heading become that heading's children
+        let heading_ids: HashSet<ThingsId> = tasks
+            .iter()
+            .filter(|t| t.task_type == TaskType::Heading)
+            .map(|t| t.uuid.clone())
+            .collect();
+        let mut by_heading: HashMap<ThingsId, Vec<Task>> = HashMap::new();
+        let mut unfiled = Vec::new();
+        for task in tasks {
+            match task.parent_uuid.clone() {
+                Some(heading) if heading_ids.contains(&heading) => {
+                    by_heading.entry(heading).or_default().push(task);
+                }
+                _ => unfiled.push(task),
+            }
+        }
+
+        // Tasks and headings go into their project
+        let mut projects: HashMap<ThingsId, Project> =
+            projects.into_iter().map(|p| (p.uuid.clone(), p)).collect();
+        let mut loose_tasks = Vec::new();
+        for mut task in unfiled {
+            if let Some(mut children) = by_heading.remove(&task.uuid) {
+                sort_by_uuid(&mut children, |t| &t.uuid);
+                task.children = children;
+            }
+            match task.project_uuid.as_ref().and_then(|p| projects.get_mut(p)) {
+                Some(project) => project.tasks.push(task),
+                None => loose_tasks.push(task),
+            }
+        }
+
+        // Projects go into their area
+        let area_index: HashMap<ThingsId, usize> = areas
+            .iter()
+            .enumerate()
+            .map(|(i, a)| (a.uuid.clone(), i))
+            .collect();
+        let mut loose_projects = Vec::new();
+        for (_, mut project) in projects.drain() {
+            sort_by_uuid(&mut project.tasks, |t| &t.uuid);
+            match project.area_uuid.as_ref().and_then(|a| area_index.get(a)) {
+                Some(&i) => areas[i].projects.push(project),
+                None => loose_projects.push(project),
+            }
+        }
+
+        for area in &mut areas {
+            area.created = DateTime::UNIX_EPOCH;
+            area.modified = DateTime::UNIX_EPOCH;
+            sort_by_uuid(&mut area.projects, |p| &p.uuid);
+        }
+        sort_by_uuid(&mut areas, |a| &a.uuid);
+        sort_by_uuid(&mut loose_projects, |p| &p.uuid);
+        sort_by_uuid(&mut loose_tasks, |t| &t.uuid);
+        sort_by_uuid(&mut tags, |t| &t.uuid);
+
+        debug!(
+            "Snapshot: {} areas, {} top-level projects, {} top-level tasks, {} tags",
+            areas.len(),
+            loose_projects.len(),
+            loose_tasks.len(),
+            tags.len()
+        );
+        Ok(ThingsSnapshot {
+            areas,
+            projects: loose_projects,
+            tasks: loose_tasks,
+            tags,
+        })
+    }
+}
+
+fn sort_by_uuid<T>(items: &mut [T], uuid: impl Fn(&T) -> &ThingsId) {
+    items.sort_by(|a, b| uuid(a).cmp(uuid(b)));
+}
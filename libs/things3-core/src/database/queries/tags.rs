@@ -1,15 +1,22 @@
+use crate::database::mappers::TASK_COLUMNS;
 use crate::{
-    database::{conversions::safe_timestamp_convert, ThingsDatabase},
+    database::{
+        conversions::safe_timestamp_convert, tag_utils::TagNormalizationConfig, ThingsDatabase,
+    },
     error::{Result as ThingsResult, ThingsError},
-    models::ThingsId,
+    models::{Task, TaskStatus, ThingsId},
 };
 use chrono::DateTime;
 use sqlx::Row;
-use tracing::instrument;
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, instrument};
 
 impl ThingsDatabase {
     /// Find a tag by normalized title (exact match, case-insensitive)
     ///
+    /// With custom [`Self::with_tag_normalization`] rules, stored titles are
+    /// normalized with the same rules before comparing.
+    ///
     /// # Errors
     ///
     /// Returns an error if the database query fails
@@ -18,6 +25,15 @@ impl ThingsDatabase {
         &self,
         normalized: &str,
     ) -> ThingsResult<Option<crate::models::Tag>> {
+        if self.tag_normalization != TagNormalizationConfig::default() {
+            let config = &self.tag_normalization;
+            return Ok(self
+                .get_all_tags()
+                .await?
+                .into_iter()
+                .find(|tag| config.normalize(&tag.title) == normalized));
+        }
+
         let row = sqlx::query(
             "SELECT uuid, title, shortcut, parent, usedDate
              FROM TMTag
@@ -68,6 +84,49 @@ impl ThingsDatabase {
         }
     }
 
+    /// Find the tag with the given keyboard shortcut
+    ///
+    /// Matches exactly and case-sensitively, as Things does.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_tag_by_shortcut(
+        &self,
+        shortcut: &str,
+    ) -> ThingsResult<Option<crate::models::Tag>> {
+        let row = sqlx::query(
+            "SELECT uuid, title, shortcut, parent, usedDate,
+                    (SELECT COUNT(*) FROM TMTaskTag tt
+                     JOIN TMTask t ON t.uuid = tt.tasks
+                     WHERE tt.tags = TMTag.uuid AND t.trashed = 0) AS usage_count
+             FROM TMTag
+             WHERE shortcut = ?
+             LIMIT 1",
+        )
+        .bind(shortcut)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to find tag by shortcut: {e}")))?;
+
+        Ok(row.map(|row| {
+            let used_ts: Option<f64> = row.get("usedDate");
+            let usage_count: i64 = row.get("usage_count");
+            crate::models::Tag {
+                uuid: ThingsId::from_trusted(row.get("uuid")),
+                title: row.get("title"),
+                shortcut: row.get("shortcut"),
+                parent_uuid: row
+                    .get::<Option<String>, _>("parent")
+                    .map(ThingsId::from_trusted),
+                usage_count: usage_count.try_into().unwrap_or(0),
+                last_used: used_ts
+                    .and_then(|ts| DateTime::from_timestamp(safe_timestamp_convert(ts), 0)),
+            }
+        }))
+    }
+
     /// Find tags similar to the given title using fuzzy matching
     ///
     /// Returns tags sorted by similarity score (highest first)
@@ -81,7 +140,9 @@ impl ThingsDatabase {
         title: &str,
         min_similarity: f32,
     ) -> ThingsResult<Vec<crate::models::TagMatch>> {
-        use crate::database::tag_utils::{calculate_similarity, get_match_type};
+        use crate::database::tag_utils::{calculate_similarity_with, get_match_type_with};
+
+        let config = &self.tag_normalization;
 
         // Get all tags
         let all_tags = self.get_all_tags().await?;
@@ -90,9 +151,9 @@ impl ThingsDatabase {
         let mut matches: Vec<crate::models::TagMatch> = all_tags
             .into_iter()
             .filter_map(|tag| {
-                let similarity = calculate_similarity(title, &tag.title);
+                let similarity = calculate_similarity_with(title, &tag.title, config);
                 if similarity >= min_similarity {
-                    let match_type = get_match_type(title, &tag.title, min_similarity);
+                    let match_type = get_match_type_with(title, &tag.title, min_similarity, config);
                     Some(crate::models::TagMatch {
                         tag,
                         similarity_score: similarity,
@@ -131,6 +192,7 @@ impl ThingsDatabase {
         .fetch_all(&self.pool)
         .await
         .map_err(|e| ThingsError::unknown(format!("Failed to search tags: {e}")))?;
+        let usage = self.tag_usage_by_uuid().await?;
 
         let mut tags = Vec::new();
         for row in rows {
@@ -146,26 +208,14 @@ impl ThingsDatabase {
                 DateTime::from_timestamp(ts_i64, 0)
             });
 
-            // Count usage via TMTaskTag join table
-            let usage_count: i64 = sqlx::query_scalar(
-                "SELECT COUNT(*) FROM TMTask
-                 WHERE trashed = 0
-                 AND EXISTS (
-                     SELECT 1 FROM TMTaskTag tt
-                     WHERE tt.tasks = TMTask.uuid AND tt.tags = ?
-                 )",
-            )
-            .bind(uuid_str.as_str())
-            .fetch_one(&self.pool)
-            .await
-            .unwrap_or(0);
+            let usage_count = usage.get(&uuid_str).copied().unwrap_or(0);
 
             tags.push(crate::models::Tag {
                 uuid: ThingsId::from_trusted(uuid_str),
                 title,
                 shortcut,
                 parent_uuid,
-                usage_count: usage_count as u32,
+                usage_count,
                 last_used,
             });
         }
@@ -188,6 +238,7 @@ impl ThingsDatabase {
         .fetch_all(&self.pool)
         .await
         .map_err(|e| ThingsError::unknown(format!("Failed to get all tags: {e}")))?;
+        let usage = self.tag_usage_by_uuid().await?;
 
         let mut tags = Vec::new();
         for row in rows {
@@ -203,26 +254,14 @@ impl ThingsDatabase {
                 DateTime::from_timestamp(ts_i64, 0)
             });
 
-            // Count usage via TMTaskTag join table
-            let usage_count: i64 = sqlx::query_scalar(
-                "SELECT COUNT(*) FROM TMTask
-                 WHERE trashed = 0
-                 AND EXISTS (
-                     SELECT 1 FROM TMTaskTag tt
-                     WHERE tt.tasks = TMTask.uuid AND tt.tags = ?
-                 )",
-            )
-            .bind(uuid_str.as_str())
-            .fetch_one(&self.pool)
-            .await
-            .unwrap_or(0);
+            let usage_count = usage.get(&uuid_str).copied().unwrap_or(0);
 
             tags.push(crate::models::Tag {
                 uuid: ThingsId::from_trusted(uuid_str),
                 title,
                 shortcut,
                 parent_uuid,
-                usage_count: usage_count as u32,
+                usage_count,
                 last_used,
             });
         }
@@ -230,6 +269,103 @@ impl ThingsDatabase {
         Ok(tags)
     }
 
+    /// Get the tag titles of every non-trashed task that has at least one tag
+    ///
+    /// Reads the `TMTaskTag` join table in one query and groups it in a single
+    /// pass, so whole-database tag analytics don't need a query per tag or per
+    /// task. Duplicate join rows are ignored. Tasks are ordered by UUID and
+    /// each task's tags by title.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn scan_all_task_tags(&self) -> ThingsResult<Vec<(ThingsId, Vec<String>)>> {
+        let links = self.scan_task_tag_links().await?;
+
+        let mut scanned: Vec<(ThingsId, Vec<String>)> = Vec::new();
+        for (task, _, title) in &links {
+            match scanned.last_mut() {
+                Some((id, tags)) if id.as_str() == task => tags.push(title.clone()),
+                _ => scanned.push((ThingsId::from_trusted(task.clone()), vec![title.clone()])),
+            }
+        }
+
+        debug!(
+            "Scanned {} tag assignment(s) across {} task(s)",
+            links.len(),
+            scanned.len()
+        );
+        Ok(scanned)
+    }
+
+    /// Read each distinct `(task, tag UUID, tag title)` assignment on a non-trashed task
+    ///
+    /// Ordered by task UUID, then tag title.
+    async fn scan_task_tag_links(&self) -> ThingsResult<Vec<(String, String, String)>> {
+        sqlx::query_as(
+            "SELECT DISTINCT tt.tasks AS task, tt.tags AS tag, tg.title
+             FROM TMTaskTag tt
+             JOIN TMTag tg ON tg.uuid = tt.tags
+             JOIN TMTask t ON t.uuid = tt.tasks
+             WHERE t.trashed = 0
+             ORDER BY tt.tasks, tg.title, tt.tags",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to scan task tags: {e}")))
+    }
+
+    /// Find non-trashed tasks still assigned tags that no longer exist
+    ///
+    /// Deleting a tag leaves its `TMTaskTag` rows behind. Those rows only hold
+    /// the deleted tag's UUID, so each task is paired with the sorted UUIDs of
+    /// its missing tags rather than titles; its [`Task::tags`] lists only the
+    /// tags that still exist. Tasks are ordered by creation date. Remove the
+    /// dangling assignments with [`Self::clean_orphan_tags`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn find_tasks_with_orphan_tags(&self) -> ThingsResult<Vec<(Task, Vec<String>)>> {
+        const ORPHAN_LINK: &str =
+            "FROM TMTaskTag tt WHERE tt.tasks = TMTask.uuid AND NOT EXISTS (SELECT 1 FROM TMTag tg WHERE tg.uuid = tt.tags)";
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS}, (SELECT GROUP_CONCAT(tt.tags, char(31)) {ORPHAN_LINK}) AS orphan_tags \
+             FROM TMTask WHERE trashed = 0 AND EXISTS (SELECT 1 {ORPHAN_LINK}) \
+             ORDER BY creationDate ASC, uuid ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to find orphan tags: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| {
+                let mut orphans: Vec<String> = row
+                    .get::<String, _>("orphan_tags")
+                    .split('\x1f')
+                    .map(str::to_owned)
+                    .collect();
+                orphans.sort();
+                Ok((self.map_task(row)?, orphans))
+            })
+            .collect::<ThingsResult<Vec<_>>>()?;
+
+        debug!("Found {} task(s) with orphan tags", tasks.len());
+        Ok(tasks)
+    }
+
+    /// Count distinct non-trashed tasks per tag UUID from one tag assignment scan
+    async fn tag_usage_by_uuid(&self) -> ThingsResult<HashMap<String, u32>> {
+        let mut usage: HashMap<String, u32> = HashMap::new();
+        for (_, tag, _) in self.scan_task_tag_links().await? {
+            *usage.entry(tag).or_insert(0) += 1;
+        }
+        Ok(usage)
+    }
+
     /// Get most frequently used tags
     ///
     /// # Errors
@@ -248,6 +384,43 @@ impl ThingsDatabase {
         Ok(all_tags)
     }
 
+    /// Rank tags by how many non-trashed tasks carry them, returning `(title, count)`
+    ///
+    /// Counts in a single `GROUP BY` over the `TMTaskTag` join table instead of
+    /// building full [`crate::models::Tag`] values with a usage subquery each, as
+    /// [`Self::get_popular_tags`] does. The `cachedTags` blob is not used because
+    /// real Things 3 databases don't reliably populate it. Returns up to `limit`
+    /// tags, most used first (ties by title); unused tags are omitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_tag_usage_ranking(&self, limit: usize) -> ThingsResult<Vec<(String, u32)>> {
+        let rows = sqlx::query(
+            "SELECT tg.title, COUNT(*) AS usage_count
+             FROM TMTaskTag tt
+             JOIN TMTag tg ON tg.uuid = tt.tags
+             JOIN TMTask t ON t.uuid = tt.tasks
+             WHERE t.trashed = 0
+             GROUP BY tg.uuid
+             ORDER BY usage_count DESC, tg.title
+             LIMIT ?",
+        )
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to rank tag usage: {e}")))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                let count: i64 = row.get("usage_count");
+                (row.get("title"), count.try_into().unwrap_or(0))
+            })
+            .collect())
+    }
+
     /// Get recently used tags
     ///
     /// Recency is determined by `MAX(t.userModificationDate)` across non-trashed tasks
@@ -326,15 +499,16 @@ impl ThingsDatabase {
         partial_input: &str,
         limit: usize,
     ) -> ThingsResult<Vec<crate::models::TagCompletion>> {
-        use crate::database::tag_utils::{calculate_similarity, normalize_tag_title};
+        use crate::database::tag_utils::calculate_similarity_with;
 
-        let normalized_input = normalize_tag_title(partial_input);
+        let config = &self.tag_normalization;
+        let normalized_input = config.normalize(partial_input);
         let all_tags = self.get_all_tags().await?;
 
         let mut completions: Vec<crate::models::TagCompletion> = all_tags
             .into_iter()
             .filter_map(|tag| {
-                let normalized_tag = normalize_tag_title(&tag.title);
+                let normalized_tag = config.normalize(&tag.title);
 
                 // Calculate score based on match type
                 let score = if normalized_tag.starts_with(&normalized_input) {
@@ -345,7 +519,7 @@ impl ThingsDatabase {
                     2.0 + (tag.usage_count as f32 / 100.0)
                 } else {
                     // Fuzzy match: lower priority
-                    let similarity = calculate_similarity(partial_input, &tag.title);
+                    let similarity = calculate_similarity_with(partial_input, &tag.title, config);
                     if similarity >= 0.6 {
                         similarity + (tag.usage_count as f32 / 1000.0)
                     } else {
@@ -410,22 +584,13 @@ impl ThingsDatabase {
         let usage_count = task_uuids.len() as u32;
 
         // Find related tags (tags that frequently appear with this tag)
-        let mut related_tags: std::collections::HashMap<String, u32> =
-            std::collections::HashMap::new();
-
-        for task_uuid in &task_uuids {
-            let rows = sqlx::query(
-                "SELECT DISTINCT tg.title FROM TMTaskTag tt
-                 JOIN TMTag tg ON tg.uuid = tt.tags
-                 WHERE tt.tasks = ?",
-            )
-            .bind(task_uuid.as_str())
-            .fetch_all(&self.pool)
-            .await
-            .map_err(|e| ThingsError::unknown(format!("Failed to fetch task tags: {e}")))?;
-
-            for row in rows {
-                let tag_title: String = row.get("title");
+        let tagged: HashSet<&str> = task_uuids.iter().map(ThingsId::as_str).collect();
+        let mut related_tags: HashMap<String, u32> = HashMap::new();
+        for (task_uuid, tags) in self.scan_all_task_tags().await? {
+            if !tagged.contains(task_uuid.as_str()) {
+                continue;
+            }
+            for tag_title in tags {
                 if tag_title != title {
                     *related_tags.entry(tag_title).or_insert(0) += 1;
                 }
@@ -445,6 +610,53 @@ impl ThingsDatabase {
         })
     }
 
+    /// Get the completion ratio of every tag that is on at least one to-do
+    ///
+    /// Tallies all non-trashed to-dos (open, completed, and canceled) in a
+    /// single scan; only completed ones count towards `completed`. Results are
+    /// sorted by tag title.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_tag_completion_stats(
+        &self,
+    ) -> ThingsResult<Vec<crate::models::TagCompletionStat>> {
+        let rows = sqlx::query(
+            "SELECT tg.title, t.status FROM TMTask t
+             JOIN TMTaskTag tt ON tt.tasks = t.uuid
+             JOIN TMTag tg ON tg.uuid = tt.tags
+             WHERE t.type = 0 AND t.trashed = 0",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch tagged tasks: {e}")))?;
+
+        // title -> (total, completed)
+        let mut tally: std::collections::BTreeMap<String, (u32, u32)> =
+            std::collections::BTreeMap::new();
+        for row in rows {
+            let entry = tally.entry(row.get("title")).or_insert((0, 0));
+            entry.0 += 1;
+            if TaskStatus::from_code(row.get("status")) == TaskStatus::Completed {
+                entry.1 += 1;
+            }
+        }
+
+        Ok(tally
+            .into_iter()
+            .map(
+                |(title, (total, completed))| crate::models::TagCompletionStat {
+                    title,
+                    total,
+                    completed,
+                    completion_ratio: f64::from(completed) / f64::from(total),
+                },
+            )
+            .collect())
+    }
+
     /// Find duplicate or highly similar tags
     ///
     /// Returns pairs of tags that are similar above the threshold
@@ -457,7 +669,7 @@ impl ThingsDatabase {
         &self,
         min_similarity: f32,
     ) -> ThingsResult<Vec<crate::models::TagPair>> {
-        use crate::database::tag_utils::calculate_similarity;
+        use crate::database::tag_utils::calculate_similarity_with;
 
         let all_tags = self.get_all_tags().await?;
         let mut pairs = Vec::new();
@@ -468,7 +680,8 @@ impl ThingsDatabase {
                 let tag1 = &all_tags[i];
                 let tag2 = &all_tags[j];
 
-                let similarity = calculate_similarity(&tag1.title, &tag2.title);
+                let similarity =
+                    calculate_similarity_with(&tag1.title, &tag2.title, &self.tag_normalization);
 
                 if similarity >= min_similarity {
                     pairs.push(crate::models::TagPair {
@@ -489,4 +702,58 @@ impl ThingsDatabase {
 
         Ok(pairs)
     }
+
+    /// Suggest tag merges for similar tag pairs
+    ///
+    /// Builds on [`Self::find_duplicate_tags`]: for each pair, the tag with the
+    /// higher `usage_count` is recommended to keep (ties go to the more recently
+    /// used tag, then the alphabetically first title). Suggestions are ordered
+    /// by similarity, highest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn suggest_tag_merges(
+        &self,
+        min_similarity: f32,
+    ) -> ThingsResult<Vec<crate::models::MergeSuggestion>> {
+        let pairs = self.find_duplicate_tags(min_similarity).await?;
+
+        let mut suggestions = Vec::with_capacity(pairs.len());
+        for pair in pairs {
+            let tag1_first = pair
+                .tag1
+                .usage_count
+                .cmp(&pair.tag2.usage_count)
+                .then_with(|| pair.tag1.last_used.cmp(&pair.tag2.last_used))
+                .then_with(|| pair.tag2.title.cmp(&pair.tag1.title))
+                .is_ge();
+            let (keep, merge) = if tag1_first {
+                (pair.tag1, pair.tag2)
+            } else {
+                (pair.tag2, pair.tag1)
+            };
+
+            let affected: i64 = sqlx::query_scalar(
+                "SELECT COUNT(DISTINCT tt.tasks) FROM TMTaskTag tt
+                 JOIN TMTask t ON t.uuid = tt.tasks
+                 WHERE t.trashed = 0 AND tt.tags IN (?, ?)",
+            )
+            .bind(keep.uuid.as_str())
+            .bind(merge.uuid.as_str())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to count merge impact: {e}")))?;
+
+            suggestions.push(crate::models::MergeSuggestion {
+                keep,
+                merge,
+                similarity: pair.similarity,
+                affected_task_count: affected.try_into().unwrap_or(0),
+            });
+        }
+
+        Ok(suggestions)
+    }
 }
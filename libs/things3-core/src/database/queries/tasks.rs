@@ -1,19 +1,129 @@
 #[cfg(feature = "advanced-queries")]
-use crate::database::conversions::naive_date_to_things_timestamp;
-#[cfg(feature = "advanced-queries")]
 use crate::models::TaskFilters;
 use crate::{
-    database::{mappers::map_task_row, ThingsDatabase},
+    database::{
+        conversions::{naive_date_to_things_timestamp, things_time_to_naive_time},
+        date_utils::{date_in, local_datetime_in, start_of_day_in, today_in},
+        mappers::TASK_COLUMNS,
+        pagination::{paginate, Paginated},
+        validators, InboxAgeStats, ThingsDatabase,
+    },
     error::{Result as ThingsResult, ThingsError},
-    models::{Task, TaskStatus, TaskType, ThingsId},
+    models::{BucketSize, IntegrityReport, Task, TaskDetail, TaskStatus, TaskType, ThingsId},
 };
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use sqlx::Row;
+use std::collections::HashMap;
 use tracing::{debug, instrument};
 #[cfg(any(feature = "advanced-queries", feature = "batch-operations"))]
 use uuid::Uuid;
 
+/// Predicate shared by `get_inbox` and `get_inbox_count`
+const INBOX_WHERE: &str = "type IN (0, 2) AND status = 0 AND project IS NULL AND trashed = 0";
+
+/// Predicate shared by `get_today` and `get_today_count`
+///
+/// Things 3 uses the `todayIndex` column to mark tasks that appear in "Today":
+/// a task is in "Today" if `todayIndex IS NOT NULL AND todayIndex != 0`.
+const TODAY_WHERE: &str =
+    "status = 0 AND todayIndex IS NOT NULL AND todayIndex != 0 AND trashed = 0";
+
+/// Predicate for `get_anytime`: to-dos in Anytime that aren't also in Today
+const ANYTIME_WHERE: &str = "type = 0 AND status = 0 AND trashed = 0 AND start = 1 AND (todayIndex IS NULL OR todayIndex = 0)";
+
+/// Predicate for `get_someday`: unscheduled to-dos in Someday
+///
+/// Someday to-dos with a start date are listed under Upcoming instead.
+const SOMEDAY_WHERE: &str =
+    "type = 0 AND status = 0 AND trashed = 0 AND start = 2 AND startDate IS NULL";
+
+/// Extract `http://` and `https://` URLs from free text, in order of appearance
+///
+/// The boundary rules are:
+/// - A URL starts at `http://` or `https://` (ASCII case-insensitive) that is
+///   not preceded by an ASCII letter or digit, so `xhttp://a.b` is skipped.
+/// - It runs until whitespace, `<`, `>`, `"`, or the end of the text.
+/// - Trailing `.`, `,`, `;`, `:`, `!`, `?`, and `'` are trimmed, as is a
+///   trailing `)`, `]`, or `}` that has no matching opener inside the URL
+///   (as in `(see https://x.y)` or Markdown `[link](https://x.y)`), repeatedly
+///   until neither applies.
+/// - A URL with nothing left after the scheme is dropped.
+fn extract_urls(text: &str) -> Vec<String> {
+    let lower = text.to_ascii_lowercase();
+    let mut urls = Vec::new();
+    let mut pos = 0;
+    while let Some(found) = lower[pos..].find("http") {
+        let start = pos + found;
+        let rest = &lower[start..];
+        let scheme_len = if rest.starts_with("https://") {
+            8
+        } else if rest.starts_with("http://") {
+            7
+        } else {
+            pos = start + 4;
+            continue;
+        };
+        if lower[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_ascii_alphanumeric())
+        {
+            pos = start + scheme_len;
+            continue;
+        }
+
+        let end = text[start..]
+            .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"'))
+            .map_or(text.len(), |len| start + len);
+        let mut url = &text[start..end];
+        loop {
+            let trimmed = url.trim_end_matches(['.', ',', ';', ':', '!', '?', '\'']);
+            let trimmed = match trimmed.chars().last() {
+                Some(close @ (')' | ']' | '}')) => {
+                    let open = match close {
+                        ')' => '(',
+                        ']' => '[',
+                        _ => '{',
+                    };
+                    if trimmed.matches(open).count() < trimmed.matches(close).count() {
+                        &trimmed[..trimmed.len() - 1]
+                    } else {
+                        trimmed
+                    }
+                }
+                _ => trimmed,
+            };
+            if trimmed.len() == url.len() {
+                break;
+            }
+            url = trimmed;
+        }
+
+        if url.len() > scheme_len {
+            urls.push(url.to_string());
+        }
+        pos = end.max(start + scheme_len);
+    }
+    urls
+}
+
+/// Predicate shared by task search and its count; binds the `LIKE` pattern twice
+fn search_where(include_canceled: bool) -> String {
+    let status_filter = if include_canceled {
+        ""
+    } else {
+        " AND status != 2"
+    };
+    format!("(title LIKE ? OR notes LIKE ?) AND type IN (0, 2) AND trashed = 0{status_filter}")
+}
+
 impl ThingsDatabase {
+    /// Largest `limit` honored by [`Self::search_logbook`]; larger requests are clamped
+    pub const MAX_QUERY_LIMIT: u32 = 10_000;
+
+    /// Largest bucket count honored by [`Self::get_creation_buckets`]; larger requests are clamped
+    pub const MAX_BUCKETS: u32 = 10_000;
+
     /// Get all tasks from the database
     ///
     /// # Examples
@@ -43,125 +153,91 @@ impl ThingsDatabase {
     /// Returns an error if the database query fails or if task data is invalid
     #[instrument]
     pub async fn get_all_tasks(&self) -> ThingsResult<Vec<Task>> {
-        let rows = sqlx::query(
-            r"
-            SELECT 
-                uuid, title, status, type, 
-                start_date, due_date, 
-                project_uuid, area_uuid, 
-                notes, tags, 
-                created, modified
-            FROM TMTask
-            ORDER BY created DESC
-            ",
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| ThingsError::unknown(format!("Failed to fetch tasks: {e}")))?;
+        let rows = self
+            .with_timeout("get_all_tasks", async {
+                sqlx::query(&format!(
+                    "SELECT {TASK_COLUMNS} FROM TMTask ORDER BY creationDate DESC"
+                ))
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| ThingsError::unknown(format!("Failed to fetch tasks: {e}")))
+            })
+            .await?;
 
-        let mut tasks = Vec::new();
-        for row in rows {
-            let task = Task {
-                uuid: ThingsId::from_trusted(row.get::<String, _>("uuid")),
-                title: row.get("title"),
-                status: TaskStatus::from_i32(row.get("status")).unwrap_or(TaskStatus::Incomplete),
-                task_type: TaskType::from_i32(row.get("type")).unwrap_or(TaskType::Todo),
-                start_date: row
-                    .get::<Option<String>, _>("start_date")
-                    .and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()),
-                deadline: row
-                    .get::<Option<String>, _>("due_date")
-                    .and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()),
-                project_uuid: row
-                    .get::<Option<String>, _>("project_uuid")
-                    .map(ThingsId::from_trusted),
-                area_uuid: row
-                    .get::<Option<String>, _>("area_uuid")
-                    .map(ThingsId::from_trusted),
-                parent_uuid: None, // Not available in this query
-                notes: row.get("notes"),
-                tags: row
-                    .get::<Option<String>, _>("tags")
-                    .map(|s| s.split(',').map(|s| s.trim().to_string()).collect())
-                    .unwrap_or_default(),
-                children: Vec::new(), // Not available in this query
-                created: DateTime::parse_from_rfc3339(&row.get::<String, _>("created"))
-                    .ok()
-                    .map_or_else(Utc::now, |dt| dt.with_timezone(&Utc)),
-                modified: DateTime::parse_from_rfc3339(&row.get::<String, _>("modified"))
-                    .ok()
-                    .map_or_else(Utc::now, |dt| dt.with_timezone(&Utc)),
-                stop_date: None, // Not available in this query context
-            };
-            tasks.push(task);
-        }
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
 
         debug!("Fetched {} tasks", tasks.len());
         Ok(tasks)
     }
 
+    /// Get every to-do and heading that isn't in the trash, newest first
+    ///
+    /// Unlike [`Self::get_all_tasks`], trashed rows and project rows are left
+    /// out. Completed and canceled items are included.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument]
+    pub async fn get_all_tasks_excluding_trashed(&self) -> ThingsResult<Vec<Task>> {
+        let rows = self
+            .with_timeout("get_all_tasks_excluding_trashed", async {
+                sqlx::query(&format!(
+                    "SELECT {TASK_COLUMNS} FROM TMTask WHERE trashed = 0 AND type IN (0, 2) \
+                     ORDER BY creationDate DESC"
+                ))
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| ThingsError::unknown(format!("Failed to fetch tasks: {e}")))
+            })
+            .await?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Fetched {} non-trashed tasks", tasks.len());
+        Ok(tasks)
+    }
+
     /// Get tasks by status
     ///
+    /// Trashed tasks are only returned for [`TaskStatus::Trashed`], which
+    /// matches rows where `trashed = 1` whatever their `status` value, as the
+    /// `query_tasks` filter does.
+    ///
     /// # Errors
     ///
     /// Returns an error if the database query fails or if task data is invalid
     #[instrument]
     pub async fn get_tasks_by_status(&self, status: TaskStatus) -> ThingsResult<Vec<Task>> {
-        let status_value = status as i32;
-        let rows = sqlx::query(
-            r"
-            SELECT 
-                uuid, title, status, type, 
-                start_date, due_date, 
-                project_uuid, area_uuid, 
-                notes, tags, 
-                created, modified
-             FROM TMTask 
-            WHERE status = ?
-            ORDER BY created DESC
-            ",
-        )
-        .bind(status_value)
-        .fetch_all(&self.pool)
-        .await
+        let rows = match status.code() {
+            None => {
+                sqlx::query(&format!(
+                    "SELECT {TASK_COLUMNS} FROM TMTask WHERE trashed = 1 ORDER BY creationDate DESC"
+                ))
+                .fetch_all(&self.pool)
+                .await
+            }
+            Some(code) => {
+                sqlx::query(&format!(
+                    "SELECT {TASK_COLUMNS} FROM TMTask WHERE status = ? AND trashed = 0 ORDER BY creationDate DESC"
+                ))
+                .bind(code)
+                .fetch_all(&self.pool)
+                .await
+            }
+        }
         .map_err(|e| ThingsError::unknown(format!("Failed to fetch tasks by status: {e}")))?;
 
-        let mut tasks = Vec::new();
-        for row in rows {
-            let task = Task {
-                uuid: ThingsId::from_trusted(row.get::<String, _>("uuid")),
-                title: row.get("title"),
-                status: TaskStatus::from_i32(row.get("status")).unwrap_or(TaskStatus::Incomplete),
-                task_type: TaskType::from_i32(row.get("type")).unwrap_or(TaskType::Todo),
-                start_date: row
-                    .get::<Option<String>, _>("start_date")
-                    .and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()),
-                deadline: row
-                    .get::<Option<String>, _>("due_date")
-                    .and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()),
-                project_uuid: row
-                    .get::<Option<String>, _>("project_uuid")
-                    .map(ThingsId::from_trusted),
-                area_uuid: row
-                    .get::<Option<String>, _>("area_uuid")
-                    .map(ThingsId::from_trusted),
-                parent_uuid: None, // Not available in this query
-                notes: row.get("notes"),
-                tags: row
-                    .get::<Option<String>, _>("tags")
-                    .map(|s| s.split(',').map(|s| s.trim().to_string()).collect())
-                    .unwrap_or_default(),
-                children: Vec::new(), // Not available in this query
-                created: DateTime::parse_from_rfc3339(&row.get::<String, _>("created"))
-                    .ok()
-                    .map_or_else(Utc::now, |dt| dt.with_timezone(&Utc)),
-                modified: DateTime::parse_from_rfc3339(&row.get::<String, _>("modified"))
-                    .ok()
-                    .map_or_else(Utc::now, |dt| dt.with_timezone(&Utc)),
-                stop_date: None, // Not available in this query context
-            };
-            tasks.push(task);
-        }
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
 
         debug!("Fetched {} tasks with status {:?}", tasks.len(), status);
         Ok(tasks)
@@ -169,38 +245,98 @@ impl ThingsDatabase {
 
     /// Search tasks by title or notes
     ///
+    /// Canceled tasks are excluded; use [`Self::search_tasks_with_options`]
+    /// to include them.
+    ///
     /// # Errors
     ///
     /// Returns an error if the database query fails or if task data is invalid
     #[instrument]
     pub async fn search_tasks(&self, query: &str) -> ThingsResult<Vec<Task>> {
-        let search_pattern = format!("%{query}%");
-        let rows = sqlx::query(
-            r"
-            SELECT
-                uuid, title, status, type,
-                startDate, deadline, stopDate,
-                project, area, heading,
-                notes,
-                (SELECT GROUP_CONCAT(tg.title, char(31))
-                   FROM TMTaskTag tt
-                   JOIN TMTag tg ON tg.uuid = tt.tags
-                  WHERE tt.tasks = TMTask.uuid) AS tags_csv,
-                creationDate, userModificationDate
-            FROM TMTask
-            WHERE (title LIKE ? OR notes LIKE ?) AND type IN (0, 2) AND trashed = 0
-            ORDER BY creationDate DESC
-            ",
-        )
-        .bind(&search_pattern)
-        .bind(&search_pattern)
-        .fetch_all(&self.pool)
+        self.with_timeout("search_tasks", self.search_tasks_with_options(query, false))
+            .await
+    }
+
+    /// Search tasks by title or notes, optionally including canceled tasks
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument]
+    pub async fn search_tasks_with_options(
+        &self,
+        query: &str,
+        include_canceled: bool,
+    ) -> ThingsResult<Vec<Task>> {
+        self.fetch_search_results(query, include_canceled, None)
+            .await
+    }
+
+    /// Get one page of search results, with the total match count
+    ///
+    /// Matches [`Self::search_tasks_with_options`]: canceled tasks are only
+    /// included when `include_canceled` is true.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn search_tasks_page(
+        &self,
+        query: &str,
+        include_canceled: bool,
+        offset: u32,
+        limit: u32,
+    ) -> ThingsResult<Paginated<Task>> {
+        let total = async {
+            let count: i64 = sqlx::query_scalar(&format!(
+                "SELECT COUNT(*) FROM TMTask WHERE {}",
+                search_where(include_canceled)
+            ))
+            .bind(format!("%{query}%"))
+            .bind(format!("%{query}%"))
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to count search results: {e}")))?;
+            Ok(count.try_into().unwrap_or(0))
+        };
+
+        paginate(offset, limit, total, |offset, limit| {
+            self.fetch_search_results(query, include_canceled, Some((offset, limit)))
+        })
         .await
-        .map_err(|e| ThingsError::unknown(format!("Failed to search tasks: {e}")))?;
+    }
+
+    /// Run a task search, optionally windowed by `(offset, limit)`
+    async fn fetch_search_results(
+        &self,
+        query: &str,
+        include_canceled: bool,
+        window: Option<(u32, u32)>,
+    ) -> ThingsResult<Vec<Task>> {
+        let search_pattern = format!("%{query}%");
+        let mut sql = format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE {} ORDER BY creationDate DESC",
+            search_where(include_canceled)
+        );
+        if window.is_some() {
+            sql.push_str(" LIMIT ? OFFSET ?");
+        }
+
+        let mut q = sqlx::query(&sql)
+            .bind(&search_pattern)
+            .bind(&search_pattern);
+        if let Some((offset, limit)) = window {
+            q = q.bind(i64::from(limit)).bind(i64::from(offset));
+        }
+        let rows = q
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to search tasks: {e}")))?;
 
         let tasks = rows
             .iter()
-            .map(map_task_row)
+            .map(|row| self.map_task(row))
             .collect::<ThingsResult<Vec<Task>>>()?;
 
         debug!("Found {} tasks matching query: {}", tasks.len(), query);
@@ -244,26 +380,14 @@ impl ThingsDatabase {
         filters: &TaskFilters,
         after: Option<(i64, Uuid)>,
     ) -> ThingsResult<Vec<Task>> {
-        const COLS: &str = "uuid, title, type, status, notes, startDate, deadline, stopDate, \
-                            creationDate, userModificationDate, project, area, heading, \
-                            (SELECT GROUP_CONCAT(tg.title, char(31)) \
-                               FROM TMTaskTag tt \
-                               JOIN TMTag tg ON tg.uuid = tt.tags \
-                              WHERE tt.tasks = TMTask.uuid) AS tags_csv";
-
         // Things 3 soft-deletes by setting trashed = 1; the status column is unchanged.
         // Requesting Trashed means "show trashed rows", not a status = 3 predicate.
         let trashed_val = i32::from(matches!(filters.status, Some(TaskStatus::Trashed)));
         let mut conditions: Vec<String> = vec![format!("trashed = {trashed_val}")];
 
         if let Some(status) = filters.status {
-            let n = match status {
-                TaskStatus::Incomplete => Some(0),
-                TaskStatus::Canceled => Some(2),
-                TaskStatus::Completed => Some(3),
-                TaskStatus::Trashed => None, // handled via trashed = 1 above
-            };
-            if let Some(n) = n {
+            // Trashed has no code; it's handled via trashed = 1 above
+            if let Some(n) = status.code() {
                 conditions.push(format!("status = {n}"));
             }
         }
@@ -331,7 +455,7 @@ impl ThingsDatabase {
         // `Task::created` is reconstructed at second precision). `uuid DESC` is
         // a deterministic tiebreak within the same second.
         let mut sql = format!(
-            "SELECT {COLS} FROM TMTask WHERE {where_clause} \
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE {where_clause} \
              ORDER BY CAST(creationDate AS INTEGER) DESC, uuid DESC"
         );
 
@@ -368,7 +492,7 @@ impl ThingsDatabase {
 
         let mut tasks = rows
             .iter()
-            .map(map_task_row)
+            .map(|row| self.map_task(row))
             .collect::<ThingsResult<Vec<Task>>>()?;
 
         if let Some(ref filter_tags) = filters.tags {
@@ -414,7 +538,8 @@ impl ThingsDatabase {
     /// - `project_uuid`: Filter by project UUID
     /// - `area_uuid`: Filter by area UUID
     /// - `tags`: Filter by tags (all tags must match)
-    /// - `limit`: Maximum number of results (default: 50)
+    /// - `limit`: Maximum number of results (default: 50, capped at
+    ///   [`Self::MAX_QUERY_LIMIT`])
     ///
     /// # Errors
     ///
@@ -433,94 +558,76 @@ impl ThingsDatabase {
         offset: Option<u32>,
     ) -> ThingsResult<Vec<Task>> {
         // Apply limit and offset
-        let result_limit = limit.unwrap_or(50).min(500);
+        let result_limit = limit.unwrap_or(50).min(Self::MAX_QUERY_LIMIT);
         let result_offset = offset.unwrap_or(0);
 
-        // Build and execute query based on filters
+        // Build the query with bound parameters only, so each filter combination
+        // maps to one stable SQL string that the per-connection statement cache reuses.
         // type = 0 (Todo) is intentional here: headings (type=2) have no stopDate and
         // cannot appear in a stop-date-ordered logbook.
-        let rows = if let Some(ref text) = search_text {
-            let pattern = format!("%{text}%");
-            let mut q = String::from(
-                "SELECT uuid, title, status, type, startDate, deadline, stopDate, project, area, heading, notes, (SELECT GROUP_CONCAT(tg.title, char(31)) FROM TMTaskTag tt JOIN TMTag tg ON tg.uuid = tt.tags WHERE tt.tasks = TMTask.uuid) AS tags_csv, creationDate, userModificationDate FROM TMTask WHERE status = 3 AND trashed = 0 AND type = 0",
-            );
-            q.push_str(" AND (title LIKE ? OR notes LIKE ?)");
-
-            if let Some(date) = from_date {
-                // stopDate is stored as Unix timestamp (seconds since 1970-01-01)
-                let date_time = date.and_hms_opt(0, 0, 0).unwrap().and_utc();
-                let timestamp = date_time.timestamp() as f64;
-                q.push_str(&format!(" AND stopDate >= {}", timestamp));
-            }
-
-            if let Some(date) = to_date {
-                // Include tasks completed on to_date by adding 1 day
-                let end_date = date + chrono::Duration::days(1);
-                let date_time = end_date.and_hms_opt(0, 0, 0).unwrap().and_utc();
-                let timestamp = date_time.timestamp() as f64;
-                q.push_str(&format!(" AND stopDate < {}", timestamp));
-            }
-
-            if let Some(ref id) = project_uuid {
-                q.push_str(&format!(" AND project = '{}'", id));
-            }
+        let mut sql = format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE status = 3 AND trashed = 0 AND type = 0"
+        );
 
-            if let Some(ref id) = area_uuid {
-                q.push_str(&format!(" AND area = '{}'", id));
-            }
+        let pattern = search_text.as_ref().map(|text| format!("%{text}%"));
+        if pattern.is_some() {
+            sql.push_str(" AND (title LIKE ? OR notes LIKE ?)");
+        }
 
-            q.push_str(&format!(
-                " ORDER BY stopDate DESC LIMIT {result_limit} OFFSET {result_offset}"
-            ));
+        // stopDate is stored as Unix timestamp (seconds since 1970-01-01)
+        let from_timestamp =
+            from_date.map(|date| start_of_day_in(date, self.timezone).timestamp() as f64);
+        if from_timestamp.is_some() {
+            sql.push_str(" AND stopDate >= ?");
+        }
 
-            sqlx::query(&q)
-                .bind(&pattern)
-                .bind(&pattern)
-                .fetch_all(&self.pool)
-                .await
-                .map_err(|e| ThingsError::unknown(format!("Failed to search logbook: {e}")))?
-        } else {
-            let mut q = String::from(
-                "SELECT uuid, title, status, type, startDate, deadline, stopDate, project, area, heading, notes, (SELECT GROUP_CONCAT(tg.title, char(31)) FROM TMTaskTag tt JOIN TMTag tg ON tg.uuid = tt.tags WHERE tt.tasks = TMTask.uuid) AS tags_csv, creationDate, userModificationDate FROM TMTask WHERE status = 3 AND trashed = 0 AND type = 0",
-            );
-
-            if let Some(date) = from_date {
-                // stopDate is stored as Unix timestamp (seconds since 1970-01-01)
-                let date_time = date.and_hms_opt(0, 0, 0).unwrap().and_utc();
-                let timestamp = date_time.timestamp() as f64;
-                q.push_str(&format!(" AND stopDate >= {}", timestamp));
-            }
+        // Include tasks completed on to_date by bounding at the start of the next day
+        let to_timestamp = to_date.map(|date| {
+            start_of_day_in(date + chrono::Duration::days(1), self.timezone).timestamp() as f64
+        });
+        if to_timestamp.is_some() {
+            sql.push_str(" AND stopDate < ?");
+        }
 
-            if let Some(date) = to_date {
-                // Include tasks completed on to_date by adding 1 day
-                let end_date = date + chrono::Duration::days(1);
-                let date_time = end_date.and_hms_opt(0, 0, 0).unwrap().and_utc();
-                let timestamp = date_time.timestamp() as f64;
-                q.push_str(&format!(" AND stopDate < {}", timestamp));
-            }
+        if project_uuid.is_some() {
+            sql.push_str(" AND project = ?");
+        }
 
-            if let Some(ref id) = project_uuid {
-                q.push_str(&format!(" AND project = '{}'", id));
-            }
+        if area_uuid.is_some() {
+            sql.push_str(" AND area = ?");
+        }
 
-            if let Some(ref id) = area_uuid {
-                q.push_str(&format!(" AND area = '{}'", id));
-            }
+        // uuid breaks stopDate ties so LIMIT/OFFSET pages never skip or repeat a row
+        sql.push_str(" ORDER BY stopDate DESC, uuid LIMIT ? OFFSET ?");
 
-            q.push_str(&format!(
-                " ORDER BY stopDate DESC LIMIT {result_limit} OFFSET {result_offset}"
-            ));
+        let mut query = sqlx::query(&sql);
+        if let Some(pattern) = &pattern {
+            query = query.bind(pattern).bind(pattern);
+        }
+        if let Some(timestamp) = from_timestamp {
+            query = query.bind(timestamp);
+        }
+        if let Some(timestamp) = to_timestamp {
+            query = query.bind(timestamp);
+        }
+        if let Some(id) = &project_uuid {
+            query = query.bind(id.as_str());
+        }
+        if let Some(id) = &area_uuid {
+            query = query.bind(id.as_str());
+        }
 
-            sqlx::query(&q)
-                .fetch_all(&self.pool)
-                .await
-                .map_err(|e| ThingsError::unknown(format!("Failed to search logbook: {e}")))?
-        };
+        let rows = query
+            .bind(i64::from(result_limit))
+            .bind(i64::from(result_offset))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to search logbook: {e}")))?;
 
         // Filter by tags if provided
         let mut tasks = rows
             .iter()
-            .map(map_task_row)
+            .map(|row| self.map_task(row))
             .collect::<ThingsResult<Vec<Task>>>()?;
 
         if let Some(ref filter_tags) = tags {
@@ -538,107 +645,1387 @@ impl ThingsDatabase {
         Ok(tasks)
     }
 
-    /// Get inbox tasks (incomplete tasks without project)
+    /// Get all tasks belonging to completed projects
+    ///
+    /// Includes tasks placed directly in a completed project and tasks under
+    /// one of its headings. Completed projects have `status = 3`, the same
+    /// code `complete_project` writes.
     ///
     /// # Errors
     ///
     /// Returns an error if the database query fails or if task data is invalid
     #[instrument(skip(self))]
-    pub async fn get_inbox(&self, limit: Option<usize>) -> ThingsResult<Vec<Task>> {
-        let query = if let Some(limit) = limit {
-            format!("SELECT uuid, title, type, status, notes, startDate, deadline, stopDate, creationDate, userModificationDate, project, area, heading, (SELECT GROUP_CONCAT(tg.title, char(31)) FROM TMTaskTag tt JOIN TMTag tg ON tg.uuid = tt.tags WHERE tt.tasks = TMTask.uuid) AS tags_csv FROM TMTask WHERE type IN (0, 2) AND status = 0 AND project IS NULL AND trashed = 0 ORDER BY creationDate DESC LIMIT {limit}")
-        } else {
-            "SELECT uuid, title, type, status, notes, startDate, deadline, stopDate, creationDate, userModificationDate, project, area, heading, (SELECT GROUP_CONCAT(tg.title, char(31)) FROM TMTaskTag tt JOIN TMTag tg ON tg.uuid = tt.tags WHERE tt.tasks = TMTask.uuid) AS tags_csv FROM TMTask WHERE type IN (0, 2) AND status = 0 AND project IS NULL AND trashed = 0 ORDER BY creationDate DESC"
-                .to_string()
-        };
-
-        let rows = sqlx::query(&query)
-            .fetch_all(&self.pool)
-            .await
-            .map_err(|e| ThingsError::unknown(format!("Failed to fetch inbox tasks: {e}")))?;
+    pub async fn get_tasks_in_completed_projects(&self) -> ThingsResult<Vec<Task>> {
+        // The owning project: the task's own, or its heading's
+        const OWNER: &str =
+            "COALESCE(project, (SELECT h.project FROM TMTask h WHERE h.uuid = TMTask.heading))";
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask \
+             WHERE type IN (0, 2) AND trashed = 0 \
+               AND {OWNER} IN (SELECT p.uuid FROM TMTask p WHERE p.type = 1 AND p.status = 3 AND p.trashed = 0) \
+             ORDER BY (SELECT p.stopDate FROM TMTask p WHERE p.uuid = {OWNER}) DESC, creationDate DESC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            ThingsError::unknown(format!("Failed to fetch tasks in completed projects: {e}"))
+        })?;
 
         let tasks = rows
             .iter()
-            .map(map_task_row)
+            .map(|row| self.map_task(row))
             .collect::<ThingsResult<Vec<Task>>>()?;
 
+        debug!("Found {} tasks in completed projects", tasks.len());
         Ok(tasks)
     }
 
-    /// Get today's tasks (incomplete tasks due today or started today)
+    /// Count non-trashed to-dos per status in a single query
+    ///
+    /// Statuses with no to-dos are absent from the map. Rows with an unknown
+    /// status code are counted under [`TaskStatus::Unknown`].
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails or if task data is invalid
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_status_counts(&self) -> ThingsResult<HashMap<TaskStatus, u64>> {
+        let rows = sqlx::query(
+            "SELECT status, COUNT(*) AS count FROM TMTask WHERE trashed = 0 AND type = 0 GROUP BY status",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to count tasks by status: {e}")))?;
+
+        let mut counts = HashMap::new();
+        for row in &rows {
+            let status = TaskStatus::from_code(row.get("status"));
+            let count: i64 = row.get("count");
+            counts.insert(status, count.try_into().unwrap_or(0));
+        }
+
+        debug!("Counted tasks across {} statuses", counts.len());
+        Ok(counts)
+    }
+
+    /// Get the current completion streak in days
     ///
-    /// # Panics
+    /// Walks backward from today over completed tasks' `stopDate`, counting
+    /// consecutive days with at least one completion and stopping at the
+    /// first day without one. Days are bucketed in the database's configured
+    /// timezone (see [`Self::with_timezone`]), so a task completed at 23:30
+    /// local time counts for that day even when it falls on the next UTC date.
+    /// Returns `0` when nothing was completed today.
     ///
-    /// Panics if the current date cannot be converted to a valid time with hours, minutes, and seconds
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
     #[instrument(skip(self))]
-    pub async fn get_today(&self, limit: Option<usize>) -> ThingsResult<Vec<Task>> {
-        // Things 3 uses the `todayIndex` column to mark tasks that appear in "Today"
-        // A task is in "Today" if todayIndex IS NOT NULL AND todayIndex != 0
-        let query = if let Some(limit) = limit {
-            format!(
-                "SELECT uuid, title, type, status, notes, startDate, deadline, stopDate, creationDate, userModificationDate, project, area, heading, (SELECT GROUP_CONCAT(tg.title, char(31)) FROM TMTaskTag tt JOIN TMTag tg ON tg.uuid = tt.tags WHERE tt.tasks = TMTask.uuid) AS tags_csv FROM TMTask WHERE status = 0 AND todayIndex IS NOT NULL AND todayIndex != 0 AND trashed = 0 ORDER BY todayIndex ASC LIMIT {limit}"
-            )
-        } else {
-            "SELECT uuid, title, type, status, notes, startDate, deadline, stopDate, creationDate, userModificationDate, project, area, heading, (SELECT GROUP_CONCAT(tg.title, char(31)) FROM TMTaskTag tt JOIN TMTag tg ON tg.uuid = tt.tags WHERE tt.tasks = TMTask.uuid) AS tags_csv FROM TMTask WHERE status = 0 AND todayIndex IS NOT NULL AND todayIndex != 0 AND trashed = 0 ORDER BY todayIndex ASC".to_string()
-        };
+    pub async fn get_completion_streak(&self) -> ThingsResult<u32> {
+        let stop_dates: Vec<f64> = sqlx::query_scalar(
+            "SELECT stopDate FROM TMTask WHERE status = 3 AND trashed = 0 AND type = 0 AND stopDate IS NOT NULL ORDER BY stopDate DESC",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch completion dates: {e}")))?;
 
-        let rows = sqlx::query(&query)
-            .fetch_all(&self.pool)
+        let mut streak = 0;
+        let mut expected = today_in(self.timezone);
+        for stop_date in stop_dates {
+            let Some(day) =
+                DateTime::from_timestamp(stop_date as i64, 0).map(|dt| date_in(dt, self.timezone))
+            else {
+                continue;
+            };
+
+            // Later days were already counted (or are in the future)
+            if day > expected {
+                continue;
+            }
+            if day < expected {
+                break;
+            }
+            streak += 1;
+            expected -= Duration::days(1);
+        }
+
+        debug!("Current completion streak: {} day(s)", streak);
+        Ok(streak)
+    }
+
+    /// Count inbox tasks without fetching them
+    ///
+    /// Uses the same predicate as [`Self::get_inbox`], so the count always
+    /// matches the unlimited list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_inbox_count(&self) -> ThingsResult<u64> {
+        let count: i64 =
+            sqlx::query_scalar(&format!("SELECT COUNT(*) FROM TMTask WHERE {INBOX_WHERE}"))
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| ThingsError::unknown(format!("Failed to count inbox tasks: {e}")))?;
+
+        Ok(count.try_into().unwrap_or(0))
+    }
+
+    /// Get the count, average age, and maximum age (in days) of inbox items
+    ///
+    /// Ages are measured from `creationDate` to now, over the same items as
+    /// [`Self::get_inbox`]. An empty inbox yields all zeros rather than NaN.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_inbox_age_stats(&self) -> ThingsResult<InboxAgeStats> {
+        let (count, avg_created, min_created): (i64, Option<f64>, Option<f64>) =
+            sqlx::query_as(&format!(
+                "SELECT COUNT(*), AVG(creationDate), MIN(creationDate) FROM TMTask WHERE {INBOX_WHERE}"
+            ))
+            .fetch_one(&self.pool)
             .await
-            .map_err(|e| ThingsError::unknown(format!("Failed to fetch today's tasks: {e}")))?;
+            .map_err(|e| ThingsError::unknown(format!("Failed to compute inbox age: {e}")))?;
 
-        let tasks = rows
-            .iter()
-            .map(map_task_row)
-            .collect::<ThingsResult<Vec<Task>>>()?;
+        let now = Utc::now().timestamp() as f64;
+        let age_days =
+            |created: Option<f64>| created.map_or(0.0, |c| ((now - c) / 86_400.0).max(0.0));
 
-        Ok(tasks)
+        Ok(InboxAgeStats {
+            count: count.try_into().unwrap_or(0),
+            avg_age_days: age_days(avg_created),
+            max_age_days: age_days(min_created),
+        })
     }
 
-    /// Get a task by its UUID
+    /// Count incomplete to-dos whose deadline is before today
+    ///
+    /// "Today" is taken in the database's configured timezone.
     ///
     /// # Errors
     ///
-    /// Returns an error if the task does not exist or if the database query fails
+    /// Returns an error if the database query fails
     #[instrument(skip(self))]
-    pub async fn get_task_by_uuid(&self, id: &ThingsId) -> ThingsResult<Option<Task>> {
-        let row = sqlx::query(
-            r"
-            SELECT
-                uuid, title, status, type,
-                startDate, deadline, stopDate,
-                project, area, heading,
-                notes, (SELECT GROUP_CONCAT(tg.title, char(31))
-                          FROM TMTaskTag tt
-                          JOIN TMTag tg ON tg.uuid = tt.tags
-                         WHERE tt.tasks = TMTask.uuid) AS tags_csv,
-                creationDate, userModificationDate,
-                trashed
-            FROM TMTask
-            WHERE uuid = ?
-            ",
+    pub async fn get_overdue_count(&self) -> ThingsResult<u64> {
+        let today = naive_date_to_things_timestamp(today_in(self.timezone));
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM TMTask WHERE type = 0 AND status = 0 AND trashed = 0 AND deadline IS NOT NULL AND deadline < ?",
         )
-        .bind(id.as_str())
-        .fetch_optional(&self.pool)
+        .bind(today)
+        .fetch_one(&self.pool)
         .await
-        .map_err(|e| ThingsError::unknown(format!("Failed to fetch task: {e}")))?;
+        .map_err(|e| ThingsError::unknown(format!("Failed to count overdue tasks: {e}")))?;
 
-        if let Some(row) = row {
-            // Check if trashed
-            let trashed: i64 = row.get("trashed");
-            if trashed == 1 {
-                return Ok(None); // Return None for trashed tasks
-            }
+        Ok(count.try_into().unwrap_or(0))
+    }
 
-            // Use the centralized mapper
-            let task = map_task_row(&row)?;
-            Ok(Some(task))
-        } else {
-            Ok(None)
-        }
+    /// Count to-dos completed since the start of today
+    ///
+    /// "Today" is taken in the database's configured timezone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_completed_today_count(&self) -> ThingsResult<u64> {
+        let start = start_of_day_in(today_in(self.timezone), self.timezone).timestamp() as f64;
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM TMTask WHERE type = 0 AND status = 3 AND trashed = 0 AND stopDate >= ?",
+        )
+        .bind(start)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to count tasks completed today: {e}")))?;
+
+        Ok(count.try_into().unwrap_or(0))
+    }
+
+    /// Get one page of inbox tasks, with the total inbox count
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_inbox_page(&self, offset: u32, limit: u32) -> ThingsResult<Paginated<Task>> {
+        paginate(offset, limit, self.get_inbox_count(), |offset, limit| async move {
+            let rows = sqlx::query(&format!(
+                "SELECT {TASK_COLUMNS} FROM TMTask WHERE {INBOX_WHERE} ORDER BY creationDate DESC LIMIT ? OFFSET ?"
+            ))
+            .bind(i64::from(limit))
+            .bind(i64::from(offset))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to fetch inbox tasks: {e}")))?;
+
+            rows.iter().map(|row| self.map_task(row)).collect()
+        })
+        .await
+    }
+
+    /// Count today's tasks without fetching them
+    ///
+    /// Uses the same predicate as [`Self::get_today`], so the count always
+    /// matches the unlimited list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_today_count(&self) -> ThingsResult<u64> {
+        let count: i64 =
+            sqlx::query_scalar(&format!("SELECT COUNT(*) FROM TMTask WHERE {TODAY_WHERE}"))
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| ThingsError::unknown(format!("Failed to count today's tasks: {e}")))?;
+
+        Ok(count.try_into().unwrap_or(0))
+    }
+
+    /// Get actionable to-dos ranked for working through, most urgent first
+    ///
+    /// Covers open, non-trashed to-dos that aren't in Someday (`start = 2`)
+    /// and aren't scheduled for a future date. They are ranked overdue, then
+    /// due today, then in Today, then past their start date, then everything
+    /// else; each group is ordered oldest created first. "Today" is taken in
+    /// the database's configured timezone. Returns at most `limit` tasks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_engage_list(&self, limit: usize) -> ThingsResult<Vec<Task>> {
+        let today = naive_date_to_things_timestamp(today_in(self.timezone));
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask \
+             WHERE type = 0 AND status = 0 AND trashed = 0 \
+             AND (start IS NULL OR start != 2) \
+             AND (startDate IS NULL OR startDate <= ?) \
+             ORDER BY CASE \
+                 WHEN deadline IS NOT NULL AND deadline < ? THEN 0 \
+                 WHEN deadline = ? THEN 1 \
+                 WHEN todayIndex IS NOT NULL AND todayIndex != 0 THEN 2 \
+                 WHEN startDate IS NOT NULL THEN 3 \
+                 ELSE 4 \
+             END, creationDate ASC \
+             LIMIT ?"
+        ))
+        .bind(today)
+        .bind(today)
+        .bind(today)
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch engage list: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Fetched {} engage list task(s)", tasks.len());
+        Ok(tasks)
+    }
+
+    /// Get incomplete to-dos whose start date has passed but that aren't in Today
+    ///
+    /// These should already have surfaced: Things rolls them into Today the next
+    /// time it runs. "Today" is taken in the database's configured timezone.
+    /// Ordered by start date, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_overdue_to_start(&self) -> ThingsResult<Vec<Task>> {
+        let today = naive_date_to_things_timestamp(today_in(self.timezone));
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE type = 0 AND status = 0 AND trashed = 0 AND startDate IS NOT NULL AND startDate < ? AND (todayIndex IS NULL OR todayIndex = 0) ORDER BY startDate ASC, creationDate ASC"
+        ))
+        .bind(today)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch overdue-to-start tasks: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Fetched {} overdue-to-start tasks", tasks.len());
+        Ok(tasks)
+    }
+
+    /// Find to-dos and projects whose deadline falls before their start date
+    ///
+    /// Writes reject such ranges (see [`crate::database::validate_date_range`]),
+    /// but rows imported or edited elsewhere may already violate it. Covers all
+    /// non-trashed rows regardless of status, ordered by deadline.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn find_invalid_date_ranges(&self) -> ThingsResult<Vec<Task>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE type IN (0, 1) AND trashed = 0 AND startDate IS NOT NULL AND deadline IS NOT NULL AND deadline < startDate ORDER BY deadline ASC, creationDate ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch invalid date ranges: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Found {} tasks with invalid date ranges", tasks.len());
+        Ok(tasks)
+    }
+
+    /// Audit references between tasks, projects, areas, headings, and tags
+    ///
+    /// Collects non-trashed to-dos and projects whose `project` or `area`
+    /// doesn't resolve, to-dos filed under a missing heading, tags whose parent
+    /// is gone, and the rows [`Self::find_invalid_date_ranges`] reports. A
+    /// reference to a trashed project, area, or heading still counts as valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a database query fails
+    #[instrument(skip(self))]
+    pub async fn check_integrity(&self) -> ThingsResult<IntegrityReport> {
+        let uuids = |sql: &'static str, what: &'static str| async move {
+            sqlx::query_scalar::<_, String>(sql)
+                .fetch_all(&self.pool)
+                .await
+                .map(|ids| ids.into_iter().map(ThingsId::from_trusted).collect())
+                .map_err(|e| ThingsError::unknown(format!("Failed to find {what}: {e}")))
+        };
+
+        let orphaned_tasks: Vec<ThingsId> = uuids(
+            "SELECT t.uuid FROM TMTask t
+             WHERE t.type IN (0, 1) AND t.trashed = 0
+             AND ((t.project IS NOT NULL
+                   AND NOT EXISTS (SELECT 1 FROM TMTask p WHERE p.uuid = t.project AND p.type = 1))
+               OR (t.area IS NOT NULL
+                   AND NOT EXISTS (SELECT 1 FROM TMArea a WHERE a.uuid = t.area)))
+             ORDER BY t.creationDate ASC, t.uuid ASC",
+            "orphaned tasks",
+        )
+        .await?;
+
+        let missing_headings: Vec<ThingsId> = uuids(
+            "SELECT t.uuid FROM TMTask t
+             WHERE t.type = 0 AND t.trashed = 0 AND t.heading IS NOT NULL
+             AND NOT EXISTS (SELECT 1 FROM TMTask h WHERE h.uuid = t.heading AND h.type = 2)
+             ORDER BY t.creationDate ASC, t.uuid ASC",
+            "tasks with missing headings",
+        )
+        .await?;
+
+        let orphaned_tags: Vec<ThingsId> = uuids(
+            "SELECT tg.uuid FROM TMTag tg
+             WHERE tg.parent IS NOT NULL
+             AND NOT EXISTS (SELECT 1 FROM TMTag p WHERE p.uuid = tg.parent)
+             ORDER BY tg.title",
+            "orphaned tags",
+        )
+        .await?;
+
+        let invalid_date_ranges = self
+            .find_invalid_date_ranges()
+            .await?
+            .into_iter()
+            .map(|task| task.uuid)
+            .collect();
+
+        let report = IntegrityReport {
+            orphaned_tasks,
+            missing_headings,
+            orphaned_tags,
+            invalid_date_ranges,
+        };
+        debug!("Integrity check: {report:?}");
+        Ok(report)
+    }
+
+    /// Get inbox tasks (incomplete tasks without project)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_inbox(&self, limit: Option<usize>) -> ThingsResult<Vec<Task>> {
+        let sql = format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE {INBOX_WHERE} ORDER BY creationDate DESC"
+        );
+
+        // Bind the limit so every call shares one cached prepared statement
+        let rows = self
+            .with_timeout("get_inbox", async {
+                if let Some(limit) = limit {
+                    sqlx::query(&format!("{sql} LIMIT ?"))
+                        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+                        .fetch_all(&self.pool)
+                        .await
+                } else {
+                    sqlx::query(&sql).fetch_all(&self.pool).await
+                }
+                .map_err(|e| ThingsError::unknown(format!("Failed to fetch inbox tasks: {e}")))
+            })
+            .await?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        Ok(tasks)
+    }
+
+    /// Count to-dos created per calendar bucket over the last `count` buckets
+    ///
+    /// Buckets follow the database's timezone (see [`Self::with_timezone`]);
+    /// weeks start on Monday and the current, partial bucket is the last entry.
+    /// Returns one `(bucket start, created)` pair per bucket, oldest first,
+    /// with zero for empty buckets. Trashed to-dos are not counted. `count` is
+    /// clamped to [`Self::MAX_BUCKETS`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_creation_buckets(
+        &self,
+        bucket: BucketSize,
+        count: u32,
+    ) -> ThingsResult<Vec<(NaiveDate, u32)>> {
+        let count = count.min(Self::MAX_BUCKETS);
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+
+        let current = bucket.start_of(today_in(self.timezone));
+        let first = bucket.rewind(current, count - 1);
+        let since = start_of_day_in(first, self.timezone).timestamp() as f64;
+
+        let created: Vec<f64> = sqlx::query_scalar(
+            "SELECT creationDate FROM TMTask WHERE type = 0 AND trashed = 0 AND creationDate >= ?",
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch creation dates: {e}")))?;
+
+        let mut buckets: Vec<(NaiveDate, u32)> =
+            (0..count).map(|n| (bucket.advance(first, n), 0)).collect();
+        for creation_date in created {
+            let Some(day) = DateTime::from_timestamp(creation_date as i64, 0)
+                .map(|dt| date_in(dt, self.timezone))
+            else {
+                continue;
+            };
+            let start = bucket.start_of(day);
+            if let Ok(i) = buckets.binary_search_by_key(&start, |(date, _)| *date) {
+                buckets[i].1 += 1;
+            }
+        }
+
+        debug!("Counted creations in {} {:?} bucket(s)", count, bucket);
+        Ok(buckets)
+    }
+
+    /// Find inbox tasks that share the same title
+    ///
+    /// Titles are compared after trimming and lowercasing, with no fuzzy
+    /// matching. Only groups with more than one member are returned. Each group
+    /// is ordered oldest first, and groups are ordered by their oldest task.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn find_exact_duplicate_inbox_tasks(&self) -> ThingsResult<Vec<Vec<Task>>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE {INBOX_WHERE} ORDER BY creationDate ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch inbox tasks: {e}")))?;
+
+        let mut groups: Vec<Vec<Task>> = Vec::new();
+        let mut index_by_title: HashMap<String, usize> = HashMap::new();
+        for row in &rows {
+            let task = self.map_task(row)?;
+            let key = task.title.trim().to_lowercase();
+            if let Some(&index) = index_by_title.get(&key) {
+                groups[index].push(task);
+            } else {
+                index_by_title.insert(key, groups.len());
+                groups.push(vec![task]);
+            }
+        }
+        groups.retain(|group| group.len() > 1);
+
+        debug!("Found {} duplicate inbox title groups", groups.len());
+        Ok(groups)
+    }
+
+    /// Get the most recently created non-trashed to-dos, newest first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_recent_tasks(&self, limit: usize) -> ThingsResult<Vec<Task>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE type = 0 AND trashed = 0 ORDER BY creationDate DESC LIMIT ?"
+        ))
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch recent tasks: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Fetched {} recent tasks", tasks.len());
+        Ok(tasks)
+    }
+
+    /// Get today's tasks (incomplete tasks due today or started today)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current date cannot be converted to a valid time with hours, minutes, and seconds
+    #[instrument(skip(self))]
+    pub async fn get_today(&self, limit: Option<usize>) -> ThingsResult<Vec<Task>> {
+        let sql = format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE {TODAY_WHERE} ORDER BY todayIndex ASC"
+        );
+
+        let rows = self
+            .with_timeout("get_today", async {
+                if let Some(limit) = limit {
+                    sqlx::query(&format!("{sql} LIMIT ?"))
+                        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+                        .fetch_all(&self.pool)
+                        .await
+                } else {
+                    sqlx::query(&sql).fetch_all(&self.pool).await
+                }
+                .map_err(|e| ThingsError::unknown(format!("Failed to fetch today's tasks: {e}")))
+            })
+            .await?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        Ok(tasks)
+    }
+
+    /// Get the Anytime list: incomplete to-dos with `start = 1`, newest first
+    ///
+    /// To-dos that are also in Today (see [`Self::get_today`]) are left out,
+    /// including ones in a project, so the two lists never overlap.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_anytime(&self, limit: Option<usize>) -> ThingsResult<Vec<Task>> {
+        self.get_start_list("get_anytime", "Anytime", ANYTIME_WHERE, limit)
+            .await
+    }
+
+    /// Get the Someday list: incomplete to-dos with `start = 2`, newest first
+    ///
+    /// Someday to-dos with a start date are left out; they appear in
+    /// [`Self::get_upcoming`] until that date arrives.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_someday(&self, limit: Option<usize>) -> ThingsResult<Vec<Task>> {
+        self.get_start_list("get_someday", "Someday", SOMEDAY_WHERE, limit)
+            .await
+    }
+
+    /// Fetch the to-dos matching `predicate`, newest first, for the `start`-based `list`
+    async fn get_start_list(
+        &self,
+        operation: &'static str,
+        list: &str,
+        predicate: &str,
+        limit: Option<usize>,
+    ) -> ThingsResult<Vec<Task>> {
+        let sql = format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE {predicate} ORDER BY creationDate DESC"
+        );
+
+        let rows = self
+            .with_timeout(operation, async {
+                if let Some(limit) = limit {
+                    sqlx::query(&format!("{sql} LIMIT ?"))
+                        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+                        .fetch_all(&self.pool)
+                        .await
+                } else {
+                    sqlx::query(&sql).fetch_all(&self.pool).await
+                }
+                .map_err(|e| ThingsError::unknown(format!("Failed to fetch {list} tasks: {e}")))
+            })
+            .await?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Fetched {} {list} tasks", tasks.len());
+        Ok(tasks)
+    }
+
+    /// Get incomplete to-dos scheduled to start after today, nearest first
+    ///
+    /// With `days_ahead`, only start dates up to that many days from today
+    /// (inclusive) are included; `None` returns everything scheduled in the
+    /// future, as does a `days_ahead` that reaches past [`NaiveDate::MAX`].
+    /// "Today" follows the database's timezone (see [`Self::with_timezone`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_upcoming(
+        &self,
+        days_ahead: Option<u32>,
+        limit: Option<usize>,
+    ) -> ThingsResult<Vec<Task>> {
+        let today = today_in(self.timezone);
+        let until =
+            days_ahead.and_then(|days| today.checked_add_signed(Duration::days(i64::from(days))));
+
+        let mut sql = format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE type = 0 AND status = 0 AND trashed = 0 AND startDate > ?"
+        );
+        if until.is_some() {
+            sql.push_str(" AND startDate <= ?");
+        }
+        sql.push_str(" ORDER BY startDate ASC, creationDate ASC");
+        if limit.is_some() {
+            sql.push_str(" LIMIT ?");
+        }
+
+        let mut query = sqlx::query(&sql).bind(naive_date_to_things_timestamp(today));
+        if let Some(until) = until {
+            query = query.bind(naive_date_to_things_timestamp(until));
+        }
+        if let Some(limit) = limit {
+            query = query.bind(i64::try_from(limit).unwrap_or(i64::MAX));
+        }
+        let rows = query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to fetch upcoming tasks: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Fetched {} upcoming tasks", tasks.len());
+        Ok(tasks)
+    }
+
+    /// Get the Today tasks filed under "This Evening", in Today order
+    ///
+    /// These are the [`Self::get_today`] tasks with [`Task::evening`] set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_evening_tasks(&self) -> ThingsResult<Vec<Task>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE {TODAY_WHERE} AND startBucket = 1 ORDER BY todayIndex ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch evening tasks: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Fetched {} evening task(s)", tasks.len());
+        Ok(tasks)
+    }
+
+    /// Get the headings and to-dos that make up a project, oldest first
+    ///
+    /// Includes non-trashed items whose `project` is the project as well as
+    /// to-dos filed under one of its headings, which Things links through
+    /// `heading` only. Completed and canceled items are included.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_project_items(&self, project_uuid: &ThingsId) -> ThingsResult<Vec<Task>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE trashed = 0 AND type IN (0, 2) AND (project = ? OR heading IN (SELECT uuid FROM TMTask WHERE project = ? AND type = 2 AND trashed = 0)) ORDER BY creationDate ASC"
+        ))
+        .bind(project_uuid.as_str())
+        .bind(project_uuid.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch project items: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!(
+            "Fetched {} item(s) for project {}",
+            tasks.len(),
+            project_uuid
+        );
+        Ok(tasks)
+    }
+
+    /// Get every heading paired with the project it belongs to
+    ///
+    /// Covers non-trashed headings in all projects, ordered by project and then
+    /// by `index` within each project. Headings without a project are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if heading data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_all_headings(&self) -> ThingsResult<Vec<(Task, ThingsId)>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE type = 2 AND trashed = 0 AND project IS NOT NULL ORDER BY project, \"index\" ASC, creationDate ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch headings: {e}")))?;
+
+        let headings = rows
+            .iter()
+            .map(|row| {
+                let project = ThingsId::from_trusted(row.get("project"));
+                Ok((self.map_task(row)?, project))
+            })
+            .collect::<ThingsResult<Vec<_>>>()?;
+
+        debug!("Fetched {} heading(s)", headings.len());
+        Ok(headings)
+    }
+
+    /// Get every to-do in an area, directly or in one of its projects, oldest first
+    ///
+    /// Includes to-dos whose `area` is the area and to-dos in one of the area's
+    /// non-trashed projects, including those filed under one of the project's
+    /// headings. Completed and canceled to-dos are included; trashed ones are not.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the area doesn't exist, if the database query fails,
+    /// or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_tasks_under_area(&self, area_uuid: &ThingsId) -> ThingsResult<Vec<Task>> {
+        validators::validate_area_exists(&self.pool, area_uuid).await?;
+
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE type = 0 AND trashed = 0 AND (
+                area = ?
+                OR COALESCE(project, (SELECT h.project FROM TMTask h WHERE h.uuid = TMTask.heading))
+                    IN (SELECT uuid FROM TMTask WHERE type = 1 AND trashed = 0 AND area = ?)
+            ) ORDER BY creationDate ASC, uuid ASC"
+        ))
+        .bind(area_uuid.as_str())
+        .bind(area_uuid.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch area tasks: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Fetched {} task(s) under area {}", tasks.len(), area_uuid);
+        Ok(tasks)
+    }
+
+    /// Get incomplete to-dos edited within `within_days` that were created before it
+    ///
+    /// These are the "in flight" tasks: older items that were recently
+    /// revisited, as opposed to ones simply created in the window. Ordered by
+    /// `userModificationDate`, most recent first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_in_progress_tasks(&self, within_days: u32) -> ThingsResult<Vec<Task>> {
+        let cutoff = Utc::now()
+            .checked_sub_signed(Duration::days(i64::from(within_days)))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC)
+            .timestamp() as f64;
+
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE type = 0 AND status = 0 AND trashed = 0 AND userModificationDate >= ? AND creationDate < ? ORDER BY userModificationDate DESC"
+        ))
+        .bind(cutoff)
+        .bind(cutoff)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch in-progress tasks: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Found {} in-progress task(s)", tasks.len());
+        Ok(tasks)
+    }
+
+    /// Get Today tasks that haven't been touched for more than `older_than_days`
+    ///
+    /// Uses the same predicate as [`Self::get_today`], narrowed to tasks whose
+    /// `userModificationDate` is older than the threshold, so these are the
+    /// items left pinned to Today without being worked on. Ordered by
+    /// `userModificationDate`, stalest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_stale_today_tasks(&self, older_than_days: u32) -> ThingsResult<Vec<Task>> {
+        let cutoff = Utc::now()
+            .checked_sub_signed(Duration::days(i64::from(older_than_days)))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC)
+            .timestamp() as f64;
+
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE {TODAY_WHERE} AND userModificationDate < ? ORDER BY userModificationDate ASC"
+        ))
+        .bind(cutoff)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch stale today tasks: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Found {} stale today task(s)", tasks.len());
+        Ok(tasks)
+    }
+
+    /// Get incomplete to-dos not modified within `older_than` of now
+    ///
+    /// Candidates for archiving: non-trashed open to-dos whose
+    /// `userModificationDate` is before now minus `older_than`. Ordered by
+    /// `userModificationDate`, stalest first. A cutoff past the range of
+    /// [`DateTime`] saturates at its minimum (or maximum, for a negative
+    /// `older_than`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_stale_tasks(&self, older_than: Duration) -> ThingsResult<Vec<Task>> {
+        let cutoff = Utc::now()
+            .checked_sub_signed(older_than)
+            .unwrap_or(if older_than < Duration::zero() {
+                DateTime::<Utc>::MAX_UTC
+            } else {
+                DateTime::<Utc>::MIN_UTC
+            })
+            .timestamp() as f64;
+
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE type = 0 AND status = 0 AND trashed = 0 AND userModificationDate < ? ORDER BY userModificationDate ASC"
+        ))
+        .bind(cutoff)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch stale tasks: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Found {} stale task(s)", tasks.len());
+        Ok(tasks)
+    }
+
+    /// Get incomplete tasks whose reminder fires within `within` from now
+    ///
+    /// Things stores a reminder as a time of day (`reminderTime`) on top of the
+    /// task's `startDate`; the fire time is that wall-clock time on the start
+    /// date in the database's configured timezone (see [`Self::with_timezone`]).
+    /// Results are ordered by fire time, soonest first. Reminders that have
+    /// already fired are excluded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_tasks_with_reminders(
+        &self,
+        within: Duration,
+    ) -> ThingsResult<Vec<(Task, DateTime<Utc>)>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS}, reminderTime FROM TMTask WHERE status = 0 AND trashed = 0 AND reminderTime IS NOT NULL AND startDate IS NOT NULL"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch task reminders: {e}")))?;
+
+        let now = Utc::now();
+        let horizon = now + within;
+        let mut reminders = Vec::new();
+        for row in &rows {
+            let Some(time) = things_time_to_naive_time(row.get("reminderTime")) else {
+                continue;
+            };
+            let task = self.map_task(row)?;
+            let Some(date) = task.start_date else {
+                continue;
+            };
+
+            let fires_at = local_datetime_in(date, time, self.timezone);
+            if fires_at >= now && fires_at <= horizon {
+                reminders.push((task, fires_at));
+            }
+        }
+        reminders.sort_by_key(|(_, fires_at)| *fires_at);
+
+        debug!("Found {} upcoming reminder(s)", reminders.len());
+        Ok(reminders)
+    }
+
+    /// Find rows whose notes are at least `min_bytes` long, largest first
+    ///
+    /// Only the UUID and the UTF-8 byte length are read, never the notes
+    /// themselves. Every `TMTask` row is checked (to-dos, projects, headings,
+    /// completed and trashed items), since all of them take up space.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_tasks_with_large_notes(
+        &self,
+        min_bytes: usize,
+    ) -> ThingsResult<Vec<(ThingsId, usize)>> {
+        let rows = sqlx::query(
+            r"
+            SELECT uuid, LENGTH(CAST(notes AS BLOB)) AS notes_bytes
+            FROM TMTask
+            WHERE notes IS NOT NULL AND LENGTH(CAST(notes AS BLOB)) >= ?
+            ORDER BY notes_bytes DESC, uuid
+            ",
+        )
+        .bind(i64::try_from(min_bytes).unwrap_or(i64::MAX))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch note sizes: {e}")))?;
+
+        let sizes: Vec<(ThingsId, usize)> = rows
+            .iter()
+            .map(|row| {
+                let bytes: i64 = row.get("notes_bytes");
+                (
+                    ThingsId::from_trusted(row.get("uuid")),
+                    bytes.try_into().unwrap_or(0),
+                )
+            })
+            .collect();
+
+        debug!(
+            "Found {} tasks with notes >= {} bytes",
+            sizes.len(),
+            min_bytes
+        );
+        Ok(sizes)
+    }
+
+    /// Get non-trashed to-dos whose notes contain `http(s)://` links, with the links
+    ///
+    /// URLs are returned in the order they appear in the notes; tasks without
+    /// any are omitted. Tasks are ordered newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_tasks_with_urls(&self) -> ThingsResult<Vec<(Task, Vec<String>)>> {
+        // LIKE is ASCII case-insensitive, so this cheaply narrows to candidate notes
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE type = 0 AND trashed = 0 AND notes LIKE '%http%://%' ORDER BY creationDate DESC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch tasks with notes: {e}")))?;
+
+        let mut tasks = Vec::new();
+        for row in &rows {
+            let task = self.map_task(row)?;
+            let urls = task.notes.as_deref().map(extract_urls).unwrap_or_default();
+            if !urls.is_empty() {
+                tasks.push((task, urls));
+            }
+        }
+
+        debug!("Found {} task(s) with URLs in notes", tasks.len());
+        Ok(tasks)
+    }
+
+    /// Get non-trashed tasks carrying the tag with the given UUID
+    ///
+    /// Matches on tag UUID through the `TMTaskTag` join table, which Things 3
+    /// keeps authoritative, rather than the `cachedTags` blob. Renaming a tag
+    /// therefore never changes the result. Tasks are ordered newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_tasks_with_tag_uuid(&self, tag_uuid: &ThingsId) -> ThingsResult<Vec<Task>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE trashed = 0 AND uuid IN (SELECT tasks FROM TMTaskTag WHERE tags = ?) ORDER BY creationDate DESC"
+        ))
+        .bind(tag_uuid.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch tasks with tag: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Found {} task(s) with tag {}", tasks.len(), tag_uuid);
+        Ok(tasks)
+    }
+
+    /// Get non-trashed to-dos delegated to a contact, newest first
+    ///
+    /// `assignee` is a `TMContact` UUID, as reported in [`Task::assignee`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or if task data is invalid
+    #[instrument(skip(self))]
+    pub async fn get_tasks_by_assignee(&self, assignee: &str) -> ThingsResult<Vec<Task>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE type = 0 AND trashed = 0 AND contact = ? ORDER BY creationDate DESC"
+        ))
+        .bind(assignee)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch tasks by assignee: {e}")))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Found {} task(s) assigned to {}", tasks.len(), assignee);
+        Ok(tasks)
+    }
+
+    /// Read a single `TMTask` column the [`Task`] model doesn't cover
+    ///
+    /// Escape hatch for installation-specific columns such as `reminderTime`
+    /// or the repeat rules. `column` must be an
+    /// existing `TMTask` column per `PRAGMA table_info`, so arbitrary SQL can't
+    /// be injected. Non-text values are returned as their SQLite text form.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `column` is not a `TMTask` column, if the task does
+    /// not exist, or if the database query fails
+    #[instrument(skip(self))]
+    pub async fn raw_column(&self, uuid: &ThingsId, column: &str) -> ThingsResult<Option<String>> {
+        let columns: Vec<String> =
+            sqlx::query_scalar("SELECT name FROM pragma_table_info('TMTask')")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| ThingsError::unknown(format!("Failed to read TMTask columns: {e}")))?;
+        let Some(column) = columns.iter().find(|c| c.eq_ignore_ascii_case(column)) else {
+            return Err(ThingsError::validation(format!(
+                "Unknown TMTask column: {column}"
+            )));
+        };
+
+        // Safe to interpolate: the name came from table_info, not the caller
+        let row = sqlx::query(&format!(
+            "SELECT CAST(\"{column}\" AS TEXT) AS value FROM TMTask WHERE uuid = ?"
+        ))
+        .bind(uuid.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to read column {column}: {e}")))?
+        .ok_or_else(|| ThingsError::TaskNotFound {
+            uuid: uuid.to_string(),
+        })?;
+
+        Ok(row.get("value"))
+    }
+
+    /// Get a task by its UUID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task does not exist or if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_task_by_uuid(&self, id: &ThingsId) -> ThingsResult<Option<Task>> {
+        let row = sqlx::query(&format!(
+            "SELECT {TASK_COLUMNS}, trashed FROM TMTask WHERE uuid = ?"
+        ))
+        .bind(id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch task: {e}")))?;
+
+        if let Some(row) = row {
+            // Check if trashed
+            let trashed: i64 = row.get("trashed");
+            if trashed == 1 {
+                return Ok(None); // Return None for trashed tasks
+            }
+
+            // Use the centralized mapper
+            let task = self.map_task(&row)?;
+            Ok(Some(task))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get a task together with the titles of its project, area and heading
+    ///
+    /// A to-do under a heading takes its project from the heading, and a to-do
+    /// in a project takes its area from the project when it has none itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThingsError::TaskNotFound`] if the task doesn't exist or is trashed,
+    /// or an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_task_detail(&self, id: &ThingsId) -> ThingsResult<TaskDetail> {
+        let task = self
+            .get_task_by_uuid(id)
+            .await?
+            .ok_or_else(|| ThingsError::TaskNotFound {
+                uuid: id.to_string(),
+            })?;
+
+        let row = sqlx::query(
+            r"
+            SELECT
+                h.title AS heading_title,
+                p.title AS project_title,
+                a.title AS area_title
+            FROM TMTask t
+            LEFT JOIN TMTask h ON h.uuid = t.heading
+            LEFT JOIN TMTask p ON p.uuid = COALESCE(t.project, h.project)
+            LEFT JOIN TMArea a ON a.uuid = COALESCE(t.area, p.area)
+            WHERE t.uuid = ?
+            ",
+        )
+        .bind(id.as_str())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch task parents: {e}")))?;
+
+        debug!("Fetched detail for task {id}");
+        Ok(TaskDetail {
+            task,
+            project_title: row.get("project_title"),
+            area_title: row.get("area_title"),
+            heading_title: row.get("heading_title"),
+        })
+    }
+
+    /// Get the area a task belongs to, directly or through its project
+    ///
+    /// Returns the task's own area, or when it has none, the area of its
+    /// project (taken from its heading for to-dos under a heading).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThingsError::TaskNotFound`] if the task doesn't exist or is trashed,
+    /// or an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_effective_area(&self, id: &ThingsId) -> ThingsResult<Option<ThingsId>> {
+        let row = sqlx::query(
+            r"
+            SELECT COALESCE(t.area, p.area) AS area
+            FROM TMTask t
+            LEFT JOIN TMTask h ON h.uuid = t.heading
+            LEFT JOIN TMTask p ON p.uuid = COALESCE(t.project, h.project)
+            WHERE t.uuid = ? AND t.trashed = 0
+            ",
+        )
+        .bind(id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch effective area: {e}")))?
+        .ok_or_else(|| ThingsError::TaskNotFound {
+            uuid: id.to_string(),
+        })?;
+
+        Ok(row
+            .get::<Option<String>, _>("area")
+            .map(ThingsId::from_trusted))
+    }
+
+    /// Get the to-dos sharing a task's parent, including the task itself
+    ///
+    /// The parent is the task's heading, else its project, else its area;
+    /// a task with none of these is grouped with the other loose to-dos.
+    /// Only the task's own level counts: a project's siblings exclude to-dos
+    /// under its headings. Incomplete to-dos are listed along with the task
+    /// itself whatever its status, ordered by `index` for previous/next navigation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThingsError::TaskNotFound`] if the task doesn't exist or is trashed,
+    /// or an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_task_siblings(&self, id: &ThingsId) -> ThingsResult<Vec<Task>> {
+        let task = self
+            .get_task_by_uuid(id)
+            .await?
+            .ok_or_else(|| ThingsError::TaskNotFound {
+                uuid: id.to_string(),
+            })?;
+
+        let (parent_where, parent) = if let Some(heading) = &task.parent_uuid {
+            ("heading = ?", Some(heading))
+        } else if let Some(project) = &task.project_uuid {
+            ("project = ? AND heading IS NULL", Some(project))
+        } else if let Some(area) = &task.area_uuid {
+            (
+                "area = ? AND project IS NULL AND heading IS NULL",
+                Some(area),
+            )
+        } else {
+            ("area IS NULL AND project IS NULL AND heading IS NULL", None)
+        };
+
+        let sql = format!(
+            "SELECT {TASK_COLUMNS} FROM TMTask WHERE type = 0 AND trashed = 0 AND (status = 0 OR uuid = ?) AND {parent_where} ORDER BY \"index\" ASC, creationDate ASC"
+        );
+        let mut query = sqlx::query(&sql).bind(id.as_str());
+        if let Some(parent) = parent {
+            query = query.bind(parent.as_str());
+        }
+        let rows = query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| ThingsError::unknown(format!("Failed to fetch task siblings: {e}")))?;
+
+        let siblings = rows
+            .iter()
+            .map(|row| self.map_task(row))
+            .collect::<ThingsResult<Vec<Task>>>()?;
+
+        debug!("Fetched {} sibling(s) for task {id}", siblings.len());
+        Ok(siblings)
+    }
+
+    /// Get tombstones for sync: trashed to-dos, projects and headings changed after `since`
+    ///
+    /// Each entry pairs the item's UUID with its type, oldest change first.
+    /// Trashing an item updates its `userModificationDate`, so this reports
+    /// everything moved to the Trash since the last sync. Items already purged
+    /// from the Trash are gone from the database and can't be reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails
+    #[instrument(skip(self))]
+    pub async fn get_deleted_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> ThingsResult<Vec<(ThingsId, TaskType)>> {
+        let rows = sqlx::query(
+            "SELECT uuid, type FROM TMTask WHERE trashed = 1 AND userModificationDate > ? ORDER BY userModificationDate ASC",
+        )
+        .bind(since.timestamp() as f64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ThingsError::unknown(format!("Failed to fetch deleted items: {e}")))?;
+
+        let deleted: Vec<_> = rows
+            .iter()
+            .map(|row| {
+                let task_type = match row.get::<i64, _>("type") {
+                    1 => TaskType::Project,
+                    2 => TaskType::Heading,
+                    _ => TaskType::Todo,
+                };
+                (ThingsId::from_trusted(row.get("uuid")), task_type)
+            })
+            .collect();
+
+        debug!("Found {} deleted items since {since}", deleted.len());
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_urls_boundaries() {
+        // Stops at whitespace, angle brackets, and double quotes
+        assert_eq!(
+            extract_urls("a https://x.y/p b <http://x.y> \"https://q.r\""),
+            ["https://x.y/p", "http://x.y", "https://q.r"]
+        );
+        // The scheme is case-insensitive but must start a word
+        assert_eq!(
+            extract_urls("HTTPS://x.y xhttps://no.match 1http://no.match"),
+            ["HTTPS://x.y"]
+        );
+        // Sentence punctuation is trimmed; inner punctuation is kept
+        assert_eq!(
+            extract_urls("See https://x.y/a?b=1&c=2. Or https://x.y/it's!?"),
+            ["https://x.y/a?b=1&c=2", "https://x.y/it's"]
+        );
+        // Unmatched closing brackets are trimmed, balanced ones kept
+        assert_eq!(
+            extract_urls("[a](https://x.y/a_(b)) {https://x.y/c}."),
+            ["https://x.y/a_(b)", "https://x.y/c"]
+        );
+        // A bare scheme is not a URL
+        assert!(extract_urls("https:// http://. httpx://a.b").is_empty());
     }
 }
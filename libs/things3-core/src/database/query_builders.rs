@@ -34,15 +34,15 @@ impl TaskUpdateBuilder {
             builder = builder.add_field("title");
         }
 
-        if request.notes.is_some() {
+        if !request.notes.is_unchanged() {
             builder = builder.add_field("notes");
         }
 
-        if request.start_date.is_some() {
+        if !request.start_date.is_unchanged() {
             builder = builder.add_field("startDate");
         }
 
-        if request.deadline.is_some() {
+        if !request.deadline.is_unchanged() {
             builder = builder.add_field("deadline");
         }
 
@@ -50,11 +50,11 @@ impl TaskUpdateBuilder {
             builder = builder.add_field("status");
         }
 
-        if request.project_uuid.is_some() {
+        if !request.project_uuid.is_unchanged() {
             builder = builder.add_field("project");
         }
 
-        if request.area_uuid.is_some() {
+        if !request.area_uuid.is_unchanged() {
             builder = builder.add_field("area");
         }
 
@@ -117,6 +117,7 @@ impl Default for TaskUpdateBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::FieldUpdate;
     use crate::models::TaskStatus;
     use crate::models::ThingsId;
     use chrono::NaiveDate;
@@ -159,12 +160,12 @@ mod tests {
         let request = UpdateTaskRequest {
             uuid: ThingsId::new_v4(),
             title: Some("Updated Title".to_string()),
-            notes: Some("Updated Notes".to_string()),
-            start_date: Some(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap()),
-            deadline: Some(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()),
+            notes: FieldUpdate::Set("Updated Notes".to_string()),
+            start_date: FieldUpdate::Set(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap()),
+            deadline: FieldUpdate::Set(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()),
             status: Some(TaskStatus::Incomplete),
-            project_uuid: Some(ThingsId::new_v4()),
-            area_uuid: Some(ThingsId::new_v4()),
+            project_uuid: FieldUpdate::Set(ThingsId::new_v4()),
+            area_uuid: FieldUpdate::Set(ThingsId::new_v4()),
             tags: Some(vec!["tag1".to_string(), "tag2".to_string()]),
         };
 
@@ -187,12 +188,12 @@ mod tests {
         let request = UpdateTaskRequest {
             uuid: ThingsId::new_v4(),
             title: Some("Updated Title".to_string()),
-            notes: None,
-            start_date: None,
-            deadline: None,
+            notes: FieldUpdate::Unchanged,
+            start_date: FieldUpdate::Unchanged,
+            deadline: FieldUpdate::Unchanged,
             status: None,
-            project_uuid: None,
-            area_uuid: None,
+            project_uuid: FieldUpdate::Unchanged,
+            area_uuid: FieldUpdate::Unchanged,
             tags: None,
         };
 
@@ -17,6 +17,16 @@ impl DatabaseStats {
     }
 }
 
+/// Age of incomplete inbox items, measured from `creationDate`
+///
+/// All fields are zero for an empty inbox.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct InboxAgeStats {
+    pub count: u64,
+    pub avg_age_days: f64,
+    pub max_age_days: f64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,6 +5,72 @@
 
 use strsim::normalized_levenshtein;
 
+/// Similarity at or above which an existing tag counts as a near-duplicate
+///
+/// Used by `create_tag_smart`, `add_tag_to_task` and `set_task_tags`; their
+/// `_with_threshold` variants take a different value per call.
+pub const DEFAULT_TAG_SIMILARITY: f32 = 0.8;
+
+/// Rules for normalizing tag titles before they are compared
+///
+/// Used by smart tag creation, tag assignment and duplicate detection; set it
+/// with [`crate::ThingsDatabase::with_tag_normalization`]. The default trims,
+/// lowercases and collapses whitespace, and keeps every leading symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagNormalizationConfig {
+    /// Characters removed from the start of a title, such as `"@#"` to treat
+    /// `@context` and `context` as the same tag
+    pub strip_leading: String,
+    /// Collapse runs of whitespace into a single space
+    pub collapse_whitespace: bool,
+    /// Compare titles case-insensitively
+    pub case_fold: bool,
+}
+
+impl Default for TagNormalizationConfig {
+    fn default() -> Self {
+        Self {
+            strip_leading: String::new(),
+            collapse_whitespace: true,
+            case_fold: true,
+        }
+    }
+}
+
+impl TagNormalizationConfig {
+    /// Normalize `title` according to these rules
+    ///
+    /// Surrounding whitespace is always trimmed, including any left behind
+    /// after stripping leading symbols.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use things3_core::database::tag_utils::TagNormalizationConfig;
+    /// let config = TagNormalizationConfig {
+    ///     strip_leading: "@#".to_string(),
+    ///     ..TagNormalizationConfig::default()
+    /// };
+    /// assert_eq!(config.normalize(" @Home "), "home");
+    /// assert_eq!(config.normalize("#Project  X"), "project x");
+    /// ```
+    #[must_use]
+    pub fn normalize(&self, title: &str) -> String {
+        let mut normalized = title
+            .trim()
+            .trim_start_matches(|c| self.strip_leading.contains(c))
+            .trim_start()
+            .to_string();
+        if self.case_fold {
+            normalized = normalized.to_lowercase();
+        }
+        if self.collapse_whitespace {
+            normalized = normalized.split_whitespace().collect::<Vec<_>>().join(" ");
+        }
+        normalized
+    }
+}
+
 /// Normalize a tag title for comparison
 ///
 /// Normalization steps:
@@ -13,6 +79,8 @@ use strsim::normalized_levenshtein;
 /// - Collapse multiple spaces into single spaces
 /// - Handle consistent character encoding
 ///
+/// Equivalent to [`TagNormalizationConfig::default`]'s [`normalize`](TagNormalizationConfig::normalize).
+///
 /// # Examples
 ///
 /// ```
@@ -22,12 +90,7 @@ use strsim::normalized_levenshtein;
 /// assert_eq!(normalize_tag_title("URGENT"), "urgent");
 /// ```
 pub fn normalize_tag_title(title: &str) -> String {
-    title
-        .trim()
-        .to_lowercase()
-        .split_whitespace()
-        .collect::<Vec<_>>()
-        .join(" ")
+    TagNormalizationConfig::default().normalize(title)
 }
 
 /// Calculate similarity between two tag titles using normalized Levenshtein distance
@@ -53,9 +116,18 @@ pub fn normalize_tag_title(title: &str) -> String {
 /// assert!(score < 0.5);
 /// ```
 pub fn calculate_similarity(title1: &str, title2: &str) -> f32 {
+    calculate_similarity_with(title1, title2, &TagNormalizationConfig::default())
+}
+
+/// [`calculate_similarity`] with custom normalization rules
+pub fn calculate_similarity_with(
+    title1: &str,
+    title2: &str,
+    config: &TagNormalizationConfig,
+) -> f32 {
     // Normalize both titles first
-    let norm1 = normalize_tag_title(title1);
-    let norm2 = normalize_tag_title(title2);
+    let norm1 = config.normalize(title1);
+    let norm2 = config.normalize(title2);
 
     // Use normalized Levenshtein distance from strsim crate
     // Returns a value between 0.0 and 1.0
@@ -79,8 +151,17 @@ pub fn calculate_similarity(title1: &str, title2: &str) -> f32 {
 /// assert!(!is_partial_match("work", "vacation"));
 /// ```
 pub fn is_partial_match(search: &str, candidate: &str) -> bool {
-    let search_norm = normalize_tag_title(search);
-    let candidate_norm = normalize_tag_title(candidate);
+    is_partial_match_with(search, candidate, &TagNormalizationConfig::default())
+}
+
+/// [`is_partial_match`] with custom normalization rules
+pub fn is_partial_match_with(
+    search: &str,
+    candidate: &str,
+    config: &TagNormalizationConfig,
+) -> bool {
+    let search_norm = config.normalize(search);
+    let candidate_norm = config.normalize(candidate);
 
     candidate_norm.contains(&search_norm) || search_norm.contains(&candidate_norm)
 }
@@ -105,11 +186,26 @@ pub fn get_match_type(
     title1: &str,
     title2: &str,
     similarity_threshold: f32,
+) -> crate::models::TagMatchType {
+    get_match_type_with(
+        title1,
+        title2,
+        similarity_threshold,
+        &TagNormalizationConfig::default(),
+    )
+}
+
+/// [`get_match_type`] with custom normalization rules
+pub fn get_match_type_with(
+    title1: &str,
+    title2: &str,
+    similarity_threshold: f32,
+    config: &TagNormalizationConfig,
 ) -> crate::models::TagMatchType {
     use crate::models::TagMatchType;
 
-    let norm1 = normalize_tag_title(title1);
-    let norm2 = normalize_tag_title(title2);
+    let norm1 = config.normalize(title1);
+    let norm2 = config.normalize(title2);
 
     // Check for exact match (normalized)
     if norm1 == norm2 {
@@ -121,7 +217,7 @@ pub fn get_match_type(
     }
 
     // Calculate similarity
-    let similarity = calculate_similarity(title1, title2);
+    let similarity = calculate_similarity_with(title1, title2, config);
 
     // Check if similarity meets threshold
     if similarity >= similarity_threshold {
@@ -129,7 +225,7 @@ pub fn get_match_type(
     }
 
     // Check for partial match
-    if is_partial_match(title1, title2) {
+    if is_partial_match_with(title1, title2, config) {
         return TagMatchType::PartialMatch;
     }
 
@@ -297,4 +393,32 @@ mod tests {
         let score = calculate_similarity("work from home", "  Work   From   Home  ");
         assert_eq!(score, 1.0);
     }
+
+    #[test]
+    fn test_normalization_config_rules() {
+        let strip = TagNormalizationConfig {
+            strip_leading: "@#".to_string(),
+            ..TagNormalizationConfig::default()
+        };
+        assert_eq!(strip.normalize("@Work"), "work");
+        assert_eq!(strip.normalize("# Errands"), "errands");
+        assert_eq!(strip.normalize("a@b"), "a@b");
+        assert_eq!(calculate_similarity_with("@home", "#Home", &strip), 1.0);
+
+        let exact = TagNormalizationConfig {
+            collapse_whitespace: false,
+            case_fold: false,
+            ..TagNormalizationConfig::default()
+        };
+        assert_eq!(exact.normalize("  High  Priority "), "High  Priority");
+        assert_eq!(
+            get_match_type_with("Work", "work", 0.8, &exact),
+            TagMatchType::Similar
+        );
+
+        assert_eq!(
+            TagNormalizationConfig::default().normalize("  @High   Priority "),
+            normalize_tag_title("  @High   Priority ")
+        );
+    }
 }
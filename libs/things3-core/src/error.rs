@@ -1,5 +1,6 @@
 //! Error types for the Things Core library
 
+use std::time::Duration;
 use thiserror::Error;
 
 /// Result type alias for Things operations
@@ -53,6 +54,12 @@ pub enum ThingsError {
     #[error("AppleScript automation failed: {message}")]
     AppleScript { message: String },
 
+    #[error("{operation} timed out after {elapsed:?}")]
+    Timeout {
+        operation: &'static str,
+        elapsed: Duration,
+    },
+
     #[error("Unknown error: {message}")]
     Unknown { message: String },
 }
@@ -120,6 +127,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_timeout_error_names_operation() {
+        let error = ThingsError::Timeout {
+            operation: "search_tasks",
+            elapsed: Duration::from_millis(1500),
+        };
+
+        assert_eq!(error.to_string(), "search_tasks timed out after 1.5s");
+    }
+
     #[test]
     fn test_database_not_found_error() {
         let error = ThingsError::DatabaseNotFound {
@@ -1,8 +1,6 @@
 //! Data export functionality for Things 3 data
 
-#[cfg(any(feature = "export-csv", feature = "export-taskpaper"))]
-use crate::models::TaskType;
-use crate::models::{Area, Project, Task, TaskStatus};
+use crate::models::{Area, ChecklistItem, Project, Tag, Task, TaskStatus, TaskType, ThingsId};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -43,10 +41,53 @@ pub struct ExportData {
     pub tasks: Vec<Task>,
     pub projects: Vec<Project>,
     pub areas: Vec<Area>,
+    /// Tag definitions; only [`DataExporter::export_full_state`] fills these
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<Tag>,
+    /// Database fields the models don't carry; only [`DataExporter::export_full_state`] fills these
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full_state: Option<FullStateRows>,
     pub exported_at: DateTime<Utc>,
     pub total_items: usize,
 }
 
+/// Raw row data that [`DataExporter::import_full_state`] needs to recreate list placement
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FullStateRows {
+    /// `TMTaskTag` links from exported projects and to-dos to exported tags
+    pub tag_links: Vec<TagLink>,
+    /// List placement of every exported project, heading, and to-do
+    pub tasks: Vec<TaskRowState>,
+    /// Sidebar visibility of every exported area
+    pub areas: Vec<AreaRowState>,
+}
+
+/// A `TMTaskTag` row: `owner` is a project or to-do UUID
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagLink {
+    pub owner: ThingsId,
+    pub tag: ThingsId,
+}
+
+/// `TMTask` columns that decide which list an item appears in and where
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskRowState {
+    pub uuid: ThingsId,
+    /// `start`: 0 = Inbox, 1 = Anytime, 2 = Someday
+    pub start: Option<i64>,
+    /// `todayIndex`: position in Today
+    pub today_index: Option<i64>,
+    /// `index`: position within the project, heading, or area
+    pub index: Option<i64>,
+}
+
+/// `TMArea` columns the [`Area`] model doesn't carry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AreaRowState {
+    pub uuid: ThingsId,
+    pub visible: bool,
+}
+
 impl ExportData {
     #[must_use]
     pub fn new(tasks: Vec<Task>, projects: Vec<Project>, areas: Vec<Area>) -> Self {
@@ -55,12 +96,54 @@ impl ExportData {
             tasks,
             projects,
             areas,
+            tags: Vec::new(),
+            full_state: None,
             exported_at: Utc::now(),
             total_items,
         }
     }
 }
 
+/// A project and everything filed under it, built by [`DataExporter::export_project`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectExport {
+    pub project: Project,
+    /// To-dos not filed under a heading
+    pub tasks: Vec<ProjectExportTask>,
+    pub headings: Vec<ProjectExportHeading>,
+    pub exported_at: DateTime<Utc>,
+}
+
+/// A heading within a [`ProjectExport`] and the to-dos filed under it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectExportHeading {
+    pub uuid: ThingsId,
+    pub title: String,
+    pub tasks: Vec<ProjectExportTask>,
+}
+
+/// A to-do within a [`ProjectExport`] together with its checklist
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectExportTask {
+    #[serde(flatten)]
+    pub task: Task,
+    pub checklist: Vec<ChecklistItem>,
+}
+
+/// Every tag with its shortcut, parent, and usage count, built by
+/// [`DataExporter::export_tags`]
+///
+/// Tags are ordered by title; the hierarchy is carried by each tag's `parent_uuid`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagExport {
+    pub tags: Vec<Tag>,
+    pub exported_at: DateTime<Utc>,
+}
+
+/// Lines written by [`DataExporter::stream_tasks_ndjson`] between flushes
+#[cfg(all(feature = "advanced-queries", feature = "batch-operations"))]
+pub const NDJSON_FLUSH_INTERVAL: u64 = 256;
+
 /// Export configuration
 #[derive(Debug, Clone)]
 pub struct ExportConfig {
@@ -69,6 +152,10 @@ pub struct ExportConfig {
     pub include_tags: bool,
     pub date_format: String,
     pub timezone: String,
+    /// Indent JSON output for reading; `false` writes it on one line
+    ///
+    /// Only affects [`DataExporter::export`]. NDJSON streaming is always compact.
+    pub pretty: bool,
 }
 
 impl Default for ExportConfig {
@@ -79,13 +166,13 @@ impl Default for ExportConfig {
             include_tags: true,
             date_format: "%Y-%m-%d %H:%M:%S".to_string(),
             timezone: "UTC".to_string(),
+            pretty: true,
         }
     }
 }
 
 /// Data exporter for Things 3 data
 pub struct DataExporter {
-    #[allow(dead_code)]
     config: ExportConfig,
 }
 
@@ -107,7 +194,7 @@ impl DataExporter {
     /// Returns an error if the export format is not supported or if serialization fails.
     pub fn export(&self, data: &ExportData, format: ExportFormat) -> Result<String> {
         match format {
-            ExportFormat::Json => Self::export_json(data),
+            ExportFormat::Json => Self::export_json(data, self.config.pretty),
             #[cfg(feature = "export-csv")]
             ExportFormat::Csv => Ok(Self::export_csv(data)),
             #[cfg(not(feature = "export-csv"))]
@@ -136,9 +223,781 @@ impl DataExporter {
         }
     }
 
-    /// Export as JSON
-    fn export_json(data: &ExportData) -> Result<String> {
-        Ok(serde_json::to_string_pretty(data)?)
+    /// Stream every non-trashed to-do to `writer` as newline-delimited JSON
+    ///
+    /// Tasks are pulled through [`crate::query::TaskQueryBuilder::execute_stream`] and
+    /// written one JSON object per line, so memory use stays constant no matter
+    /// how large the database is. The writer is flushed every
+    /// [`NDJSON_FLUSH_INTERVAL`] lines and once at the end. Returns the number
+    /// of tasks written.
+    ///
+    /// Requires both the `advanced-queries` and `batch-operations` feature flags.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails, if a task cannot be
+    /// serialized, or if writing to `writer` fails. Lines already written stay
+    /// in the output.
+    #[cfg(all(feature = "advanced-queries", feature = "batch-operations"))]
+    pub async fn stream_tasks_ndjson<W>(
+        db: &crate::database::ThingsDatabase,
+        mut writer: W,
+    ) -> Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        let mut stream = crate::query::TaskQueryBuilder::new()
+            .task_type(TaskType::Todo)
+            .execute_stream(db);
+
+        let mut written = 0u64;
+        let mut line = Vec::new();
+        while let Some(task) = std::future::poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+            line.clear();
+            serde_json::to_writer(&mut line, &task?)?;
+            line.push(b'\n');
+            writer.write_all(&line).await?;
+
+            written += 1;
+            if written.is_multiple_of(NDJSON_FLUSH_INTERVAL) {
+                writer.flush().await?;
+            }
+        }
+        writer.flush().await?;
+
+        Ok(written)
+    }
+
+    /// Write to-dos completed between `from` and `to` (inclusive) as CSV
+    ///
+    /// Pages through [`ThingsDatabase::search_logbook`] and writes each page as
+    /// it arrives, newest first. `completed_date` is the `stopDate` as a calendar
+    /// date in the database's timezone. Returns the number of rows written,
+    /// excluding the header.
+    ///
+    /// [`ThingsDatabase::search_logbook`]: crate::database::ThingsDatabase::search_logbook
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `from` is after `to`, if the database query fails,
+    /// or if writing to `writer` fails. Rows already written stay in the output.
+    #[cfg(feature = "export-csv")]
+    pub async fn export_logbook_csv<W>(
+        db: &crate::database::ThingsDatabase,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        mut writer: W,
+    ) -> Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use crate::database::{date_utils::date_in, ThingsDatabase};
+        use tokio::io::AsyncWriteExt;
+
+        if from > to {
+            return Err(anyhow::anyhow!(
+                "Logbook range starts after it ends: {from} > {to}"
+            ));
+        }
+
+        writer
+            .write_all(b"completed_date,title,notes,tags,project,area\n")
+            .await?;
+
+        let mut written = 0u64;
+        let mut offset = 0u32;
+        loop {
+            let page = db
+                .search_logbook(
+                    None,
+                    Some(from),
+                    Some(to),
+                    None,
+                    None,
+                    None,
+                    Some(ThingsDatabase::MAX_QUERY_LIMIT),
+                    Some(offset),
+                )
+                .await?;
+
+            let mut chunk = String::new();
+            for task in &page {
+                writeln!(
+                    chunk,
+                    "{},{},{},{},{},{}",
+                    format_date_csv(task.stop_date.map(|dt| date_in(dt, db.timezone()))),
+                    escape_csv(&task.title),
+                    escape_csv(task.notes.as_deref().unwrap_or("")),
+                    escape_csv(&task.tags.join(", ")),
+                    task.project_uuid
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_default(),
+                    task.area_uuid
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_default(),
+                )
+                .unwrap();
+            }
+            writer.write_all(chunk.as_bytes()).await?;
+            written += page.len() as u64;
+
+            if page.len() < ThingsDatabase::MAX_QUERY_LIMIT as usize {
+                break;
+            }
+            offset += ThingsDatabase::MAX_QUERY_LIMIT;
+        }
+        writer.flush().await?;
+
+        Ok(written)
+    }
+
+    /// Export one project with its headings, to-dos, checklists, and notes
+    ///
+    /// Builds a [`ProjectExport`] tree and renders it as a self-contained
+    /// document. Tags appear by title. Only [`ExportFormat::Json`] and
+    /// [`ExportFormat::Markdown`] are supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `format` is not JSON or Markdown, if the project
+    /// does not exist, or if a database query fails.
+    pub async fn export_project(
+        db: &crate::database::ThingsDatabase,
+        project_uuid: &ThingsId,
+        format: ExportFormat,
+    ) -> Result<String> {
+        if !matches!(format, ExportFormat::Json | ExportFormat::Markdown) {
+            return Err(anyhow::anyhow!(
+                "Project export supports JSON and Markdown, not {format:?}"
+            ));
+        }
+
+        let mut project = db
+            .get_project_by_uuid(project_uuid)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Project not found: {project_uuid}"))?;
+        project.tags = db.get_project_tags(project_uuid).await?;
+
+        let (headings, todos): (Vec<Task>, Vec<Task>) = db
+            .get_project_items(project_uuid)
+            .await?
+            .into_iter()
+            .partition(|item| item.task_type == TaskType::Heading);
+
+        let mut doc = ProjectExport {
+            project,
+            tasks: Vec::new(),
+            headings: headings
+                .into_iter()
+                .map(|heading| ProjectExportHeading {
+                    uuid: heading.uuid,
+                    title: heading.title,
+                    tasks: Vec::new(),
+                })
+                .collect(),
+            exported_at: Utc::now(),
+        };
+
+        for task in todos {
+            let checklist = db.get_checklist_items(&task.uuid).await?;
+            let heading = task
+                .parent_uuid
+                .as_ref()
+                .and_then(|parent| doc.headings.iter().position(|h| &h.uuid == parent));
+            let entry = ProjectExportTask { task, checklist };
+            match heading {
+                Some(index) => doc.headings[index].tasks.push(entry),
+                None => doc.tasks.push(entry),
+            }
+        }
+
+        match format {
+            ExportFormat::Json => Ok(serde_json::to_string_pretty(&doc)?),
+            _ => Ok(Self::export_project_markdown(&doc)),
+        }
+    }
+
+    /// Render a [`ProjectExport`] as Markdown
+    fn export_project_markdown(doc: &ProjectExport) -> String {
+        fn write_task(md: &mut String, entry: &ProjectExportTask) {
+            let task = &entry.task;
+            let mark = if task.status == TaskStatus::Completed {
+                "x"
+            } else {
+                " "
+            };
+            writeln!(md, "- [{mark}] {}", task.title).unwrap();
+            if !task.tags.is_empty() {
+                writeln!(md, "  - **Tags:** {}", task.tags.join(", ")).unwrap();
+            }
+            if let Some(deadline) = &task.deadline {
+                writeln!(md, "  - **Deadline:** {deadline}").unwrap();
+            }
+            if let Some(notes) = task.notes.as_deref().filter(|n| !n.is_empty()) {
+                for line in notes.lines() {
+                    writeln!(md, "  > {line}").unwrap();
+                }
+            }
+            for item in &entry.checklist {
+                let mark = if item.completed { "x" } else { " " };
+                writeln!(md, "  - [{mark}] {}", item.title).unwrap();
+            }
+        }
+
+        let project = &doc.project;
+        let mut md = String::new();
+        writeln!(md, "# {}\n", project.title).unwrap();
+        writeln!(md, "**Status:** {:?}  ", project.status).unwrap();
+        if let Some(deadline) = &project.deadline {
+            writeln!(md, "**Deadline:** {deadline}  ").unwrap();
+        }
+        if !project.tags.is_empty() {
+            writeln!(md, "**Tags:** {}  ", project.tags.join(", ")).unwrap();
+        }
+        writeln!(
+            md,
+            "**Exported:** {}\n",
+            doc.exported_at.format("%Y-%m-%d %H:%M:%S UTC")
+        )
+        .unwrap();
+        if let Some(notes) = project.notes.as_deref().filter(|n| !n.is_empty()) {
+            writeln!(md, "{notes}\n").unwrap();
+        }
+
+        for entry in &doc.tasks {
+            write_task(&mut md, entry);
+        }
+        for heading in &doc.headings {
+            writeln!(md, "\n## {}\n", heading.title).unwrap();
+            for entry in &heading.tasks {
+                write_task(&mut md, entry);
+            }
+        }
+
+        md
+    }
+
+    /// Export every tag with its shortcut, parent, and usage count
+    ///
+    /// JSON is a [`TagExport`] with a flat, title-ordered tag list. Markdown
+    /// nests each tag under its parent as an indented list; tags whose parent
+    /// is missing are listed at the top level. Only [`ExportFormat::Json`] and
+    /// [`ExportFormat::Markdown`] are supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `format` is not JSON or Markdown or if the database
+    /// query fails.
+    pub async fn export_tags(
+        db: &crate::database::ThingsDatabase,
+        format: ExportFormat,
+    ) -> Result<String> {
+        if !matches!(format, ExportFormat::Json | ExportFormat::Markdown) {
+            return Err(anyhow::anyhow!(
+                "Tag export supports JSON and Markdown, not {format:?}"
+            ));
+        }
+
+        let doc = TagExport {
+            tags: db.get_all_tags().await?,
+            exported_at: Utc::now(),
+        };
+
+        match format {
+            ExportFormat::Json => Ok(serde_json::to_string_pretty(&doc)?),
+            _ => Ok(Self::export_tags_markdown(&doc)),
+        }
+    }
+
+    /// Render a [`TagExport`] as Markdown, one indented list level per tag depth
+    fn export_tags_markdown(doc: &TagExport) -> String {
+        fn write_tag(
+            md: &mut String,
+            doc: &TagExport,
+            tag: &Tag,
+            depth: usize,
+            seen: &mut Vec<usize>,
+        ) {
+            let indent = "  ".repeat(depth);
+            write!(md, "{indent}- {}", tag.title).unwrap();
+            if let Some(shortcut) = tag.shortcut.as_deref().filter(|s| !s.is_empty()) {
+                write!(md, " `{shortcut}`").unwrap();
+            }
+            let plural = if tag.usage_count == 1 { "" } else { "s" };
+            writeln!(md, " — {} task{plural}", tag.usage_count).unwrap();
+
+            for (i, child) in doc.tags.iter().enumerate() {
+                if child.parent_uuid.as_ref() == Some(&tag.uuid) && !seen.contains(&i) {
+                    seen.push(i);
+                    write_tag(md, doc, child, depth + 1, seen);
+                }
+            }
+        }
+
+        let mut md = String::new();
+        writeln!(md, "# Tags\n").unwrap();
+        writeln!(
+            md,
+            "**Exported:** {}\n",
+            doc.exported_at.format("%Y-%m-%d %H:%M:%S UTC")
+        )
+        .unwrap();
+
+        let mut seen = Vec::new();
+        for (i, tag) in doc.tags.iter().enumerate() {
+            let is_root = tag
+                .parent_uuid
+                .as_ref()
+                .is_none_or(|parent| !doc.tags.iter().any(|t| &t.uuid == parent));
+            if is_root {
+                seen.push(i);
+                write_tag(&mut md, doc, tag, 0, &mut seen);
+            }
+        }
+        // Tags caught in a parent cycle have no root; list them at the top level
+        for (i, tag) in doc.tags.iter().enumerate() {
+            if !seen.contains(&i) {
+                seen.push(i);
+                write_tag(&mut md, doc, tag, 0, &mut seen);
+            }
+        }
+
+        md
+    }
+
+    /// Capture every non-trashed area, project, to-do, heading, and tag as flat lists
+    ///
+    /// Built from [`ThingsDatabase::snapshot`], so it sees the same data: trashed
+    /// items are left out and areas carry the Unix epoch as their timestamps.
+    /// Feed the result to [`Self::import_full_state`] to recreate the state in
+    /// another database.
+    ///
+    /// [`ThingsDatabase::snapshot`]: crate::database::ThingsDatabase::snapshot
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a database query fails.
+    pub async fn export_full_state(db: &crate::database::ThingsDatabase) -> Result<ExportData> {
+        use sqlx::Row;
+        use std::collections::HashSet;
+
+        fn push_task(mut task: Task, tasks: &mut Vec<Task>) {
+            let children = std::mem::take(&mut task.children);
+            tasks.push(task);
+            for child in children {
+                push_task(child, tasks);
+            }
+        }
+        fn push_project(mut project: Project, projects: &mut Vec<Project>, tasks: &mut Vec<Task>) {
+            for task in std::mem::take(&mut project.tasks) {
+                push_task(task, tasks);
+            }
+            projects.push(project);
+        }
+
+        let snapshot = db.snapshot().await?;
+        let (mut areas, mut projects, mut tasks) = (Vec::new(), Vec::new(), Vec::new());
+        for mut area in snapshot.areas {
+            for project in std::mem::take(&mut area.projects) {
+                push_project(project, &mut projects, &mut tasks);
+            }
+            areas.push(area);
+        }
+        for project in snapshot.projects {
+            push_project(project, &mut projects, &mut tasks);
+        }
+        for task in snapshot.tasks {
+            push_task(task, &mut tasks);
+        }
+
+        let task_ids: HashSet<&str> = projects
+            .iter()
+            .map(|p| p.uuid.as_str())
+            .chain(tasks.iter().map(|t| t.uuid.as_str()))
+            .collect();
+        let tag_ids: HashSet<&str> = snapshot.tags.iter().map(|t| t.uuid.as_str()).collect();
+        let area_ids: HashSet<&str> = areas.iter().map(|a| a.uuid.as_str()).collect();
+
+        let mut rows = FullStateRows::default();
+        let links: Vec<(String, String)> =
+            sqlx::query_as("SELECT tasks, tags FROM TMTaskTag ORDER BY tasks, tags")
+                .fetch_all(db.pool())
+                .await?;
+        for (owner, tag) in links {
+            if task_ids.contains(owner.as_str()) && tag_ids.contains(tag.as_str()) {
+                rows.tag_links.push(TagLink {
+                    owner: ThingsId::from_trusted(owner),
+                    tag: ThingsId::from_trusted(tag),
+                });
+            }
+        }
+        let placements = sqlx::query(
+            "SELECT uuid, start, todayIndex, \"index\" FROM TMTask WHERE trashed = 0 ORDER BY uuid",
+        )
+        .fetch_all(db.pool())
+        .await?;
+        for row in placements {
+            let uuid: String = row.try_get("uuid")?;
+            if task_ids.contains(uuid.as_str()) {
+                rows.tasks.push(TaskRowState {
+                    uuid: ThingsId::from_trusted(uuid),
+                    start: row.try_get("start")?,
+                    today_index: row.try_get("todayIndex")?,
+                    index: row.try_get("index")?,
+                });
+            }
+        }
+        let visibility: Vec<(String, Option<bool>)> =
+            sqlx::query_as("SELECT uuid, visible FROM TMArea ORDER BY uuid")
+                .fetch_all(db.pool())
+                .await?;
+        for (uuid, visible) in visibility {
+            if area_ids.contains(uuid.as_str()) {
+                rows.areas.push(AreaRowState {
+                    uuid: ThingsId::from_trusted(uuid),
+                    visible: visible.unwrap_or(true),
+                });
+            }
+        }
+
+        let mut data = ExportData::new(tasks, projects, areas);
+        data.total_items += snapshot.tags.len();
+        data.tags = snapshot.tags;
+        data.full_state = Some(rows);
+        Ok(data)
+    }
+
+    /// Insert the areas, tags, projects, and tasks of `data` into `db`, keeping their UUIDs
+    ///
+    /// Meant for loading an [`Self::export_full_state`] result into an empty
+    /// test database. List placement (`start`, `todayIndex`, `index`), area
+    /// visibility, and tag assignments come from [`ExportData::full_state`];
+    /// tags are linked by UUID, so same-titled tags stay distinct. Everything
+    /// is written in one transaction: if any row fails, for example because
+    /// its UUID already exists, nothing is imported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` has no `full_state`, if a tag link names a
+    /// tag or item missing from `data`, or if a database write fails.
+    pub async fn import_full_state(
+        db: &crate::database::ThingsDatabase,
+        data: &ExportData,
+    ) -> Result<()> {
+        use crate::database::naive_date_to_things_timestamp;
+        use std::collections::{HashMap, HashSet};
+
+        let rows = data.full_state.as_ref().ok_or_else(|| {
+            anyhow::anyhow!("import_full_state needs the full_state rows from export_full_state")
+        })?;
+        let tag_ids: HashSet<&ThingsId> = data.tags.iter().map(|tag| &tag.uuid).collect();
+        let owner_ids: HashSet<&ThingsId> = data
+            .projects
+            .iter()
+            .map(|p| &p.uuid)
+            .chain(data.tasks.iter().map(|t| &t.uuid))
+            .collect();
+        for link in &rows.tag_links {
+            if !tag_ids.contains(&link.tag) || !owner_ids.contains(&link.owner) {
+                anyhow::bail!(
+                    "tag link {} -> {} names an item that is not in the export",
+                    link.owner,
+                    link.tag
+                );
+            }
+        }
+        let placement: HashMap<&ThingsId, &TaskRowState> =
+            rows.tasks.iter().map(|row| (&row.uuid, row)).collect();
+        let visible: HashMap<&ThingsId, bool> = rows
+            .areas
+            .iter()
+            .map(|row| (&row.uuid, row.visible))
+            .collect();
+
+        let mut tx = db.pool().begin().await?;
+        for (index, area) in (0_i64..).zip(&data.areas) {
+            sqlx::query(
+                "INSERT INTO TMArea (uuid, title, visible, `index`, creationDate, \
+                 userModificationDate) VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(area.uuid.as_str())
+            .bind(&area.title)
+            .bind(visible.get(&area.uuid).copied().unwrap_or(true))
+            .bind(index)
+            .bind(area.created.timestamp() as f64)
+            .bind(area.modified.timestamp() as f64)
+            .execute(&mut *tx)
+            .await?;
+        }
+        for (index, tag) in (0_i64..).zip(&data.tags) {
+            sqlx::query(
+                "INSERT INTO TMTag (uuid, title, shortcut, parent, usedDate, `index`) \
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(tag.uuid.as_str())
+            .bind(&tag.title)
+            .bind(tag.shortcut.as_deref())
+            .bind(tag.parent_uuid.as_ref().map(ThingsId::as_str))
+            .bind(tag.last_used.map(|t| t.timestamp() as f64))
+            .bind(index)
+            .execute(&mut *tx)
+            .await?;
+        }
+        for project in &data.projects {
+            let row = placement.get(&project.uuid);
+            sqlx::query(
+                "INSERT INTO TMTask (uuid, title, type, status, trashed, area, notes, startDate, \
+                 deadline, creationDate, userModificationDate, start, todayIndex, \"index\") \
+                 VALUES (?, ?, 1, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(project.uuid.as_str())
+            .bind(&project.title)
+            .bind(project.status.code().unwrap_or(0))
+            .bind(project.trashed || project.status == TaskStatus::Trashed)
+            .bind(project.area_uuid.as_ref().map(ThingsId::as_str))
+            .bind(project.notes.as_deref())
+            .bind(project.start_date.map(naive_date_to_things_timestamp))
+            .bind(project.deadline.map(naive_date_to_things_timestamp))
+            .bind(project.created.timestamp() as f64)
+            .bind(project.modified.timestamp() as f64)
+            .bind(row.and_then(|r| r.start))
+            .bind(row.and_then(|r| r.today_index))
+            .bind(row.and_then(|r| r.index))
+            .execute(&mut *tx)
+            .await?;
+        }
+        for task in &data.tasks {
+            let task_type = match task.task_type {
+                TaskType::Todo => 0,
+                TaskType::Project => 1,
+                TaskType::Heading => 2,
+                TaskType::Area => 3,
+            };
+            let row = placement.get(&task.uuid);
+            sqlx::query(
+                "INSERT INTO TMTask (uuid, title, type, status, trashed, notes, startDate, deadline, \
+                 stopDate, creationDate, userModificationDate, project, area, heading, contact, \
+                 \"index\", startBucket, start, todayIndex) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(task.uuid.as_str())
+            .bind(&task.title)
+            .bind(task_type)
+            .bind(task.status.code().unwrap_or(0))
+            .bind(task.status == TaskStatus::Trashed)
+            .bind(task.notes.as_deref())
+            .bind(task.start_date.map(naive_date_to_things_timestamp))
+            .bind(task.deadline.map(naive_date_to_things_timestamp))
+            .bind(task.stop_date.map(|t| t.timestamp() as f64))
+            .bind(task.created.timestamp() as f64)
+            .bind(task.modified.timestamp() as f64)
+            .bind(task.project_uuid.as_ref().map(ThingsId::as_str))
+            .bind(task.area_uuid.as_ref().map(ThingsId::as_str))
+            .bind(task.parent_uuid.as_ref().map(ThingsId::as_str))
+            .bind(task.assignee.as_deref())
+            .bind(row.and_then(|r| r.index).or(task.index))
+            .bind(i64::from(task.evening))
+            .bind(row.and_then(|r| r.start))
+            .bind(row.and_then(|r| r.today_index))
+            .execute(&mut *tx)
+            .await?;
+        }
+        for link in &rows.tag_links {
+            sqlx::query("INSERT INTO TMTaskTag (tasks, tags) VALUES (?, ?)")
+                .bind(link.owner.as_str())
+                .bind(link.tag.as_str())
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// JSON Schema (draft 2020-12) describing the [`ExportFormat::Json`] output
+    ///
+    /// The schema mirrors the serde shape of [`ExportData`] and rejects unknown
+    /// properties, so any model change must be reflected here.
+    #[must_use]
+    pub fn json_schema() -> serde_json::Value {
+        let nullable_string = serde_json::json!({ "type": ["string", "null"] });
+        let nullable_date = serde_json::json!({ "type": ["string", "null"], "format": "date" });
+        let date_time = serde_json::json!({ "type": "string", "format": "date-time" });
+        let tags = serde_json::json!({ "type": "array", "items": { "type": "string" } });
+
+        serde_json::json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "ExportData",
+            "type": "object",
+            "required": ["tasks", "projects", "areas", "exported_at", "total_items"],
+            "additionalProperties": false,
+            "properties": {
+                "tasks": { "type": "array", "items": { "$ref": "#/$defs/Task" } },
+                "projects": { "type": "array", "items": { "$ref": "#/$defs/Project" } },
+                "areas": { "type": "array", "items": { "$ref": "#/$defs/Area" } },
+                "tags": { "type": "array", "items": { "$ref": "#/$defs/Tag" } },
+                "full_state": { "$ref": "#/$defs/FullStateRows" },
+                "exported_at": date_time,
+                "total_items": { "type": "integer", "minimum": 0 }
+            },
+            "$defs": {
+                "TaskStatus": {
+                    "oneOf": [
+                        { "enum": ["incomplete", "completed", "canceled", "trashed"] },
+                        {
+                            "type": "object",
+                            "properties": { "unknown": { "type": "integer" } },
+                            "required": ["unknown"],
+                            "additionalProperties": false
+                        }
+                    ]
+                },
+                "TaskType": {
+                    "enum": ["to-do", "project", "heading", "area"]
+                },
+                "Task": {
+                    "type": "object",
+                    "required": [
+                        "uuid", "title", "task_type", "status", "notes", "start_date",
+                        "deadline", "created", "modified", "stop_date", "project_uuid",
+                        "area_uuid", "parent_uuid", "tags", "index", "evening", "assignee",
+                        "children"
+                    ],
+                    "additionalProperties": false,
+                    "properties": {
+                        "uuid": { "type": "string" },
+                        "title": { "type": "string" },
+                        "task_type": { "$ref": "#/$defs/TaskType" },
+                        "status": { "$ref": "#/$defs/TaskStatus" },
+                        "notes": nullable_string,
+                        "start_date": nullable_date,
+                        "deadline": nullable_date,
+                        "created": date_time,
+                        "modified": date_time,
+                        "stop_date": { "type": ["string", "null"], "format": "date-time" },
+                        "project_uuid": nullable_string,
+                        "area_uuid": nullable_string,
+                        "parent_uuid": nullable_string,
+                        "tags": tags,
+                        "index": { "type": ["integer", "null"] },
+                        "evening": { "type": "boolean" },
+                        "assignee": nullable_string,
+                        "children": { "type": "array", "items": { "$ref": "#/$defs/Task" } }
+                    }
+                },
+                "Project": {
+                    "type": "object",
+                    "required": [
+                        "uuid", "title", "notes", "start_date", "deadline", "created",
+                        "modified", "area_uuid", "tags", "status", "tasks", "trashed"
+                    ],
+                    "additionalProperties": false,
+                    "properties": {
+                        "uuid": { "type": "string" },
+                        "title": { "type": "string" },
+                        "notes": nullable_string,
+                        "start_date": nullable_date,
+                        "deadline": nullable_date,
+                        "created": date_time,
+                        "modified": date_time,
+                        "area_uuid": nullable_string,
+                        "tags": tags,
+                        "status": { "$ref": "#/$defs/TaskStatus" },
+                        "tasks": { "type": "array", "items": { "$ref": "#/$defs/Task" } },
+                        "trashed": { "type": "boolean" }
+                    }
+                },
+                "Area": {
+                    "type": "object",
+                    "required": [
+                        "uuid", "title", "notes", "created", "modified", "tags", "projects"
+                    ],
+                    "additionalProperties": false,
+                    "properties": {
+                        "uuid": { "type": "string" },
+                        "title": { "type": "string" },
+                        "notes": nullable_string,
+                        "created": date_time,
+                        "modified": date_time,
+                        "tags": tags,
+                        "projects": { "type": "array", "items": { "$ref": "#/$defs/Project" } }
+                    }
+                },
+                "FullStateRows": {
+                    "type": "object",
+                    "required": ["tag_links", "tasks", "areas"],
+                    "additionalProperties": false,
+                    "properties": {
+                        "tag_links": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "required": ["owner", "tag"],
+                                "additionalProperties": false,
+                                "properties": {
+                                    "owner": { "type": "string" },
+                                    "tag": { "type": "string" }
+                                }
+                            }
+                        },
+                        "tasks": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "required": ["uuid", "start", "today_index", "index"],
+                                "additionalProperties": false,
+                                "properties": {
+                                    "uuid": { "type": "string" },
+                                    "start": { "type": ["integer", "null"] },
+                                    "today_index": { "type": ["integer", "null"] },
+                                    "index": { "type": ["integer", "null"] }
+                                }
+                            }
+                        },
+                        "areas": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "required": ["uuid", "visible"],
+                                "additionalProperties": false,
+                                "properties": {
+                                    "uuid": { "type": "string" },
+                                    "visible": { "type": "boolean" }
+                                }
+                            }
+                        }
+                    }
+                },
+                "Tag": {
+                    "type": "object",
+                    "required": [
+                        "uuid", "title", "shortcut", "parent_uuid", "usage_count", "last_used"
+                    ],
+                    "additionalProperties": false,
+                    "properties": {
+                        "uuid": { "type": "string" },
+                        "title": { "type": "string" },
+                        "shortcut": nullable_string,
+                        "parent_uuid": nullable_string,
+                        "usage_count": { "type": "integer", "minimum": 0 },
+                        "last_used": { "type": ["string", "null"], "format": "date-time" }
+                    }
+                }
+            }
+        })
+    }
+
+    /// Export as JSON, indented when `pretty` is set
+    fn export_json(data: &ExportData, pretty: bool) -> Result<String> {
+        if pretty {
+            Ok(serde_json::to_string_pretty(data)?)
+        } else {
+            Ok(serde_json::to_string(data)?)
+        }
     }
 
     /// Export as CSV
@@ -541,6 +1400,7 @@ const fn format_task_status_csv(status: TaskStatus) -> &'static str {
         TaskStatus::Completed => "Completed",
         TaskStatus::Canceled => "Canceled",
         TaskStatus::Trashed => "Trashed",
+        TaskStatus::Unknown(_) => "Unknown",
     }
 }
 
@@ -634,7 +1494,7 @@ fn taskpaper_metadata(
         }
         TaskStatus::Canceled => parts.push("@cancelled".to_string()),
         TaskStatus::Trashed => parts.push("@trashed".to_string()),
-        TaskStatus::Incomplete => {}
+        TaskStatus::Incomplete | TaskStatus::Unknown(_) => {}
     }
 
     if let Some(d) = deadline {
@@ -728,7 +1588,7 @@ fn escape_xml(s: &str) -> String {
 #[cfg(feature = "export-ical")]
 fn ical_todo_status(status: TaskStatus) -> icalendar::TodoStatus {
     match status {
-        TaskStatus::Incomplete => icalendar::TodoStatus::NeedsAction,
+        TaskStatus::Incomplete | TaskStatus::Unknown(_) => icalendar::TodoStatus::NeedsAction,
         TaskStatus::Completed => icalendar::TodoStatus::Completed,
         TaskStatus::Canceled | TaskStatus::Trashed => icalendar::TodoStatus::Cancelled,
     }
@@ -737,12 +1597,332 @@ fn ical_todo_status(status: TaskStatus) -> icalendar::TodoStatus {
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[cfg(any(feature = "export-taskpaper", feature = "export-ical"))]
-    use crate::models::ThingsId;
     use crate::test_utils::{create_mock_areas, create_mock_projects, create_mock_tasks};
     #[cfg(any(feature = "export-taskpaper", feature = "export-ical"))]
     use std::str::FromStr;
 
+    #[cfg(all(feature = "advanced-queries", feature = "batch-operations"))]
+    #[tokio::test]
+    async fn test_stream_tasks_ndjson_writes_one_task_per_line() {
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        crate::test_utils::create_test_database(temp_file.path())
+            .await
+            .unwrap();
+        let db = crate::database::ThingsDatabase::new(temp_file.path())
+            .await
+            .unwrap();
+
+        let mut output = Vec::new();
+        let written = DataExporter::stream_tasks_ndjson(&db, &mut output)
+            .await
+            .unwrap();
+
+        let text = String::from_utf8(output).unwrap();
+        let tasks: Vec<Task> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert!(written > 0);
+        assert_eq!(tasks.len() as u64, written);
+        assert!(text.ends_with('\n'));
+        assert!(tasks.iter().all(|t| t.task_type == TaskType::Todo));
+    }
+
+    #[tokio::test]
+    async fn test_export_project_builds_full_tree() {
+        use crate::models::{ChecklistItemInput, CreateProjectRequest};
+        use crate::test_utils::TaskRequestBuilder;
+
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        crate::test_utils::create_test_database(temp_file.path())
+            .await
+            .unwrap();
+        let db = crate::database::ThingsDatabase::new(temp_file.path())
+            .await
+            .unwrap();
+
+        let project = db
+            .create_project(CreateProjectRequest {
+                title: "Trip".to_string(),
+                notes: Some("Summer plans".to_string()),
+                area_uuid: None,
+                start_date: None,
+                deadline: None,
+                tags: Some(vec!["travel".to_string()]),
+            })
+            .await
+            .unwrap();
+        let loose = db
+            .create_task(
+                TaskRequestBuilder::new()
+                    .title("Book flights")
+                    .project(project.clone())
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let heading = db
+            .create_task(
+                TaskRequestBuilder::new()
+                    .title("Packing")
+                    .task_type(TaskType::Heading)
+                    .project(project.clone())
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let packed = db
+            .create_task(
+                TaskRequestBuilder::new()
+                    .title("Pack bag")
+                    .notes("Check the weather")
+                    .parent(heading.clone())
+                    .build(),
+            )
+            .await
+            .unwrap();
+        db.set_checklist_items(
+            &packed,
+            vec![ChecklistItemInput {
+                title: "Passport".to_string(),
+                completed: Some(true),
+            }],
+        )
+        .await
+        .unwrap();
+
+        let json = DataExporter::export_project(&db, &project, ExportFormat::Json)
+            .await
+            .unwrap();
+        let doc: ProjectExport = serde_json::from_str(&json).unwrap();
+        assert_eq!(doc.project.tags, vec!["travel".to_string()]);
+        assert_eq!(doc.tasks.len(), 1);
+        assert_eq!(doc.tasks[0].task.uuid, loose);
+        assert_eq!(doc.headings.len(), 1);
+        assert_eq!(doc.headings[0].uuid, heading);
+        assert_eq!(doc.headings[0].tasks[0].task.uuid, packed);
+        assert!(doc.headings[0].tasks[0].checklist[0].completed);
+
+        let md = DataExporter::export_project(&db, &project, ExportFormat::Markdown)
+            .await
+            .unwrap();
+        assert!(md.starts_with("# Trip\n"));
+        assert!(md.contains("**Tags:** travel"));
+        assert!(
+            md.contains("## Packing\n\n- [ ] Pack bag\n  > Check the weather\n  - [x] Passport")
+        );
+
+        assert!(
+            DataExporter::export_project(&db, &project, ExportFormat::Csv)
+                .await
+                .is_err()
+        );
+        assert!(
+            DataExporter::export_project(&db, &ThingsId::new_v4(), ExportFormat::Json)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_full_state_round_trips_through_import() {
+        use crate::models::{CreateTagRequest, TaskType};
+        use crate::test_utils::TaskRequestBuilder;
+
+        let source_file = tempfile::NamedTempFile::new().unwrap();
+        crate::test_utils::create_test_database(source_file.path())
+            .await
+            .unwrap();
+        let source = crate::database::ThingsDatabase::new(source_file.path())
+            .await
+            .unwrap();
+
+        let parent = source
+            .create_tag_force(CreateTagRequest {
+                title: "Office".to_string(),
+                shortcut: Some("o".to_string()),
+                parent_uuid: None,
+            })
+            .await
+            .unwrap();
+        source
+            .create_tag_force(CreateTagRequest {
+                title: "Meetings".to_string(),
+                shortcut: None,
+                parent_uuid: Some(parent),
+            })
+            .await
+            .unwrap();
+        let project = source.get_all_projects().await.unwrap()[0].uuid.clone();
+        let heading = source
+            .create_task(
+                TaskRequestBuilder::new()
+                    .title("Phase 1")
+                    .task_type(TaskType::Heading)
+                    .project(project.clone())
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let task = source
+            .create_task(
+                TaskRequestBuilder::new()
+                    .title("Standup")
+                    .notes("Daily")
+                    .parent(heading)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        // A second "Meetings" tag under another parent; the task uses this one
+        let home = source
+            .create_tag_force(CreateTagRequest {
+                title: "Home".to_string(),
+                shortcut: None,
+                parent_uuid: None,
+            })
+            .await
+            .unwrap();
+        let home_meetings = source
+            .create_tag_force(CreateTagRequest {
+                title: "Meetings".to_string(),
+                shortcut: None,
+                parent_uuid: Some(home),
+            })
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO TMTaskTag (tasks, tags) VALUES (?, ?)")
+            .bind(task.as_str())
+            .bind(home_meetings.as_str())
+            .execute(source.pool())
+            .await
+            .unwrap();
+        // List placement and area visibility aren't on the models
+        sqlx::query("UPDATE TMTask SET start = 2, todayIndex = 7, \"index\" = 4 WHERE uuid = ?")
+            .bind(project.as_str())
+            .execute(source.pool())
+            .await
+            .unwrap();
+        sqlx::query("UPDATE TMTask SET start = 1, todayIndex = 5 WHERE uuid = ?")
+            .bind(task.as_str())
+            .execute(source.pool())
+            .await
+            .unwrap();
+        sqlx::query("UPDATE TMArea SET visible = 0")
+            .execute(source.pool())
+            .await
+            .unwrap();
+
+        let data = DataExporter::export_full_state(&source).await.unwrap();
+        assert_eq!(
+            data.tags.iter().filter(|t| t.title == "Meetings").count(),
+            2
+        );
+        assert_eq!(
+            data.total_items,
+            data.tasks.len() + data.projects.len() + data.areas.len() + data.tags.len()
+        );
+
+        let target_file = tempfile::NamedTempFile::new().unwrap();
+        crate::test_utils::create_test_database(target_file.path())
+            .await
+            .unwrap();
+        let target = crate::database::ThingsDatabase::new(target_file.path())
+            .await
+            .unwrap();
+        for table in ["TMTaskTag", "TMTask", "TMArea", "TMTag"] {
+            sqlx::query(&format!("DELETE FROM {table}"))
+                .execute(target.pool())
+                .await
+                .unwrap();
+        }
+        DataExporter::import_full_state(&target, &data)
+            .await
+            .unwrap();
+
+        let before = serde_json::to_string(&source.snapshot().await.unwrap()).unwrap();
+        let after = serde_json::to_string(&target.snapshot().await.unwrap()).unwrap();
+        assert_eq!(before, after);
+
+        let rows = data.full_state.as_ref().unwrap();
+        assert!(rows.tasks.contains(&TaskRowState {
+            uuid: project.clone(),
+            start: Some(2),
+            today_index: Some(7),
+            index: Some(4),
+        }));
+        assert!(!rows.areas.is_empty() && rows.areas.iter().all(|area| !area.visible));
+        assert!(rows.tag_links.contains(&TagLink {
+            owner: task.clone(),
+            tag: home_meetings,
+        }));
+        let reexported = DataExporter::export_full_state(&target).await.unwrap();
+        assert_eq!(reexported.full_state, data.full_state);
+
+        // A second import collides on every UUID and leaves the target untouched
+        assert!(DataExporter::import_full_state(&target, &data)
+            .await
+            .is_err());
+        let again = serde_json::to_string(&target.snapshot().await.unwrap()).unwrap();
+        assert_eq!(after, again);
+    }
+
+    #[tokio::test]
+    async fn test_export_tags_nests_children_under_parents() {
+        use crate::models::CreateTagRequest;
+        use crate::test_utils::TaskRequestBuilder;
+
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        crate::test_utils::create_test_database(temp_file.path())
+            .await
+            .unwrap();
+        let db = crate::database::ThingsDatabase::new(temp_file.path())
+            .await
+            .unwrap();
+
+        let parent = db
+            .create_tag_force(CreateTagRequest {
+                title: "Office".to_string(),
+                shortcut: Some("o".to_string()),
+                parent_uuid: None,
+            })
+            .await
+            .unwrap();
+        db.create_tag_force(CreateTagRequest {
+            title: "Meetings".to_string(),
+            shortcut: None,
+            parent_uuid: Some(parent.clone()),
+        })
+        .await
+        .unwrap();
+        let task = db
+            .create_task(TaskRequestBuilder::new().title("Standup").build())
+            .await
+            .unwrap();
+        db.add_tag_to_task(&task, "Meetings").await.unwrap();
+
+        let json = DataExporter::export_tags(&db, ExportFormat::Json)
+            .await
+            .unwrap();
+        let doc: TagExport = serde_json::from_str(&json).unwrap();
+        let meetings = doc.tags.iter().find(|t| t.title == "Meetings").unwrap();
+        assert_eq!(meetings.parent_uuid.as_ref(), Some(&parent));
+        assert_eq!(meetings.usage_count, 1);
+
+        let md = DataExporter::export_tags(&db, ExportFormat::Markdown)
+            .await
+            .unwrap();
+        assert!(md.starts_with("# Tags\n"));
+        assert!(
+            md.contains("- Office `o` — 0 tasks\n  - Meetings — 1 task\n"),
+            "{md}"
+        );
+
+        assert!(DataExporter::export_tags(&db, ExportFormat::Csv)
+            .await
+            .is_err());
+    }
+
     #[test]
     fn test_export_format_from_str() {
         assert_eq!("json".parse::<ExportFormat>().unwrap(), ExportFormat::Json);
@@ -772,6 +1952,50 @@ mod tests {
         assert!("".parse::<ExportFormat>().is_err());
     }
 
+    #[test]
+    fn test_json_export_matches_schema() {
+        let mut data = ExportData::new(
+            create_mock_tasks(),
+            create_mock_projects(),
+            create_mock_areas(),
+        );
+        data.full_state = Some(FullStateRows {
+            tag_links: vec![TagLink {
+                owner: data.tasks[0].uuid.clone(),
+                tag: ThingsId::new_v4(),
+            }],
+            tasks: vec![TaskRowState {
+                uuid: data.tasks[0].uuid.clone(),
+                start: Some(1),
+                today_index: None,
+                index: Some(3),
+            }],
+            areas: vec![AreaRowState {
+                uuid: data.areas[0].uuid.clone(),
+                visible: false,
+            }],
+        });
+        let exporter = DataExporter::new_default();
+        let json = exporter.export(&data, ExportFormat::Json).unwrap();
+        let instance: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let schema = DataExporter::json_schema();
+        let validator = jsonschema::JSONSchema::options()
+            .with_draft(jsonschema::Draft::Draft202012)
+            .compile(&schema)
+            .expect("export schema compiles");
+        let messages: Vec<String> = match validator.validate(&instance) {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors
+                .map(|e| format!("{}: {e}", e.instance_path))
+                .collect(),
+        };
+        assert!(
+            messages.is_empty(),
+            "JSON export does not match schema: {messages:#?}"
+        );
+    }
+
     #[test]
     fn test_export_data_new() {
         let tasks = create_mock_tasks();
@@ -796,6 +2020,7 @@ mod tests {
         assert!(config.include_tags);
         assert_eq!(config.date_format, "%Y-%m-%d %H:%M:%S");
         assert_eq!(config.timezone, "UTC");
+        assert!(config.pretty);
     }
 
     #[test]
@@ -845,6 +2070,89 @@ mod tests {
         assert!(json.contains("\"Work\""));
     }
 
+    #[test]
+    fn test_export_json_pretty_and_compact() {
+        let data = ExportData::new(create_mock_tasks(), vec![], vec![]);
+
+        let pretty = DataExporter::new_default()
+            .export(&data, ExportFormat::Json)
+            .unwrap();
+        let compact = DataExporter::new(ExportConfig {
+            pretty: false,
+            ..ExportConfig::default()
+        })
+        .export(&data, ExportFormat::Json)
+        .unwrap();
+
+        assert!(pretty.contains('\n'));
+        assert!(!compact.contains('\n'));
+        assert!(compact.len() < pretty.len());
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&pretty).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&compact).unwrap()
+        );
+    }
+
+    #[cfg(feature = "export-csv")]
+    #[tokio::test]
+    async fn test_export_logbook_csv_bounds_by_completion_date() {
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        crate::test_utils::create_test_database(temp_file.path())
+            .await
+            .unwrap();
+        let db = crate::database::ThingsDatabase::new(temp_file.path())
+            .await
+            .unwrap()
+            .with_timezone(crate::Tz::UTC);
+        sqlx::query("DELETE FROM TMTask")
+            .execute(db.pool())
+            .await
+            .unwrap();
+
+        let stop = |date: &str| {
+            chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .unwrap()
+                .and_hms_opt(15, 0, 0)
+                .unwrap()
+                .and_utc()
+                .timestamp() as f64
+        };
+        for (title, stop_date) in [
+            ("Ship release", stop("2025-03-07")),
+            ("Plan Q2, draft", stop("2025-03-03")),
+            ("Last week", stop("2025-02-28")),
+        ] {
+            sqlx::query(
+                "INSERT INTO TMTask (uuid, title, type, status, stopDate, creationDate, userModificationDate, trashed) VALUES (?, ?, 0, 3, ?, 0, 0, 0)",
+            )
+            .bind(ThingsId::new_v4().as_str())
+            .bind(title)
+            .bind(stop_date)
+            .execute(db.pool())
+            .await
+            .unwrap();
+        }
+
+        let from = chrono::NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+        let to = chrono::NaiveDate::from_ymd_opt(2025, 3, 9).unwrap();
+        let mut output = Vec::new();
+        let written = DataExporter::export_logbook_csv(&db, from, to, &mut output)
+            .await
+            .unwrap();
+
+        let csv = String::from_utf8(output).unwrap();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(written, 2);
+        assert_eq!(lines[0], "completed_date,title,notes,tags,project,area");
+        assert_eq!(lines[1], "2025-03-07,Ship release,,,,");
+        assert_eq!(lines[2], "2025-03-03,\"Plan Q2, draft\",,,,");
+        assert_eq!(lines.len(), 3);
+
+        assert!(DataExporter::export_logbook_csv(&db, to, from, Vec::new())
+            .await
+            .is_err());
+    }
+
     #[test]
     #[cfg(feature = "export-csv")]
     fn test_export_csv_empty() {
@@ -1201,6 +2509,9 @@ mod tests {
                 area_uuid: None,
                 parent_uuid: None,
                 tags: vec![],
+                index: None,
+                evening: false,
+                assignee: None,
                 children: vec![],
             };
         let _ = base_uuid;
@@ -1258,6 +2569,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: None,
             tags: vec![],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
         let data = ExportData::new(vec![task], vec![], vec![]);
@@ -1293,6 +2607,9 @@ mod tests {
                 "high priority".to_string(),
                 "@weird(name)".to_string(),
             ],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
         let data = ExportData::new(vec![task], vec![], vec![]);
@@ -1326,6 +2643,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: None,
             tags: vec![],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
         let data = ExportData::new(vec![task], vec![], vec![]);
@@ -1485,6 +2805,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: None,
             tags: vec![],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
         let data = ExportData::new(vec![task], vec![], vec![]);
@@ -1519,6 +2842,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: None,
             tags: vec![],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
 
@@ -1575,6 +2901,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: None,
             tags: vec![],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
         let data = ExportData::new(vec![task], vec![], vec![]);
@@ -1622,6 +2951,9 @@ mod tests {
             area_uuid: Some(area_uuid),
             parent_uuid: None,
             tags: vec!["focus".to_string(), "deep-work".to_string()],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
         let data = ExportData::new(vec![task], vec![], vec![area]);
@@ -1664,6 +2996,7 @@ mod tests {
             tags: vec![],
             status: TaskStatus::Incomplete,
             tasks: vec![],
+            trashed: false,
         };
         let task = Task {
             uuid: ThingsId::from_str("87654321-0000-0000-0000-000000000001").unwrap(),
@@ -1680,6 +3013,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: None,
             tags: vec![],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
         let data = ExportData::new(vec![task], vec![project], vec![]);
@@ -1716,6 +3052,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: parent,
             tags: vec![],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
         let tasks = vec![
@@ -1753,6 +3092,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: None,
             tags: vec!["design, UX".to_string(), "client\\work".to_string()],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
         let data = ExportData::new(vec![task], vec![], vec![]);
@@ -1789,6 +3131,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: None,
             tags: vec![],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
         let data = ExportData::new(vec![task], vec![], vec![]);
@@ -232,6 +232,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: None,
             tags: vec![],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         }
     }
@@ -96,6 +96,8 @@ pub mod saved_queries;
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils;
 
+pub mod things_url;
+
 pub use backup::{BackupManager, BackupMetadata, BackupStats};
 pub use cache::{CacheConfig, CachePreloader, CacheStats, DefaultPreloader, ThingsCache};
 pub use cache_invalidation_middleware::{
@@ -110,8 +112,10 @@ pub use config_hot_reload::{
 };
 pub use config_loader::{load_config, load_config_from_env, load_config_with_paths, ConfigLoader};
 pub use database::{
-    get_default_database_path, ComprehensiveHealthStatus, DatabasePoolConfig, DatabaseStats,
-    PoolHealthStatus, PoolMetrics, SqliteOptimizations, ThingsDatabase,
+    discover_database_paths, get_default_database_path, paginate, CheckpointMode, CheckpointResult,
+    ComprehensiveHealthStatus, ConnectionState, DatabasePoolConfig, DatabaseStats, InboxAgeStats,
+    MultiDatabaseResults, Paginated, PoolHealthStatus, PoolMetrics, SqliteOptimizations,
+    TagNormalizationConfig, ThingsDatabase, ThingsMultiDatabase,
 };
 pub use disk_cache::{DiskCache, DiskCacheConfig, DiskCacheStats};
 pub use error::{Result, ThingsError};
@@ -122,7 +126,10 @@ pub use error::{Result, ThingsError};
     feature = "export-taskpaper",
     feature = "export-ical"
 ))]
-pub use export::{DataExporter, ExportConfig, ExportData, ExportFormat};
+pub use export::{
+    AreaRowState, DataExporter, ExportConfig, ExportData, ExportFormat, FullStateRows,
+    ProjectExport, ProjectExportHeading, ProjectExportTask, TagExport, TagLink, TaskRowState,
+};
 
 pub use mcp_cache_middleware::{MCPCacheConfig, MCPCacheEntry, MCPCacheMiddleware, MCPCacheStats};
 pub use mcp_config::McpServerConfig;
@@ -144,6 +151,7 @@ pub use performance::{
     PerformanceStats, PerformanceSummary, QueryMetrics,
 };
 pub use query_cache::{QueryCache, QueryCacheConfig, QueryCacheStats};
+pub use things_url::{AddTaskUrl, UpdateTaskUrl, When};
 
 #[cfg(feature = "advanced-queries")]
 pub use filter_expr::{FilterExpr, FilterPredicate};
@@ -160,5 +168,6 @@ pub use saved_queries::{SavedQuery, SavedQueryStore};
 
 /// Re-export commonly used types
 pub use chrono::{DateTime, NaiveDate, Utc};
+pub use chrono_tz::Tz;
 pub use serde::{Deserialize, Serialize};
 pub use uuid::Uuid;
@@ -668,30 +668,25 @@ impl McpServerConfig {
     /// Get the effective database path, falling back to default if needed
     ///
     /// # Errors
-    /// Returns an error if neither the specified path nor the default path exists
+    /// Returns `ThingsError::DatabaseNotFound` if neither the specified path nor
+    /// (with `fallback_to_default`) the default path is an existing file
     pub fn get_effective_database_path(&self) -> Result<PathBuf> {
-        // Check if the specified path exists
-        if self.database.path.exists() {
+        // Check if the specified path is an existing file (not a directory)
+        if self.database.path.is_file() {
             return Ok(self.database.path.clone());
         }
 
         // If fallback is enabled, try the default path
         if self.database.fallback_to_default {
             let default_path = Self::get_default_database_path();
-            if default_path.exists() {
+            if default_path.is_file() {
                 return Ok(default_path);
             }
         }
 
-        Err(ThingsError::configuration(format!(
-            "Database not found at {} and fallback is {}",
-            self.database.path.display(),
-            if self.database.fallback_to_default {
-                "enabled but default path also not found"
-            } else {
-                "disabled"
-            }
-        )))
+        Err(ThingsError::DatabaseNotFound {
+            path: self.database.path.display().to_string(),
+        })
     }
 
     /// Get the default Things 3 database path.
@@ -3,7 +3,7 @@
 use std::fmt;
 use std::str::FromStr;
 
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -353,7 +353,7 @@ mod things_id_tests {
 }
 
 /// Task status enumeration
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TaskStatus {
     #[serde(rename = "incomplete")]
     Incomplete,
@@ -365,6 +365,10 @@ pub enum TaskStatus {
     /// not by a status value — this variant is never returned from any read path.
     #[serde(rename = "trashed")]
     Trashed,
+    /// A `TMTask.status` code this library doesn't recognize, kept as read so
+    /// callers can detect it instead of seeing it as `Incomplete`
+    #[serde(rename = "unknown")]
+    Unknown(i32),
 }
 
 /// Task type enumeration
@@ -425,10 +429,35 @@ pub struct Task {
     pub parent_uuid: Option<ThingsId>,
     /// Associated tags
     pub tags: Vec<String>,
+    /// Manual sort position within its project, heading, area, or the Inbox
+    #[serde(default)]
+    pub index: Option<i64>,
+    /// Whether a Today task is filed under "This Evening" (`startBucket = 1`)
+    #[serde(default)]
+    pub evening: bool,
+    /// UUID of the `TMContact` the task is delegated to (the `contact` column)
+    #[serde(default)]
+    pub assignee: Option<String>,
     /// Child tasks
     pub children: Vec<Task>,
 }
 
+/// A task with its parents resolved to titles, built by
+/// [`crate::ThingsDatabase::get_task_detail`]
+///
+/// Tag titles are already on `task.tags`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskDetail {
+    /// The task itself
+    pub task: Task,
+    /// Project the task is in, directly or through its heading
+    pub project_title: Option<String>,
+    /// The task's own area, or else its project's area
+    pub area_title: Option<String>,
+    /// Heading the task is filed under
+    pub heading_title: Option<String>,
+}
+
 /// Project entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
@@ -454,6 +483,9 @@ pub struct Project {
     pub status: TaskStatus,
     /// Child tasks
     pub tasks: Vec<Task>,
+    /// Whether the project is in the Trash
+    #[serde(default)]
+    pub trashed: bool,
 }
 
 /// Area entity
@@ -614,6 +646,70 @@ pub struct TagStatistics {
     pub related_tags: Vec<(String, u32)>, // (tag_title, co_occurrence_count)
 }
 
+/// How many of a tag's to-dos have been completed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagCompletionStat {
+    /// Tag title
+    pub title: String,
+    /// Non-trashed to-dos carrying the tag, in any status
+    pub total: u32,
+    /// Of those, how many are completed
+    pub completed: u32,
+    /// `completed / total`, between 0.0 and 1.0
+    pub completion_ratio: f64,
+}
+
+/// A tag whose stored `usedDate` was corrected by
+/// [`crate::ThingsDatabase::recompute_tag_usage`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagUsageChange {
+    /// Tag UUID
+    pub uuid: ThingsId,
+    /// Tag title
+    pub title: String,
+    /// Number of non-trashed tasks carrying the tag
+    pub usage_count: u32,
+    /// `usedDate` before the recompute
+    pub previous_last_used: Option<DateTime<Utc>>,
+    /// `usedDate` after the recompute; `None` when no task uses the tag
+    pub last_used: Option<DateTime<Utc>>,
+}
+
+/// Result of [`crate::ThingsDatabase::recompute_tag_usage`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TagUsageReport {
+    /// Number of tags checked
+    pub tags_scanned: usize,
+    /// Tags whose `usedDate` differed from their tasks and was updated
+    pub changed: Vec<TagUsageChange>,
+}
+
+/// Referential integrity problems found by [`crate::ThingsDatabase::check_integrity`]
+///
+/// Each field lists the UUIDs of the offending rows; trashed rows are skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    /// To-dos and projects whose `project` or `area` points at no project or area
+    pub orphaned_tasks: Vec<ThingsId>,
+    /// To-dos whose `heading` points at no heading
+    pub missing_headings: Vec<ThingsId>,
+    /// Tags whose `parent` points at no tag
+    pub orphaned_tags: Vec<ThingsId>,
+    /// To-dos and projects whose deadline falls before their start date
+    pub invalid_date_ranges: Vec<ThingsId>,
+}
+
+impl IntegrityReport {
+    /// Whether no problems were found
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.orphaned_tasks.is_empty()
+            && self.missing_headings.is_empty()
+            && self.orphaned_tags.is_empty()
+            && self.invalid_date_ranges.is_empty()
+    }
+}
+
 /// Pair of similar tags (for duplicate detection)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TagPair {
@@ -625,6 +721,19 @@ pub struct TagPair {
     pub similarity: f32,
 }
 
+/// Recommendation to merge one tag into a similar one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeSuggestion {
+    /// Tag to keep (the more used of the pair)
+    pub keep: Tag,
+    /// Tag to merge into `keep` and remove
+    pub merge: Tag,
+    /// Similarity score between the two titles
+    pub similarity: f32,
+    /// Distinct non-trashed tasks carrying either tag (tagged `keep` after the merge)
+    pub affected_task_count: u32,
+}
+
 /// Task creation request
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTaskRequest {
@@ -650,7 +759,70 @@ pub struct CreateTaskRequest {
     pub status: Option<TaskStatus>,
 }
 
+/// Tri-state update for a nullable field
+///
+/// Serializes with JSON merge-patch semantics: a missing key is
+/// [`FieldUpdate::Unchanged`], an explicit `null` is [`FieldUpdate::Clear`],
+/// and any other value is [`FieldUpdate::Set`]. Fields using it must be marked
+/// `#[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FieldUpdate<T> {
+    /// Leave the field as it is
+    #[default]
+    Unchanged,
+    /// Set the field to NULL
+    Clear,
+    /// Set the field to a new value
+    Set(T),
+}
+
+impl<T> FieldUpdate<T> {
+    /// Whether the field is left untouched
+    #[must_use]
+    pub const fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+
+    /// The new nullable value, or `None` if the field is unchanged
+    #[must_use]
+    pub fn as_change(&self) -> Option<Option<&T>> {
+        match self {
+            Self::Unchanged => None,
+            Self::Clear => Some(None),
+            Self::Set(value) => Some(Some(value)),
+        }
+    }
+
+    /// The value the field will hold after applying this update to `current`
+    #[must_use]
+    pub fn resolve(self, current: Option<T>) -> Option<T> {
+        match self {
+            Self::Unchanged => current,
+            Self::Clear => None,
+            Self::Set(value) => Some(value),
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for FieldUpdate<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Set(value) => serializer.serialize_some(value),
+            Self::Unchanged | Self::Clear => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for FieldUpdate<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Option::<T>::deserialize(deserializer)?.map_or(Self::Clear, Self::Set))
+    }
+}
+
 /// Task update request
+///
+/// `None` / [`FieldUpdate::Unchanged`] leave a field as it is; nullable fields
+/// can be cleared with [`FieldUpdate::Clear`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateTaskRequest {
     /// Task UUID
@@ -658,21 +830,66 @@ pub struct UpdateTaskRequest {
     /// New title
     pub title: Option<String>,
     /// New notes
-    pub notes: Option<String>,
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub notes: FieldUpdate<String>,
     /// New start date
-    pub start_date: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub start_date: FieldUpdate<NaiveDate>,
     /// New deadline
-    pub deadline: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub deadline: FieldUpdate<NaiveDate>,
     /// New status
     pub status: Option<TaskStatus>,
     /// New project UUID
-    pub project_uuid: Option<ThingsId>,
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub project_uuid: FieldUpdate<ThingsId>,
     /// New area UUID
-    pub area_uuid: Option<ThingsId>,
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub area_uuid: FieldUpdate<ThingsId>,
     /// New tags
     pub tags: Option<Vec<String>>,
 }
 
+/// A checklist item read from `TMChecklistItem`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    /// Item UUID
+    pub uuid: ThingsId,
+    /// Item title
+    pub title: String,
+    /// Whether the item is checked off
+    pub completed: bool,
+}
+
+/// Checklist item input for [`crate::ThingsDatabase::set_checklist_items`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecklistItemInput {
+    /// Item title
+    pub title: String,
+    /// Completion state. `None` keeps the state of an existing item with the
+    /// same title, or incomplete if there is none.
+    pub completed: Option<bool>,
+}
+
+/// Every non-trashed area, project, task, and tag, nested by parent
+///
+/// Built by [`crate::ThingsDatabase::snapshot`]. Projects sit inside their
+/// area, to-dos and headings inside their project, and to-dos filed under a
+/// heading inside that heading's `children`. The top-level `projects` and
+/// `tasks` hold only the items without such a parent. Every list is sorted by
+/// UUID, so two snapshots serialize identically when the data is unchanged.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThingsSnapshot {
+    /// Areas, each with its projects
+    pub areas: Vec<Area>,
+    /// Projects not in any area
+    pub projects: Vec<Project>,
+    /// To-dos and headings not in any project (Inbox and area-level items)
+    pub tasks: Vec<Task>,
+    /// All tags
+    pub tags: Vec<Tag>,
+}
+
 /// Task filters for queries
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TaskFilters {
@@ -781,6 +998,108 @@ pub enum ProjectChildHandling {
     Orphan,
 }
 
+/// Calendar bucket used by [`crate::ThingsDatabase::get_creation_buckets`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BucketSize {
+    /// One calendar day
+    Day,
+    /// Monday through Sunday
+    Week,
+    /// One calendar month
+    Month,
+}
+
+impl BucketSize {
+    /// First day of the bucket containing `date`
+    #[must_use]
+    pub fn start_of(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Day => date,
+            Self::Week => {
+                date - chrono::Duration::days(i64::from(date.weekday().num_days_from_monday()))
+            }
+            Self::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    /// First day of the bucket `n` buckets after the one starting at `start`
+    ///
+    /// `start` must be a bucket start as returned by [`Self::start_of`].
+    /// Saturates at [`NaiveDate::MAX`].
+    #[must_use]
+    pub fn advance(self, start: NaiveDate, n: u32) -> NaiveDate {
+        match self {
+            Self::Day => start.checked_add_signed(chrono::Duration::days(i64::from(n))),
+            Self::Week => start.checked_add_signed(chrono::Duration::weeks(i64::from(n))),
+            Self::Month => start.checked_add_months(chrono::Months::new(n)),
+        }
+        .unwrap_or(NaiveDate::MAX)
+    }
+
+    /// First day of the bucket `n` buckets before the one starting at `start`
+    ///
+    /// Saturates at [`NaiveDate::MIN`].
+    #[must_use]
+    pub fn rewind(self, start: NaiveDate, n: u32) -> NaiveDate {
+        match self {
+            Self::Day => start.checked_sub_signed(chrono::Duration::days(i64::from(n))),
+            Self::Week => start.checked_sub_signed(chrono::Duration::weeks(i64::from(n))),
+            Self::Month => start.checked_sub_months(chrono::Months::new(n)),
+        }
+        .unwrap_or(NaiveDate::MIN)
+    }
+}
+
+/// Kind of container in a [`ListItem`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ListItemKind {
+    Area,
+    Project,
+}
+
+/// One row of the flat list built by [`crate::ThingsDatabase::get_list_picker_items`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListItem {
+    pub uuid: ThingsId,
+    pub title: String,
+    pub kind: ListItemKind,
+    /// Area the project belongs to; always `None` for areas
+    pub area_uuid: Option<ThingsId>,
+}
+
+/// One result of [`crate::ThingsDatabase::global_search`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum SearchHit {
+    Task(Task),
+    Project(Project),
+    Area(Area),
+}
+
+impl SearchHit {
+    /// UUID of the matched item
+    #[must_use]
+    pub fn uuid(&self) -> &ThingsId {
+        match self {
+            Self::Task(task) => &task.uuid,
+            Self::Project(project) => &project.uuid,
+            Self::Area(area) => &area.uuid,
+        }
+    }
+
+    /// Title of the matched item
+    #[must_use]
+    pub fn title(&self) -> &str {
+        match self {
+            Self::Task(task) => &task.title,
+            Self::Project(project) => &project.title,
+            Self::Area(area) => &area.title,
+        }
+    }
+}
+
 // ============================================================================
 // Bulk Operation Models
 // ============================================================================
@@ -885,6 +1204,35 @@ mod tests {
         assert_eq!(deserialized, TaskStatus::Trashed);
     }
 
+    #[test]
+    fn test_bucket_size_boundaries() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(); // a Wednesday
+        assert_eq!(BucketSize::Day.start_of(date), date);
+        assert_eq!(
+            BucketSize::Week.start_of(date),
+            NaiveDate::from_ymd_opt(2024, 1, 29).unwrap()
+        );
+        let month = BucketSize::Month.start_of(date);
+        assert_eq!(month, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        assert_eq!(
+            BucketSize::Month.advance(month, 1),
+            NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()
+        );
+        assert_eq!(
+            BucketSize::Month.rewind(month, 2),
+            NaiveDate::from_ymd_opt(2023, 11, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_bucket_size_saturates() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        for bucket in [BucketSize::Day, BucketSize::Week, BucketSize::Month] {
+            assert_eq!(bucket.advance(date, u32::MAX), NaiveDate::MAX);
+            assert_eq!(bucket.rewind(date, u32::MAX), NaiveDate::MIN);
+        }
+    }
+
     #[test]
     fn test_task_type_serialization() {
         let task_type = TaskType::Todo;
@@ -941,6 +1289,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: None,
             tags: vec!["work".to_string(), "urgent".to_string()],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
 
@@ -976,6 +1327,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: None,
             tags: vec![],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
 
@@ -1006,6 +1360,7 @@ mod tests {
             tags: vec!["project".to_string()],
             status: TaskStatus::Incomplete,
             tasks: vec![],
+            trashed: false,
         };
 
         assert_eq!(project.uuid, uuid);
@@ -1032,6 +1387,7 @@ mod tests {
             tags: vec![],
             status: TaskStatus::Incomplete,
             tasks: vec![],
+            trashed: false,
         };
 
         let serialized = serde_json::to_string(&project).unwrap();
@@ -1183,12 +1539,12 @@ mod tests {
         let request = UpdateTaskRequest {
             uuid: uuid.clone(),
             title: Some("Updated Title".to_string()),
-            notes: Some("Updated notes".to_string()),
-            start_date: None,
-            deadline: None,
+            notes: FieldUpdate::Set("Updated notes".to_string()),
+            start_date: FieldUpdate::Unchanged,
+            deadline: FieldUpdate::Unchanged,
             status: Some(TaskStatus::Completed),
-            project_uuid: None,
-            area_uuid: None,
+            project_uuid: FieldUpdate::Unchanged,
+            area_uuid: FieldUpdate::Unchanged,
             tags: Some(vec!["updated".to_string()]),
         };
 
@@ -1205,12 +1561,12 @@ mod tests {
         let request = UpdateTaskRequest {
             uuid: uuid.clone(),
             title: None,
-            notes: None,
-            start_date: None,
-            deadline: None,
+            notes: FieldUpdate::Unchanged,
+            start_date: FieldUpdate::Unchanged,
+            deadline: FieldUpdate::Unchanged,
             status: None,
-            project_uuid: None,
-            area_uuid: None,
+            project_uuid: FieldUpdate::Unchanged,
+            area_uuid: FieldUpdate::Unchanged,
             tags: None,
         };
 
@@ -1220,6 +1576,26 @@ mod tests {
         assert_eq!(deserialized.uuid, request.uuid);
     }
 
+    #[test]
+    fn test_update_task_request_merge_patch() {
+        let uuid = ThingsId::new_v4();
+        let json = serde_json::json!({
+            "uuid": uuid,
+            "notes": "New notes",
+            "deadline": null
+        });
+
+        let request: UpdateTaskRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(request.notes, FieldUpdate::Set("New notes".to_string()));
+        assert_eq!(request.deadline, FieldUpdate::Clear);
+        assert_eq!(request.start_date, FieldUpdate::Unchanged);
+        assert_eq!(request.project_uuid, FieldUpdate::Unchanged);
+
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value["deadline"], serde_json::Value::Null);
+        assert!(value.get("start_date").is_none());
+    }
+
     #[test]
     fn test_task_filters_default() {
         let filters = TaskFilters::default();
@@ -1327,6 +1703,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: Some(parent_uuid.clone()),
             tags: vec![],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
 
@@ -1345,6 +1724,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: None,
             tags: vec![],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![child_task],
         };
 
@@ -1374,6 +1756,9 @@ mod tests {
             area_uuid: None,
             parent_uuid: None,
             tags: vec![],
+            index: None,
+            evening: false,
+            assignee: None,
             children: vec![],
         };
 
@@ -1389,6 +1774,7 @@ mod tests {
             tags: vec![],
             status: TaskStatus::Incomplete,
             tasks: vec![task],
+            trashed: false,
         };
 
         assert_eq!(project.tasks.len(), 1);
@@ -1414,6 +1800,7 @@ mod tests {
             tags: vec![],
             status: TaskStatus::Incomplete,
             tasks: vec![],
+            trashed: false,
         };
 
         let area = Area {
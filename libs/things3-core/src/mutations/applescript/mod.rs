@@ -40,7 +40,7 @@ use crate::error::{Result as ThingsResult, ThingsError};
 use crate::models::{
     BulkCompleteRequest, BulkCreateTasksRequest, BulkDeleteRequest, BulkMoveRequest,
     BulkOperationResult, BulkUpdateDatesRequest, CreateAreaRequest, CreateProjectRequest,
-    CreateTagRequest, CreateTaskRequest, DeleteChildHandling, ProjectChildHandling,
+    CreateTagRequest, CreateTaskRequest, DeleteChildHandling, FieldUpdate, ProjectChildHandling,
     TagAssignmentResult, TagCreationResult, TagMatch, ThingsId, UpdateAreaRequest,
     UpdateProjectRequest, UpdateTagRequest, UpdateTaskRequest,
 };
@@ -254,10 +254,10 @@ impl MutationBackend for AppleScriptBackend {
 
     async fn update_task(&self, request: UpdateTaskRequest) -> ThingsResult<()> {
         request.uuid.as_things_native()?;
-        if let Some(p) = &request.project_uuid {
+        if let FieldUpdate::Set(p) = &request.project_uuid {
             p.as_things_native()?;
         }
-        if let Some(a) = &request.area_uuid {
+        if let FieldUpdate::Set(a) = &request.area_uuid {
             a.as_things_native()?;
         }
         let script = script::update_task_script(&request);
@@ -1179,12 +1179,12 @@ mod tests {
         let req = UpdateTaskRequest {
             uuid: hyphenated_uuid(),
             title: Some("x".into()),
-            notes: None,
-            start_date: None,
-            deadline: None,
+            notes: FieldUpdate::Unchanged,
+            start_date: FieldUpdate::Unchanged,
+            deadline: FieldUpdate::Unchanged,
             status: None,
-            project_uuid: None,
-            area_uuid: None,
+            project_uuid: FieldUpdate::Unchanged,
+            area_uuid: FieldUpdate::Unchanged,
             tags: None,
         };
         let err = backend
@@ -1202,12 +1202,12 @@ mod tests {
         let req = UpdateTaskRequest {
             uuid: ThingsId::new_things_native(),
             title: Some("x".into()),
-            notes: None,
-            start_date: None,
-            deadline: None,
+            notes: FieldUpdate::Unchanged,
+            start_date: FieldUpdate::Unchanged,
+            deadline: FieldUpdate::Unchanged,
             status: None,
-            project_uuid: Some(hyphenated_uuid()),
-            area_uuid: None,
+            project_uuid: FieldUpdate::Set(hyphenated_uuid()),
+            area_uuid: FieldUpdate::Unchanged,
             tags: None,
         };
         let err = backend
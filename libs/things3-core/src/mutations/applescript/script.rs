@@ -26,7 +26,7 @@ use super::escape::as_applescript_string;
 use crate::models::{
     BulkCompleteRequest, BulkCreateTasksRequest, BulkDeleteRequest, BulkMoveRequest,
     BulkUpdateDatesRequest, CreateAreaRequest, CreateProjectRequest, CreateTagRequest,
-    CreateTaskRequest, TaskStatus, ThingsId, UpdateAreaRequest, UpdateProjectRequest,
+    CreateTaskRequest, FieldUpdate, TaskStatus, ThingsId, UpdateAreaRequest, UpdateProjectRequest,
     UpdateTagRequest, UpdateTaskRequest,
 };
 
@@ -67,10 +67,10 @@ fn assign_date_var(var: &str, date: NaiveDate) -> String {
 /// Things AS exposes `open | completed | canceled` as the `status` enum.
 /// `Trashed` has no direct equivalent — the proper way to trash a task is
 /// `delete_task`, which calls a different script — so we map it to `canceled`
-/// as the closest finished state.
+/// as the closest finished state. `Unknown` codes are written back as `open`.
 fn status_as_applescript(status: TaskStatus) -> &'static str {
     match status {
-        TaskStatus::Incomplete => "open",
+        TaskStatus::Incomplete | TaskStatus::Unknown(_) => "open",
         TaskStatus::Completed => "completed",
         TaskStatus::Canceled | TaskStatus::Trashed => "canceled",
     }
@@ -135,7 +135,9 @@ pub(crate) fn create_task_script(req: &CreateTaskRequest) -> String {
 /// Build the partial-update script for an [`UpdateTaskRequest`].
 ///
 /// Only emits `set` lines for fields where the corresponding `Option` is
-/// `Some` — `None` means "leave unchanged".
+/// `Some` — `None` means "leave unchanged". [`FieldUpdate::Clear`] dates are
+/// set to `missing value`; clearing the project or area moves the to-do to
+/// the Inbox unless the other container is being set.
 #[allow(dead_code)] // Used by AppleScriptBackend, added in #134.
 pub(crate) fn update_task_script(req: &UpdateTaskRequest) -> String {
     let mut body = format!("\t\tset t to to do id \"{}\"\n", req.uuid);
@@ -146,19 +148,29 @@ pub(crate) fn update_task_script(req: &UpdateTaskRequest) -> String {
             as_applescript_string(title),
         ));
     }
-    if let Some(notes) = &req.notes {
-        body.push_str(&format!(
+    match &req.notes {
+        FieldUpdate::Set(notes) => body.push_str(&format!(
             "\t\tset notes of t to {}\n",
             as_applescript_string(notes),
-        ));
-    }
-    if let Some(date) = req.start_date {
-        body.push_str(&assign_date_var("activationDate", date));
-        body.push_str("\t\tset activation date of t to activationDate\n");
+        )),
+        FieldUpdate::Clear => body.push_str("\t\tset notes of t to \"\"\n"),
+        FieldUpdate::Unchanged => {}
+    }
+    match req.start_date {
+        FieldUpdate::Set(date) => {
+            body.push_str(&assign_date_var("activationDate", date));
+            body.push_str("\t\tset activation date of t to activationDate\n");
+        }
+        FieldUpdate::Clear => body.push_str("\t\tset activation date of t to missing value\n"),
+        FieldUpdate::Unchanged => {}
     }
-    if let Some(date) = req.deadline {
-        body.push_str(&assign_date_var("dueDate", date));
-        body.push_str("\t\tset due date of t to dueDate\n");
+    match req.deadline {
+        FieldUpdate::Set(date) => {
+            body.push_str(&assign_date_var("dueDate", date));
+            body.push_str("\t\tset due date of t to dueDate\n");
+        }
+        FieldUpdate::Clear => body.push_str("\t\tset due date of t to missing value\n"),
+        FieldUpdate::Unchanged => {}
     }
     if let Some(status) = req.status {
         body.push_str(&format!(
@@ -166,10 +178,17 @@ pub(crate) fn update_task_script(req: &UpdateTaskRequest) -> String {
             status_as_applescript(status),
         ));
     }
-    if let Some(uuid) = &req.project_uuid {
-        body.push_str(&format!("\t\tmove t to project id \"{uuid}\"\n"));
-    } else if let Some(uuid) = &req.area_uuid {
-        body.push_str(&format!("\t\tmove t to area id \"{uuid}\"\n"));
+    match (&req.project_uuid, &req.area_uuid) {
+        (FieldUpdate::Set(uuid), _) => {
+            body.push_str(&format!("\t\tmove t to project id \"{uuid}\"\n"));
+        }
+        (_, FieldUpdate::Set(uuid)) => {
+            body.push_str(&format!("\t\tmove t to area id \"{uuid}\"\n"));
+        }
+        (FieldUpdate::Clear, _) | (_, FieldUpdate::Clear) => {
+            body.push_str("\t\tmove t to list \"Inbox\"\n");
+        }
+        _ => {}
     }
     if let Some(tags) = &req.tags {
         let joined = tags.join(", ");
@@ -883,12 +902,12 @@ mod tests {
         let req = UpdateTaskRequest {
             uuid: sample_uuid(),
             title: None,
-            notes: None,
-            start_date: None,
-            deadline: None,
+            notes: FieldUpdate::Unchanged,
+            start_date: FieldUpdate::Unchanged,
+            deadline: FieldUpdate::Unchanged,
             status: None,
-            project_uuid: None,
-            area_uuid: None,
+            project_uuid: FieldUpdate::Unchanged,
+            area_uuid: FieldUpdate::Unchanged,
             tags: None,
         };
         let script = update_task_script(&req);
@@ -905,12 +924,12 @@ mod tests {
         let req = UpdateTaskRequest {
             uuid: sample_uuid(),
             title: Some("renamed".into()),
-            notes: None,
-            start_date: None,
-            deadline: None,
+            notes: FieldUpdate::Unchanged,
+            start_date: FieldUpdate::Unchanged,
+            deadline: FieldUpdate::Unchanged,
             status: Some(TaskStatus::Canceled),
-            project_uuid: None,
-            area_uuid: None,
+            project_uuid: FieldUpdate::Unchanged,
+            area_uuid: FieldUpdate::Unchanged,
             tags: Some(vec!["a".into(), "b".into()]),
         };
         let script = update_task_script(&req);
@@ -922,17 +941,37 @@ mod tests {
         assert!(!script.contains("due date"));
     }
 
+    #[test]
+    fn update_task_clear_fields_emit_missing_value_and_inbox_move() {
+        let req = UpdateTaskRequest {
+            uuid: sample_uuid(),
+            title: None,
+            notes: FieldUpdate::Clear,
+            start_date: FieldUpdate::Unchanged,
+            deadline: FieldUpdate::Clear,
+            status: None,
+            project_uuid: FieldUpdate::Clear,
+            area_uuid: FieldUpdate::Unchanged,
+            tags: None,
+        };
+        let script = update_task_script(&req);
+        assert!(script.contains("set notes of t to \"\""));
+        assert!(script.contains("set due date of t to missing value"));
+        assert!(script.contains("move t to list \"Inbox\""));
+        assert!(!script.contains("activation date"));
+    }
+
     #[test]
     fn update_task_trashed_status_maps_to_canceled() {
         let req = UpdateTaskRequest {
             uuid: sample_uuid(),
             title: None,
-            notes: None,
-            start_date: None,
-            deadline: None,
+            notes: FieldUpdate::Unchanged,
+            start_date: FieldUpdate::Unchanged,
+            deadline: FieldUpdate::Unchanged,
             status: Some(TaskStatus::Trashed),
-            project_uuid: None,
-            area_uuid: None,
+            project_uuid: FieldUpdate::Unchanged,
+            area_uuid: FieldUpdate::Unchanged,
             tags: None,
         };
         let script = update_task_script(&req);
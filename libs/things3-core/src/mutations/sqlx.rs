@@ -16,7 +16,7 @@ use crate::models::{
     BulkCompleteRequest, BulkCreateTasksRequest, BulkDeleteRequest, BulkMoveRequest,
     BulkOperationResult, BulkUpdateDatesRequest, CreateAreaRequest, CreateProjectRequest,
     CreateTagRequest, CreateTaskRequest, DeleteChildHandling, ProjectChildHandling,
-    TagAssignmentResult, TagCreationResult, TagMatch, ThingsId, UpdateAreaRequest,
+    TagAssignmentResult, TagCreationResult, TagMatch, TaskType, ThingsId, UpdateAreaRequest,
     UpdateProjectRequest, UpdateTagRequest, UpdateTaskRequest,
 };
 
@@ -31,6 +31,27 @@ impl SqlxBackend {
     }
 }
 
+/// The shared project (`None` for the Inbox) when every request sets only a
+/// title and that project, so the batch can go through
+/// [`ThingsDatabase::create_tasks_from_titles`]
+fn titles_only_project(tasks: &[CreateTaskRequest]) -> Option<Option<ThingsId>> {
+    let project = tasks.first()?.project_uuid.clone();
+    tasks
+        .iter()
+        .all(|t| {
+            t.project_uuid == project
+                && matches!(t.task_type, None | Some(TaskType::Todo))
+                && t.notes.is_none()
+                && t.start_date.is_none()
+                && t.deadline.is_none()
+                && t.area_uuid.is_none()
+                && t.parent_uuid.is_none()
+                && t.tags.is_none()
+                && t.status.is_none()
+        })
+        .then_some(project)
+}
+
 #[async_trait]
 impl MutationBackend for SqlxBackend {
     fn kind(&self) -> &'static str {
@@ -61,6 +82,31 @@ impl MutationBackend for SqlxBackend {
             )));
         }
         let total = request.tasks.len();
+
+        // Plain titles sharing one container (quick capture) are created in a
+        // single transaction
+        if let Some(project_uuid) = titles_only_project(&request.tasks) {
+            let titles: Vec<String> = request.tasks.into_iter().map(|t| t.title).collect();
+            return Ok(
+                match self
+                    .db
+                    .create_tasks_from_titles(&titles, project_uuid)
+                    .await
+                {
+                    Ok(created) => BulkOperationResult {
+                        success: true,
+                        processed_count: created.len(),
+                        message: format!("Successfully created {} task(s)", created.len()),
+                    },
+                    Err(e) => BulkOperationResult {
+                        success: false,
+                        processed_count: 0,
+                        message: format!("Created 0/{total}; errors: {e}"),
+                    },
+                },
+            );
+        }
+
         let mut processed = 0usize;
         let mut errors: Vec<String> = Vec::new();
         for (idx, task) in request.tasks.into_iter().enumerate() {
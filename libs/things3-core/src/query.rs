@@ -1,7 +1,8 @@
 //! Query builder for filtering and searching tasks
 
 use crate::models::{TaskFilters, TaskStatus, TaskType, ThingsId};
-use chrono::{Datelike, Duration, NaiveDate, Utc};
+use chrono::{Datelike, Duration, NaiveDate};
+use chrono_tz::Tz;
 
 /// Builder for constructing task queries with filters
 #[derive(Debug, Clone)]
@@ -26,6 +27,8 @@ pub struct TaskQueryBuilder {
     /// because `TaskFilters` is frozen public API.
     #[cfg(feature = "batch-operations")]
     after: Option<crate::cursor::Cursor>,
+    /// Timezone that decides what "today" means for the relative date helpers.
+    timezone: Tz,
 }
 
 impl TaskQueryBuilder {
@@ -48,9 +51,21 @@ impl TaskQueryBuilder {
             where_expr: None,
             #[cfg(feature = "batch-operations")]
             after: None,
+            timezone: crate::database::system_timezone(),
         }
     }
 
+    /// Set the timezone used by `due_today`, `overdue`, and the other relative date helpers
+    ///
+    /// Defaults to the system timezone. Pass [`crate::ThingsDatabase::timezone`] so the
+    /// builder agrees with the database on day boundaries. The helpers resolve dates when
+    /// they are called, so set this first.
+    #[must_use]
+    pub const fn timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
     /// Filter by status
     #[must_use]
     pub const fn status(mut self, status: TaskStatus) -> Self {
@@ -228,7 +243,7 @@ impl TaskQueryBuilder {
     /// Filter to tasks whose deadline is today.
     #[must_use]
     pub fn due_today(self) -> Self {
-        let today = today();
+        let today = self.today();
         self.deadline_range(Some(today), Some(today))
     }
 
@@ -236,7 +251,7 @@ impl TaskQueryBuilder {
     /// (Monday-Sunday week).
     #[must_use]
     pub fn due_this_week(self) -> Self {
-        let today = today();
+        let today = self.today();
         self.deadline_range(Some(today), Some(end_of_week(today)))
     }
 
@@ -244,7 +259,7 @@ impl TaskQueryBuilder {
     /// through Sunday, Monday-Sunday week).
     #[must_use]
     pub fn due_next_week(self) -> Self {
-        let today = today();
+        let today = self.today();
         let next_monday = end_of_week(today) + Duration::days(1);
         self.deadline_range(Some(next_monday), Some(end_of_week(next_monday)))
     }
@@ -252,7 +267,7 @@ impl TaskQueryBuilder {
     /// Filter to tasks whose deadline is between today and `days` days from now (inclusive).
     #[must_use]
     pub fn due_in(self, days: i64) -> Self {
-        let today = today();
+        let today = self.today();
         self.deadline_range(Some(today), Some(today + Duration::days(days)))
     }
 
@@ -263,7 +278,7 @@ impl TaskQueryBuilder {
     /// explicit `.status(...)` call before this helper is preserved.
     #[must_use]
     pub fn overdue(mut self) -> Self {
-        let yesterday = today() - Duration::days(1);
+        let yesterday = self.today() - Duration::days(1);
         self.filters.deadline_from = None;
         self.filters.deadline_to = Some(yesterday);
         if self.filters.status.is_none() {
@@ -275,7 +290,7 @@ impl TaskQueryBuilder {
     /// Filter to tasks with a start date of today.
     #[must_use]
     pub fn starting_today(self) -> Self {
-        let today = today();
+        let today = self.today();
         self.start_date_range(Some(today), Some(today))
     }
 
@@ -283,10 +298,14 @@ impl TaskQueryBuilder {
     /// (Monday-Sunday week).
     #[must_use]
     pub fn starting_this_week(self) -> Self {
-        let today = today();
+        let today = self.today();
         self.start_date_range(Some(today), Some(end_of_week(today)))
     }
 
+    fn today(&self) -> NaiveDate {
+        crate::database::today_in(self.timezone)
+    }
+
     /// Build the final filters
     #[must_use]
     pub fn build(self) -> TaskFilters {
@@ -660,6 +679,7 @@ impl TaskQueryBuilder {
             // Cursors are ephemeral and not part of saved-query state.
             #[cfg(feature = "batch-operations")]
             after: None,
+            timezone: crate::database::system_timezone(),
         }
     }
 }
@@ -722,10 +742,6 @@ fn best_window_score(query: &str, field: &str) -> f32 {
     best
 }
 
-fn today() -> NaiveDate {
-    Utc::now().date_naive()
-}
-
 fn end_of_week(d: NaiveDate) -> NaiveDate {
     let days_from_monday = i64::from(d.weekday().num_days_from_monday());
     d + Duration::days(6 - days_from_monday)
@@ -1006,6 +1022,9 @@ mod tests {
                 area_uuid: None,
                 parent_uuid: None,
                 tags: vec![],
+                index: None,
+                evening: false,
+                assignee: None,
                 children: vec![],
             };
             let score = task_fuzzy_score("agenda", &task);
@@ -1243,6 +1262,33 @@ mod tests {
     mod date_helper_tests {
         use super::*;
 
+        fn today() -> NaiveDate {
+            crate::database::today_in(crate::database::system_timezone())
+        }
+
+        #[test]
+        fn test_date_helpers_use_builder_timezone() {
+            // UTC+14 and UTC-12 are always on different calendar dates
+            let ahead: Tz = "Pacific/Kiritimati".parse().unwrap();
+            let behind: Tz = "Etc/GMT+12".parse().unwrap();
+
+            let filters = TaskQueryBuilder::new().timezone(ahead).due_today().build();
+            assert_eq!(
+                filters.deadline_from,
+                Some(crate::database::today_in(ahead))
+            );
+
+            let filters = TaskQueryBuilder::new().timezone(behind).due_today().build();
+            assert_eq!(
+                filters.deadline_from,
+                Some(crate::database::today_in(behind))
+            );
+            assert_ne!(
+                crate::database::today_in(ahead),
+                crate::database::today_in(behind)
+            );
+        }
+
         #[test]
         fn test_due_today_sets_deadline_range_to_today() {
             let filters = TaskQueryBuilder::new().due_today().build();
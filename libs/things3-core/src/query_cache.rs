@@ -778,6 +778,7 @@ mod tests {
             start_date: None,
             tags: vec![],
             tasks: vec![],
+            trashed: false,
         }];
 
         let query_key = "test_projects_query";
@@ -9,6 +9,9 @@ use std::time::Instant;
 use tracing::debug;
 use uuid::Uuid;
 
+/// Average result size (rows) above which an unbounded query should get a `LIMIT`
+pub const LARGE_RESULT_ROWS: f64 = 1000.0;
+
 /// Query execution context for tracking performance
 #[derive(Debug, Clone)]
 pub struct QueryContext {
@@ -235,6 +238,111 @@ impl QueryPerformanceTracker {
         suggestions.clone()
     }
 
+    /// Derive a ranked list of concrete optimizations from observed metrics
+    ///
+    /// Unlike [`Self::get_optimization_suggestions`], which reacts to each
+    /// individual execution, this looks at the aggregated statistics per query
+    /// type and the most recent SQL text recorded for it:
+    ///
+    /// - a query type whose p95 latency reaches the slow-query threshold gets an
+    ///   [`OptimizationType::AddIndex`] suggestion naming the table and `WHERE`
+    ///   columns to index, or [`OptimizationType::OptimizeQuery`] when no
+    ///   indexable predicate is found (or it uses a leading-wildcard `LIKE`);
+    /// - a query type averaging at least [`LARGE_RESULT_ROWS`] rows without a
+    ///   `LIMIT` gets an [`OptimizationType::ReduceResultSet`] suggestion.
+    ///
+    /// Priority scales with how far p95 exceeds the threshold. Results are
+    /// ordered by priority, then by total time spent in the query type, so the
+    /// first entries are the most worthwhile to fix.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn suggestions(&self) -> Vec<QueryOptimizationSuggestion> {
+        let stats = self.get_all_stats();
+        let latest_sql: HashMap<String, String> = {
+            let metrics = self.metrics.read();
+            metrics
+                .iter()
+                .map(|m| (m.query_type.clone(), m.query_text.clone()))
+                .collect()
+        };
+
+        let mut ranked: Vec<(f64, QueryOptimizationSuggestion)> = Vec::new();
+        for (query_type, stat) in &stats {
+            let sql = latest_sql.get(query_type).map_or("", String::as_str);
+            let lower = sql.to_ascii_lowercase();
+            let total_time_ms = stat.average_execution_time_ms * stat.total_executions as f64;
+
+            if stat.p95_execution_time_ms >= self.slow_query_threshold_ms {
+                let overage =
+                    stat.p95_execution_time_ms as f64 / self.slow_query_threshold_ms.max(1) as f64;
+                let priority = if overage >= 5.0 {
+                    OptimizationPriority::Critical
+                } else if overage >= 2.0 {
+                    OptimizationPriority::High
+                } else {
+                    OptimizationPriority::Medium
+                };
+                let observed = format!(
+                    "p95 {}ms over {} run(s)",
+                    stat.p95_execution_time_ms, stat.total_executions
+                );
+
+                let suggestion = match where_columns(sql) {
+                    Some((table, columns)) if !lower.contains("like '%") => {
+                        let index_name = format!("idx_{}_{}", table, columns.join("_"));
+                        QueryOptimizationSuggestion {
+                            query_type: query_type.clone(),
+                            suggestion_type: OptimizationType::AddIndex,
+                            description: format!(
+                                "{observed}. Add an index on {table}({cols}): CREATE INDEX {index_name} ON {table}({cols})",
+                                cols = columns.join(", ")
+                            ),
+                            potential_improvement_percent: 50.0,
+                            priority,
+                            implementation_effort: ImplementationEffort::Low,
+                        }
+                    }
+                    _ => QueryOptimizationSuggestion {
+                        query_type: query_type.clone(),
+                        suggestion_type: OptimizationType::OptimizeQuery,
+                        description: format!(
+                            "{observed}. No indexable WHERE predicate; narrow the query or avoid leading-wildcard LIKE."
+                        ),
+                        potential_improvement_percent: 30.0,
+                        priority,
+                        implementation_effort: ImplementationEffort::Medium,
+                    },
+                };
+                ranked.push((total_time_ms, suggestion));
+            }
+
+            if stat.average_result_size >= LARGE_RESULT_ROWS && !lower.contains(" limit ") {
+                ranked.push((
+                    total_time_ms,
+                    QueryOptimizationSuggestion {
+                        query_type: query_type.clone(),
+                        suggestion_type: OptimizationType::ReduceResultSet,
+                        description: format!(
+                            "Returns {:.0} rows on average with no LIMIT. Add LIMIT/OFFSET or paginate.",
+                            stat.average_result_size
+                        ),
+                        potential_improvement_percent: 40.0,
+                        priority: OptimizationPriority::Medium,
+                        implementation_effort: ImplementationEffort::Low,
+                    },
+                ));
+            }
+        }
+
+        ranked.sort_by(|(a_time, a), (b_time, b)| {
+            b.priority
+                .cmp(&a.priority)
+                .then(b_time.total_cmp(a_time))
+                .then_with(|| a.query_type.cmp(&b.query_type))
+        });
+        ranked.into_iter().map(|(_, s)| s).collect()
+    }
+
     /// Get slow queries (above threshold)
     #[must_use]
     pub fn get_slow_queries(&self) -> Vec<QueryPerformanceMetrics> {
@@ -498,6 +606,56 @@ impl QueryPerformanceTracker {
     }
 }
 
+/// Find the table and `WHERE` columns of a simple `SELECT ... FROM t WHERE ...`
+///
+/// A heuristic, not a SQL parser: it takes the first identifier after `FROM`
+/// and the leading identifier of each `AND`/`OR`-separated predicate, dropping
+/// any `alias.` prefix. Returns `None` if either part is missing.
+fn where_columns(sql: &str) -> Option<(String, Vec<String>)> {
+    let lower = sql.to_ascii_lowercase();
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '.';
+    let strip_alias = |ident: &str| ident.rsplit('.').next().unwrap_or(ident).to_string();
+
+    let after_from = &sql[lower.find(" from ")? + 6..];
+    let table: String = after_from
+        .trim_start()
+        .chars()
+        .take_while(|c| is_ident(*c))
+        .collect();
+    if table.is_empty() {
+        return None;
+    }
+
+    let where_start = lower.find(" where ")? + 7;
+    let where_end = [" order by ", " group by ", " limit "]
+        .iter()
+        .filter_map(|kw| lower[where_start..].find(kw))
+        .min()
+        .map_or(sql.len(), |end| where_start + end);
+    let clause = &lower[where_start..where_end];
+
+    let mut columns: Vec<String> = Vec::new();
+    for predicate in clause.split(" and ").flat_map(|p| p.split(" or ")) {
+        let ident: String = predicate
+            .trim_start_matches(|c: char| c.is_whitespace() || c == '(')
+            .chars()
+            .take_while(|c| is_ident(*c))
+            .collect();
+        if ident.is_empty() || ident.chars().all(|c| c.is_ascii_digit()) {
+            continue;
+        }
+        // Keep the column's original spelling from the SQL text
+        let offset = where_start + clause.find(&ident).unwrap_or(0);
+        let column = strip_alias(&sql[offset..offset + ident.len()]);
+        if !columns.contains(&column) {
+            columns.push(column);
+        }
+    }
+
+    let table = strip_alias(&table);
+    (!columns.is_empty()).then_some((table, columns))
+}
+
 /// Query performance summary
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryPerformanceSummary {
@@ -567,6 +725,63 @@ mod tests {
         assert!(suggestions.iter().any(|s| s.query_type == "slow_query"));
     }
 
+    #[test]
+    fn test_suggestions_rank_concrete_actions() {
+        // Zero latency threshold: every observed query counts as slow
+        let tracker = QueryPerformanceTracker::new(1000, 0, 0);
+
+        let run = |query_type: &str, sql: &str, rows: usize| {
+            let context = tracker.start_query(query_type, sql, vec![]);
+            tracker.complete_query(context, false, Some(rows), None, None, vec![]);
+        };
+        run(
+            "inbox",
+            "SELECT * FROM TMTask WHERE status = 0 AND t.trashed = 0 ORDER BY creationDate",
+            5000,
+        );
+        run(
+            "by_project",
+            "SELECT uuid FROM TMTask WHERE project = ? LIMIT 10",
+            10,
+        );
+        run(
+            "search",
+            "SELECT uuid FROM TMTask WHERE title LIKE '%foo%'",
+            3,
+        );
+
+        let suggestions = tracker.suggestions();
+        let find = |query_type: &str, kind: OptimizationType| {
+            suggestions
+                .iter()
+                .find(|s| s.query_type == query_type && s.suggestion_type == kind)
+        };
+
+        let index = find("inbox", OptimizationType::AddIndex).unwrap();
+        assert!(index.description.contains("ON TMTask(status, trashed)"));
+        assert!(find("inbox", OptimizationType::ReduceResultSet).is_some());
+        assert!(find("by_project", OptimizationType::AddIndex)
+            .unwrap()
+            .description
+            .contains("TMTask(project)"));
+        assert!(find("by_project", OptimizationType::ReduceResultSet).is_none());
+        assert!(find("search", OptimizationType::OptimizeQuery).is_some());
+
+        // Ranked by priority first
+        assert!(suggestions
+            .windows(2)
+            .all(|pair| pair[0].priority >= pair[1].priority));
+    }
+
+    #[test]
+    fn test_suggestions_empty_for_fast_small_queries() {
+        let tracker = QueryPerformanceTracker::new_default();
+        let context = tracker.start_query("quick", "SELECT 1 FROM TMTask WHERE uuid = ?", vec![]);
+        tracker.complete_query(context, true, Some(1), None, None, vec![]);
+
+        assert!(tracker.suggestions().is_empty());
+    }
+
     #[test]
     fn test_performance_summary() {
         let tracker = QueryPerformanceTracker::new_default();
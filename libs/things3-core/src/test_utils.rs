@@ -31,6 +31,7 @@ pub async fn create_test_database<P: AsRef<Path>>(db_path: P) -> crate::Result<(
             status INTEGER NOT NULL DEFAULT 0,
             notes TEXT,
             startDate INTEGER,
+            reminderTime INTEGER,
             deadline INTEGER,
             stopDate REAL,
             creationDate REAL NOT NULL,
@@ -38,10 +39,16 @@ pub async fn create_test_database<P: AsRef<Path>>(db_path: P) -> crate::Result<(
             project TEXT,
             area TEXT,
             heading TEXT,
+            contact TEXT,
+            checklistItemsCount INTEGER,
+            openChecklistItemsCount INTEGER,
             trashed INTEGER NOT NULL DEFAULT 0,
             tags TEXT DEFAULT '[]',
             cachedTags BLOB,
-            todayIndex INTEGER
+            todayIndex INTEGER,
+            start INTEGER,
+            startBucket INTEGER,
+            'index' INTEGER
         )
         ",
     )
@@ -100,6 +107,28 @@ pub async fn create_test_database<P: AsRef<Path>>(db_path: P) -> crate::Result<(
     .await
     .map_err(|e| crate::ThingsError::Database(format!("Failed to create TMTaskTag table: {e}")))?;
 
+    // Create TMChecklistItem table — checklist rows reference their task via `task`
+    sqlx::query(
+        "
+        CREATE TABLE IF NOT EXISTS TMChecklistItem (
+            uuid TEXT PRIMARY KEY,
+            leavesTombstone INTEGER,
+            creationDate REAL,
+            userModificationDate REAL,
+            status INTEGER,
+            stopDate REAL,
+            title TEXT,
+            task TEXT,
+            'index' INTEGER
+        )
+        ",
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| {
+        crate::ThingsError::Database(format!("Failed to create TMChecklistItem table: {e}"))
+    })?;
+
     // Insert test data
     insert_test_data(&pool).await?;
 
@@ -107,6 +136,35 @@ pub async fn create_test_database<P: AsRef<Path>>(db_path: P) -> crate::Result<(
     Ok(())
 }
 
+/// Create a test database with mock data plus seeded tags
+///
+/// Like [`create_test_database`], and additionally inserts `TMTag` rows using
+/// only the columns real Things 3 databases have (`shortcut`, `usedDate`,
+/// `parent`, `index`; no creation/modification dates) and links them to the
+/// seeded tasks through `TMTaskTag`:
+///
+/// - `work` (shortcut `w`) on "Research competitors"
+/// - `urgent` on "Inbox Task" and "Research competitors"
+/// - `home`, with child tag `errands` (both unused)
+///
+/// # Errors
+/// Returns `ThingsError::Database` if the database cannot be created
+pub async fn create_test_database_with_tags<P: AsRef<Path>>(db_path: P) -> crate::Result<()> {
+    use sqlx::SqlitePool;
+
+    create_test_database(&db_path).await?;
+
+    let database_url = format!("sqlite:{}", db_path.as_ref().display());
+    let pool = SqlitePool::connect(&database_url)
+        .await
+        .map_err(|e| crate::ThingsError::Database(format!("Failed to connect to database: {e}")))?;
+
+    insert_test_tags(&pool).await?;
+
+    pool.close().await;
+    Ok(())
+}
+
 async fn insert_test_data(pool: &sqlx::SqlitePool) -> crate::Result<()> {
     // Use a safe conversion for timestamp to avoid precision loss
     let timestamp_i64 = Utc::now().timestamp();
@@ -199,6 +257,55 @@ async fn insert_test_data(pool: &sqlx::SqlitePool) -> crate::Result<()> {
     Ok(())
 }
 
+async fn insert_test_tags(pool: &sqlx::SqlitePool) -> crate::Result<()> {
+    let now = Utc::now().timestamp() as f64;
+    let work_uuid = ThingsId::new_things_native().into_string();
+    let urgent_uuid = ThingsId::new_things_native().into_string();
+    let home_uuid = ThingsId::new_things_native().into_string();
+    let errands_uuid = ThingsId::new_things_native().into_string();
+
+    let tags = [
+        (&work_uuid, "work", Some("w"), Some(now), None),
+        (&urgent_uuid, "urgent", None, Some(now), None),
+        (&home_uuid, "home", None, None, None),
+        (&errands_uuid, "errands", None, None, Some(&home_uuid)),
+    ];
+    for (index, (uuid, title, shortcut, used_date, parent)) in tags.into_iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO TMTag (uuid, title, shortcut, usedDate, parent, 'index') VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(uuid)
+        .bind(title)
+        .bind(shortcut)
+        .bind(used_date)
+        .bind(parent)
+        .bind(index as i64)
+        .execute(pool)
+        .await
+        .map_err(|e| crate::ThingsError::Database(format!("Failed to insert test tag: {e}")))?;
+    }
+
+    let links = [
+        ("Research competitors", &work_uuid),
+        ("Research competitors", &urgent_uuid),
+        ("Inbox Task", &urgent_uuid),
+    ];
+    for (task_title, tag_uuid) in links {
+        sqlx::query(
+            "INSERT INTO TMTaskTag (tasks, tags) SELECT uuid, ? FROM TMTask WHERE title = ?",
+        )
+        .bind(tag_uuid)
+        .bind(task_title)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            crate::ThingsError::Database(format!("Failed to insert test task tag: {e}"))
+        })?;
+    }
+
+    Ok(())
+}
+
 /// Create mock data for testing
 ///
 /// # Panics
@@ -250,6 +357,7 @@ pub fn create_mock_projects() -> Vec<Project> {
             )),
             tags: vec!["work".to_string(), "web".to_string()],
             tasks: Vec::new(),
+            trashed: false,
         },
         Project {
             uuid: ThingsId::from_trusted("550e8400-e29b-41d4-a716-446655440011".to_string()),
@@ -265,6 +373,7 @@ pub fn create_mock_projects() -> Vec<Project> {
             )),
             tags: vec!["personal".to_string(), "learning".to_string()],
             tasks: Vec::new(),
+            trashed: false,
         },
     ]
 }
@@ -296,6 +405,9 @@ pub fn create_mock_tasks() -> Vec<Task> {
             )),
             parent_uuid: None,
             tags: vec!["research".to_string()],
+            index: None,
+            evening: false,
+            assignee: None,
             children: Vec::new(),
         },
         Task {
@@ -317,6 +429,9 @@ pub fn create_mock_tasks() -> Vec<Task> {
             )),
             parent_uuid: None,
             tags: vec!["reading".to_string()],
+            index: None,
+            evening: false,
+            assignee: None,
             children: Vec::new(),
         },
     ]
@@ -573,10 +688,16 @@ mod tests {
                 project TEXT,
                 area TEXT,
                 heading TEXT,
+                contact TEXT,
+                checklistItemsCount INTEGER,
+                openChecklistItemsCount INTEGER,
                 trashed INTEGER NOT NULL DEFAULT 0,
                 tags TEXT DEFAULT '[]',
                 cachedTags BLOB,
-                todayIndex INTEGER
+                todayIndex INTEGER,
+                start INTEGER,
+                startBucket INTEGER,
+                'index' INTEGER
             )
             ",
         )
@@ -812,6 +933,22 @@ pub async fn create_test_database_and_connect(
     Ok((db, temp_file))
 }
 
+/// Like [`create_test_database_and_connect`], but seeded via
+/// [`create_test_database_with_tags`]
+///
+/// # Errors
+///
+/// Returns an error if database creation or connection fails
+pub async fn create_test_database_with_tags_and_connect(
+) -> Result<(ThingsDatabase, NamedTempFile), crate::ThingsError> {
+    let temp_file = NamedTempFile::new()
+        .map_err(|e| crate::ThingsError::Database(format!("Failed to create temp file: {e}")))?;
+    let db_path = temp_file.path();
+    create_test_database_with_tags(db_path).await?;
+    let db = ThingsDatabase::new(db_path).await?;
+    Ok((db, temp_file))
+}
+
 /// Builder for creating test task requests with fluent API
 ///
 /// Provides a convenient way to create `CreateTaskRequest` instances in tests
@@ -0,0 +1,264 @@
+//! Builders for Things URL scheme commands (`things:///add`, `things:///update`)
+//!
+//! Opening one of these URLs asks the Things app to perform the change itself,
+//! so automations can create or edit to-dos without writing to the SQLite file.
+//! Every parameter value is percent-encoded; unset fields are omitted.
+
+use crate::models::ThingsId;
+use chrono::NaiveDate;
+
+/// Base URL for Things URL scheme commands
+const SCHEME_BASE: &str = "things:///";
+
+/// Value of the `when` parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum When {
+    /// Move to Today
+    Today,
+    /// Start tomorrow
+    Tomorrow,
+    /// Move to This Evening in Today
+    Evening,
+    /// Move to Anytime, clearing any start date
+    Anytime,
+    /// Move to Someday
+    Someday,
+    /// A specific start date
+    Date(NaiveDate),
+}
+
+impl When {
+    fn as_param(self) -> String {
+        match self {
+            Self::Today => "today".to_string(),
+            Self::Tomorrow => "tomorrow".to_string(),
+            Self::Evening => "evening".to_string(),
+            Self::Anytime => "anytime".to_string(),
+            Self::Someday => "someday".to_string(),
+            Self::Date(date) => date.format("%Y-%m-%d").to_string(),
+        }
+    }
+}
+
+/// `things:///add` — create a to-do
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AddTaskUrl {
+    /// Title of the new to-do
+    pub title: String,
+    /// Notes for the new to-do
+    pub notes: Option<String>,
+    /// Where the to-do starts; Things puts it in the Inbox when unset
+    pub when: Option<When>,
+    /// Deadline date
+    pub deadline: Option<NaiveDate>,
+    /// Tag titles; tags that don't exist in Things are ignored by the app
+    pub tags: Vec<String>,
+    /// Title of the project or area to add the to-do to
+    pub list: Option<String>,
+    /// Checklist item titles, in order
+    pub checklist_items: Vec<String>,
+}
+
+impl AddTaskUrl {
+    /// Create an add command for a to-do with the given title
+    #[must_use]
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Render the percent-encoded `things:///add?...` URL
+    #[must_use]
+    pub fn build(&self) -> String {
+        let mut params = vec![("title", self.title.clone())];
+        push_opt(&mut params, "notes", self.notes.clone());
+        push_opt(&mut params, "when", self.when.map(When::as_param));
+        push_opt(&mut params, "deadline", self.deadline.map(format_date));
+        push_list(&mut params, "tags", &self.tags, ",");
+        push_opt(&mut params, "list", self.list.clone());
+        push_list(&mut params, "checklist-items", &self.checklist_items, "\n");
+        command_url("add", &params)
+    }
+}
+
+/// `things:///update` — change an existing to-do
+///
+/// Things requires the per-device `auth-token` from Settings → General →
+/// Enable Things URLs for every update command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateTaskUrl {
+    /// ID of the to-do to change
+    pub id: ThingsId,
+    /// Per-device authorization token from the Things settings
+    pub auth_token: String,
+    /// Replaces the title
+    pub title: Option<String>,
+    /// Replaces the notes
+    pub notes: Option<String>,
+    /// Appended to the end of the existing notes
+    pub append_notes: Option<String>,
+    /// Moves the to-do to a new start list or date
+    pub when: Option<When>,
+    /// Replaces the deadline
+    pub deadline: Option<NaiveDate>,
+    /// Replaces all tags when set
+    pub tags: Option<Vec<String>>,
+    /// Adds tags without removing existing ones
+    pub add_tags: Vec<String>,
+    /// Title of the project or area to move the to-do to
+    pub list: Option<String>,
+    /// Marks the to-do completed (`true`) or incomplete (`false`)
+    pub completed: Option<bool>,
+    /// Marks the to-do canceled (`true`) or incomplete (`false`)
+    pub canceled: Option<bool>,
+}
+
+impl UpdateTaskUrl {
+    /// Create an update command that changes nothing yet
+    #[must_use]
+    pub fn new(id: ThingsId, auth_token: impl Into<String>) -> Self {
+        Self {
+            id,
+            auth_token: auth_token.into(),
+            title: None,
+            notes: None,
+            append_notes: None,
+            when: None,
+            deadline: None,
+            tags: None,
+            add_tags: Vec::new(),
+            list: None,
+            completed: None,
+            canceled: None,
+        }
+    }
+
+    /// Render the percent-encoded `things:///update?...` URL
+    #[must_use]
+    pub fn build(&self) -> String {
+        let mut params = vec![
+            ("id", self.id.to_string()),
+            ("auth-token", self.auth_token.clone()),
+        ];
+        push_opt(&mut params, "title", self.title.clone());
+        push_opt(&mut params, "notes", self.notes.clone());
+        push_opt(&mut params, "append-notes", self.append_notes.clone());
+        push_opt(&mut params, "when", self.when.map(When::as_param));
+        push_opt(&mut params, "deadline", self.deadline.map(format_date));
+        if let Some(tags) = &self.tags {
+            // An empty value clears every tag
+            params.push(("tags", tags.join(",")));
+        }
+        push_list(&mut params, "add-tags", &self.add_tags, ",");
+        push_opt(&mut params, "list", self.list.clone());
+        push_opt(
+            &mut params,
+            "completed",
+            self.completed.map(|b| b.to_string()),
+        );
+        push_opt(
+            &mut params,
+            "canceled",
+            self.canceled.map(|b| b.to_string()),
+        );
+        command_url("update", &params)
+    }
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+fn push_opt(params: &mut Vec<(&'static str, String)>, key: &'static str, value: Option<String>) {
+    if let Some(value) = value {
+        params.push((key, value));
+    }
+}
+
+fn push_list(
+    params: &mut Vec<(&'static str, String)>,
+    key: &'static str,
+    values: &[String],
+    separator: &str,
+) {
+    if !values.is_empty() {
+        params.push((key, values.join(separator)));
+    }
+}
+
+fn command_url(command: &str, params: &[(&str, String)]) -> String {
+    let query = params
+        .iter()
+        .map(|(key, value)| format!("{key}={}", percent_encode(value)))
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{SCHEME_BASE}{command}?{query}")
+}
+
+/// Percent-encode everything except RFC 3986 unreserved characters
+///
+/// Spaces become `%20` rather than `+`, which Things would show literally.
+fn percent_encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            out.push(char::from(byte));
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("a-b_c.d~e"), "a-b_c.d~e");
+        assert_eq!(percent_encode("Buy milk & eggs"), "Buy%20milk%20%26%20eggs");
+        assert_eq!(percent_encode("a+b=c?d/e#f"), "a%2Bb%3Dc%3Fd%2Fe%23f");
+        assert_eq!(percent_encode("café\n"), "caf%C3%A9%0A");
+    }
+
+    #[test]
+    fn test_add_task_url_build() {
+        let url = AddTaskUrl {
+            title: "Call Bob".to_string(),
+            notes: Some("About the trip".to_string()),
+            when: Some(When::Date(NaiveDate::from_ymd_opt(2025, 3, 1).unwrap())),
+            deadline: Some(NaiveDate::from_ymd_opt(2025, 3, 5).unwrap()),
+            tags: vec!["Errand".to_string(), "Phone".to_string()],
+            list: Some("Home & Garden".to_string()),
+            checklist_items: vec!["Dates".to_string(), "Budget".to_string()],
+        }
+        .build();
+
+        assert_eq!(
+            url,
+            "things:///add?title=Call%20Bob&notes=About%20the%20trip&when=2025-03-01\
+             &deadline=2025-03-05&tags=Errand%2CPhone&list=Home%20%26%20Garden\
+             &checklist-items=Dates%0ABudget"
+        );
+        assert_eq!(AddTaskUrl::new("Hi").build(), "things:///add?title=Hi");
+    }
+
+    #[test]
+    fn test_update_task_url_build() {
+        let id = ThingsId::from_str("2nTiSbYtTNBMXAXFR7ghBa").unwrap();
+        let mut update = UpdateTaskUrl::new(id, "tok en");
+        update.when = Some(When::Evening);
+        update.tags = Some(Vec::new());
+        update.completed = Some(true);
+
+        assert_eq!(
+            update.build(),
+            "things:///update?id=2nTiSbYtTNBMXAXFR7ghBa&auth-token=tok%20en&when=evening\
+             &tags=&completed=true"
+        );
+    }
+}
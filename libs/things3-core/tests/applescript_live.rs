@@ -27,8 +27,8 @@ use std::sync::Arc;
 use things3_core::{
     mutations::{AppleScriptBackend, MutationBackend},
     CreateAreaRequest, CreateProjectRequest, CreateTagRequest, CreateTaskRequest,
-    DeleteChildHandling, ProjectChildHandling, ThingsDatabase, ThingsId, UpdateAreaRequest,
-    UpdateProjectRequest, UpdateTagRequest, UpdateTaskRequest,
+    DeleteChildHandling, FieldUpdate, ProjectChildHandling, ThingsDatabase, ThingsId,
+    UpdateAreaRequest, UpdateProjectRequest, UpdateTagRequest, UpdateTaskRequest,
 };
 
 /// Skip the test body if `THINGS3_LIVE_TESTS=1` is not set in the env.
@@ -167,11 +167,11 @@ async fn task_lifecycle_round_trip() {
         .update_task(UpdateTaskRequest {
             uuid: id.clone(),
             title: Some(format!("{title} (updated)")),
-            notes: None,
-            start_date: None,
-            deadline: None,
-            project_uuid: None,
-            area_uuid: None,
+            notes: FieldUpdate::Unchanged,
+            start_date: FieldUpdate::Unchanged,
+            deadline: FieldUpdate::Unchanged,
+            project_uuid: FieldUpdate::Unchanged,
+            area_uuid: FieldUpdate::Unchanged,
             tags: None,
             status: None,
         })
@@ -9,6 +9,42 @@ use things3_core::models::{
 use things3_core::test_utils::{create_test_database_and_connect, TaskRequestBuilder};
 use things3_core::{ThingsError, ThingsId};
 
+// ============================================================================
+// Batch Validation Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_validate_tasks_exist_reports_missing() {
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+
+    let kept = db
+        .create_task(TaskRequestBuilder::new().title("Kept").build())
+        .await
+        .unwrap();
+    let trashed = db
+        .create_task(TaskRequestBuilder::new().title("Trashed").build())
+        .await
+        .unwrap();
+    db.delete_task(&trashed, things3_core::models::DeleteChildHandling::Error)
+        .await
+        .unwrap();
+    let unknown = ThingsId::new_v4();
+
+    let missing = db
+        .validate_tasks_exist(&[
+            kept.clone(),
+            unknown.clone(),
+            trashed.clone(),
+            unknown.clone(),
+        ])
+        .await
+        .unwrap();
+    assert_eq!(missing, vec![unknown, trashed]);
+
+    assert!(db.validate_tasks_exist(&[kept]).await.unwrap().is_empty());
+    assert!(db.validate_tasks_exist(&[]).await.unwrap().is_empty());
+}
+
 // ============================================================================
 // Bulk Move Tests
 // ============================================================================
@@ -297,6 +333,80 @@ async fn test_bulk_update_dates_merge_validation() {
     assert!(matches!(result, Err(ThingsError::DateValidation(_))));
 }
 
+#[tokio::test]
+async fn test_schedule_tasks_sets_start_date() {
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+
+    let deadline = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+    let with_deadline = db
+        .create_task(
+            TaskRequestBuilder::new()
+                .title("Has deadline")
+                .deadline(deadline)
+                .build(),
+        )
+        .await
+        .unwrap();
+    let plain = db
+        .create_task(TaskRequestBuilder::new().title("Plain").build())
+        .await
+        .unwrap();
+
+    let sprint = NaiveDate::from_ymd_opt(2024, 6, 17).unwrap();
+    let updated = db
+        .schedule_tasks(
+            &[with_deadline.clone(), plain.clone(), plain.clone()],
+            sprint,
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated, 2);
+
+    for uuid in [&with_deadline, &plain] {
+        let task = db.get_task_by_uuid(uuid).await.unwrap().unwrap();
+        assert_eq!(task.start_date, Some(sprint));
+    }
+    let task = db.get_task_by_uuid(&with_deadline).await.unwrap().unwrap();
+    assert_eq!(task.deadline, Some(deadline));
+}
+
+#[tokio::test]
+async fn test_schedule_tasks_is_atomic() {
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+
+    let early_deadline = db
+        .create_task(
+            TaskRequestBuilder::new()
+                .title("Due early")
+                .deadline(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap())
+                .build(),
+        )
+        .await
+        .unwrap();
+    let plain = db
+        .create_task(TaskRequestBuilder::new().title("Plain").build())
+        .await
+        .unwrap();
+    let sprint = NaiveDate::from_ymd_opt(2024, 6, 17).unwrap();
+
+    // Start date after an existing deadline fails the whole batch
+    let result = db
+        .schedule_tasks(&[plain.clone(), early_deadline], sprint)
+        .await;
+    assert!(matches!(result, Err(ThingsError::DateValidation(_))));
+
+    // So does a missing task
+    let result = db
+        .schedule_tasks(&[plain.clone(), ThingsId::new_v4()], sprint)
+        .await;
+    assert!(matches!(result, Err(ThingsError::TaskNotFound { .. })));
+
+    let task = db.get_task_by_uuid(&plain).await.unwrap().unwrap();
+    assert_eq!(task.start_date, None);
+
+    assert!(db.schedule_tasks(&[], sprint).await.is_err());
+}
+
 // ============================================================================
 // Bulk Complete Tests
 // ============================================================================
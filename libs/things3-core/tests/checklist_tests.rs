@@ -0,0 +1,115 @@
+//! Checklist item operation tests
+
+#![cfg(feature = "test-utils")]
+
+use sqlx::Row;
+use things3_core::{
+    test_utils::{create_test_database_and_connect, TaskRequestBuilder},
+    ChecklistItemInput, ThingsId,
+};
+
+fn item(title: &str, completed: Option<bool>) -> ChecklistItemInput {
+    ChecklistItemInput {
+        title: title.to_string(),
+        completed,
+    }
+}
+
+/// Fetch (title, status, index) for a task's checklist, ordered by index
+async fn fetch_items(
+    db: &things3_core::ThingsDatabase,
+    task: &ThingsId,
+) -> Vec<(String, i64, i64)> {
+    sqlx::query(
+        "SELECT title, status, `index` FROM TMChecklistItem WHERE task = ? ORDER BY `index`",
+    )
+    .bind(task.as_str())
+    .fetch_all(db.pool())
+    .await
+    .unwrap()
+    .iter()
+    .map(|row| (row.get("title"), row.get("status"), row.get("index")))
+    .collect()
+}
+
+/// Fetch the task's (checklistItemsCount, openChecklistItemsCount)
+async fn fetch_counts(db: &things3_core::ThingsDatabase, task: &ThingsId) -> (i64, i64) {
+    let row = sqlx::query(
+        "SELECT checklistItemsCount, openChecklistItemsCount FROM TMTask WHERE uuid = ?",
+    )
+    .bind(task.as_str())
+    .fetch_one(db.pool())
+    .await
+    .unwrap();
+    (
+        row.get("checklistItemsCount"),
+        row.get("openChecklistItemsCount"),
+    )
+}
+
+#[tokio::test]
+async fn test_set_checklist_items_replaces_with_sequential_index() {
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let task = db
+        .create_task(TaskRequestBuilder::new().title("Pack").build())
+        .await
+        .unwrap();
+
+    db.set_checklist_items(&task, vec![item("Old", None)])
+        .await
+        .unwrap();
+    db.set_checklist_items(
+        &task,
+        vec![item("Passport", None), item("Charger", Some(true))],
+    )
+    .await
+    .unwrap();
+
+    let items = fetch_items(&db, &task).await;
+    assert_eq!(
+        items,
+        vec![
+            ("Passport".to_string(), 0, 0),
+            ("Charger".to_string(), 3, 1),
+        ]
+    );
+    assert_eq!(fetch_counts(&db, &task).await, (2, 1));
+
+    db.set_checklist_items(&task, Vec::new()).await.unwrap();
+    assert_eq!(fetch_counts(&db, &task).await, (0, 0));
+}
+
+#[tokio::test]
+async fn test_set_checklist_items_preserves_completion_by_title() {
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let task = db
+        .create_task(TaskRequestBuilder::new().title("Pack").build())
+        .await
+        .unwrap();
+
+    db.set_checklist_items(
+        &task,
+        vec![item("Passport", Some(true)), item("Socks", None)],
+    )
+    .await
+    .unwrap();
+    db.set_checklist_items(&task, vec![item("Socks", None), item("Passport", None)])
+        .await
+        .unwrap();
+
+    let items = fetch_items(&db, &task).await;
+    assert_eq!(
+        items,
+        vec![("Socks".to_string(), 0, 0), ("Passport".to_string(), 3, 1)]
+    );
+    assert_eq!(fetch_counts(&db, &task).await, (2, 1));
+}
+
+#[tokio::test]
+async fn test_set_checklist_items_nonexistent_task() {
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let result = db
+        .set_checklist_items(&ThingsId::new_v4(), vec![item("Anything", None)])
+        .await;
+    assert!(result.is_err());
+}
@@ -1,9 +1,9 @@
-use chrono::Utc;
+use chrono::{Duration, Timelike, Utc};
 use std::path::Path;
 use tempfile::{tempdir, NamedTempFile};
 use things3_core::{
     models::{TaskStatus, TaskType},
-    ThingsDatabase,
+    ThingsDatabase, ThingsId,
 };
 use uuid::Uuid;
 
@@ -23,6 +23,7 @@ async fn create_test_schema(db: &ThingsDatabase) -> Result<(), Box<dyn std::erro
             status INTEGER NOT NULL DEFAULT 0,
             notes TEXT,
             startDate INTEGER,
+            reminderTime INTEGER,
             deadline INTEGER,
             stopDate REAL,
             creationDate REAL NOT NULL,
@@ -30,10 +31,14 @@ async fn create_test_schema(db: &ThingsDatabase) -> Result<(), Box<dyn std::erro
             project TEXT,
             area TEXT,
             heading TEXT,
+            contact TEXT,
             trashed INTEGER NOT NULL DEFAULT 0,
             tags TEXT DEFAULT '[]',
             cachedTags BLOB,
-            todayIndex INTEGER
+            todayIndex INTEGER,
+            start INTEGER,
+            startBucket INTEGER,
+            'index' INTEGER
         )
         ",
     )
@@ -179,6 +184,69 @@ async fn create_test_schema(db: &ThingsDatabase) -> Result<(), Box<dyn std::erro
     Ok(())
 }
 
+/// A `TMTask` row for [`insert_task`]; columns left at their default are NULL, 0, or false
+#[derive(Default)]
+struct TaskRow<'a> {
+    /// A fresh UUID is generated when unset
+    uuid: Option<&'a str>,
+    title: &'a str,
+    task_type: i64,
+    status: i64,
+    notes: Option<&'a str>,
+    start: Option<i64>,
+    start_date: Option<i64>,
+    deadline: Option<i64>,
+    reminder_time: Option<i64>,
+    stop_date: Option<f64>,
+    created: f64,
+    modified: f64,
+    project: Option<&'a str>,
+    area: Option<&'a str>,
+    heading: Option<&'a str>,
+    contact: Option<&'a str>,
+    today_index: Option<i64>,
+    start_bucket: Option<i64>,
+    index: Option<i64>,
+    trashed: bool,
+}
+
+/// Insert one `TMTask` row into a database set up by [`create_test_schema`], returning its UUID
+async fn insert_task(db: &ThingsDatabase, row: TaskRow<'_>) -> String {
+    let uuid = row
+        .uuid
+        .map_or_else(|| Uuid::new_v4().to_string(), str::to_string);
+    sqlx::query(
+        "INSERT INTO TMTask (uuid, title, type, status, notes, start, startDate, deadline, \
+         reminderTime, stopDate, creationDate, userModificationDate, project, area, heading, \
+         contact, todayIndex, startBucket, \"index\", trashed) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&uuid)
+    .bind(row.title)
+    .bind(row.task_type)
+    .bind(row.status)
+    .bind(row.notes)
+    .bind(row.start)
+    .bind(row.start_date)
+    .bind(row.deadline)
+    .bind(row.reminder_time)
+    .bind(row.stop_date)
+    .bind(row.created)
+    .bind(row.modified)
+    .bind(row.project)
+    .bind(row.area)
+    .bind(row.heading)
+    .bind(row.contact)
+    .bind(row.today_index)
+    .bind(row.start_bucket)
+    .bind(row.index)
+    .bind(row.trashed)
+    .execute(db.pool())
+    .await
+    .unwrap();
+    uuid
+}
+
 #[tokio::test]
 async fn test_database_new() {
     let db = ThingsDatabase::from_connection_string("sqlite::memory:")
@@ -231,68 +299,1066 @@ async fn test_get_today() {
     // Create schema and insert test data
     create_test_schema(&db).await.unwrap();
 
-    let today = db.get_today(None).await.unwrap();
+    let today = db.get_today(None).await.unwrap();
+
+    // Should have tasks for today
+    assert!(!today.is_empty());
+}
+
+#[tokio::test]
+async fn test_inbox_and_today_counts_match_lists() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+
+    let inbox = db.get_inbox(None).await.unwrap();
+    let today = db.get_today(None).await.unwrap();
+    assert_eq!(db.get_inbox_count().await.unwrap(), inbox.len() as u64);
+    assert_eq!(db.get_today_count().await.unwrap(), today.len() as u64);
+}
+
+#[tokio::test]
+async fn test_overdue_and_completed_today_counts() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap()
+        .with_timezone(chrono_tz::UTC);
+
+    create_test_schema(&db).await.unwrap();
+    let overdue_before = db.get_overdue_count().await.unwrap();
+    let completed_before = db.get_completed_today_count().await.unwrap();
+
+    let epoch = chrono::NaiveDate::from_ymd_opt(2001, 1, 1).unwrap();
+    let today = (Utc::now().date_naive() - epoch).num_days() * 86400;
+    let now = Utc::now().timestamp() as f64;
+    // (title, status, deadline, stopDate)
+    for (title, status, deadline, stop_date) in [
+        ("Overdue", 0, Some(today - 86400), None),
+        ("Due today", 0, Some(today), None),
+        ("Completed late", 3, Some(today - 86400), Some(now)),
+        ("Completed yesterday", 3, None, Some(now - 2.0 * 86400.0)),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                status,
+                deadline,
+                stop_date,
+                created: now,
+                modified: now,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    assert_eq!(db.get_overdue_count().await.unwrap(), overdue_before + 1);
+    assert_eq!(
+        db.get_completed_today_count().await.unwrap(),
+        completed_before + 1
+    );
+}
+
+#[tokio::test]
+async fn test_inbox_and_search_pages() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+
+    let now = Utc::now().timestamp() as f64;
+    for i in 0..3 {
+        insert_task(
+            &db,
+            TaskRow {
+                title: &format!("Paged {i}"),
+                created: now + f64::from(i),
+                modified: now,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let inbox_total = db.get_inbox_count().await.unwrap();
+    let first = db.get_inbox_page(0, 2).await.unwrap();
+    assert_eq!(first.total, inbox_total);
+    assert_eq!(first.items.len(), 2);
+    assert!(first.has_more);
+    let rest = db.get_inbox_page(2, 10).await.unwrap();
+    assert_eq!(rest.items.len() as u64, inbox_total - 2);
+    assert!(!rest.has_more);
+
+    let search = db.search_tasks_page("Paged", false, 1, 1).await.unwrap();
+    assert_eq!(search.total, 3);
+    assert_eq!(search.items[0].title, "Paged 1");
+    assert!(search.has_more);
+}
+
+#[tokio::test]
+async fn test_get_projects() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    // Create schema and insert test data
+    create_test_schema(&db).await.unwrap();
+
+    let projects = db.get_projects(None).await.unwrap();
+
+    // Should have projects from test data
+    assert!(!projects.is_empty());
+
+    // Verify project properties
+    let first_project = &projects[0];
+    assert_eq!(first_project.title, "Website Redesign");
+    assert_eq!(first_project.status, TaskStatus::Incomplete);
+}
+
+#[tokio::test]
+async fn test_get_areas() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    // Create schema and insert test data
+    create_test_schema(&db).await.unwrap();
+
+    let areas = db.get_areas().await.unwrap();
+
+    // Should have areas from test data
+    assert!(!areas.is_empty());
+
+    // Verify area properties
+    let first_area = &areas[0];
+    assert_eq!(first_area.title, "Work");
+}
+
+#[tokio::test]
+async fn test_search_tasks() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    // Create schema and insert test data
+    create_test_schema(&db).await.unwrap();
+
+    let results = db.search_tasks("competitors").await.unwrap();
+
+    // Should find tasks containing "competitors"
+    assert!(!results.is_empty());
+
+    // Verify search results contain the search term
+    let found_task = results.iter().find(|t| t.title.contains("competitors"));
+    assert!(found_task.is_some());
+}
+
+#[tokio::test]
+async fn test_search_tasks_excludes_canceled_by_default() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    let now = Utc::now().timestamp() as f64;
+    insert_task(
+        &db,
+        TaskRow {
+            title: "Abandoned competitors review",
+            status: 2, // Canceled
+            created: now,
+            modified: now,
+            ..TaskRow::default()
+        },
+    )
+    .await;
+
+    let results = db.search_tasks("competitors").await.unwrap();
+    assert!(results.iter().all(|t| t.status != TaskStatus::Canceled));
+    assert!(results.iter().any(|t| t.title == "Research competitors"));
+
+    let results = db
+        .search_tasks_with_options("competitors", true)
+        .await
+        .unwrap();
+    assert!(results
+        .iter()
+        .any(|t| t.title == "Abandoned competitors review" && t.status == TaskStatus::Canceled));
+
+    let without = db
+        .search_tasks_page("competitors", false, 0, 10)
+        .await
+        .unwrap();
+    let with = db
+        .search_tasks_page("competitors", true, 0, 10)
+        .await
+        .unwrap();
+    assert_eq!(with.total, without.total + 1);
+    assert_eq!(with.items.len(), without.items.len() + 1);
+    assert!(without
+        .items
+        .iter()
+        .all(|t| t.status != TaskStatus::Canceled));
+}
+
+#[tokio::test]
+async fn test_get_status_counts() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    let before = db.get_status_counts().await.unwrap();
+    let open = before.get(&TaskStatus::Incomplete).copied().unwrap_or(0);
+
+    let now = Utc::now().timestamp() as f64;
+    // Canceled, completed, and an unknown status code
+    for status in [2, 3, 3, 42] {
+        insert_task(
+            &db,
+            TaskRow {
+                title: &format!("Status {status}"),
+                status,
+                created: now,
+                modified: now,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let counts = db.get_status_counts().await.unwrap();
+    assert_eq!(
+        counts.get(&TaskStatus::Incomplete).copied().unwrap_or(0),
+        open
+    );
+    assert_eq!(counts.get(&TaskStatus::Canceled), Some(&1));
+    assert_eq!(counts.get(&TaskStatus::Completed), Some(&2));
+    assert_eq!(counts.get(&TaskStatus::Unknown(42)), Some(&1));
+    assert_eq!(counts.len(), 4);
+}
+
+#[tokio::test]
+async fn test_unknown_status_code_is_preserved() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+
+    let now = Utc::now().timestamp() as f64;
+    let uuid = insert_task(
+        &db,
+        TaskRow {
+            title: "Future status",
+            status: 7,
+            created: now,
+            modified: now,
+            ..TaskRow::default()
+        },
+    )
+    .await;
+
+    let id: ThingsId = uuid.parse().unwrap();
+    let task = db.get_task_by_uuid(&id).await.unwrap().unwrap();
+    assert_eq!(task.status, TaskStatus::Unknown(7));
+
+    // Not mislabeled as an open inbox task
+    let inbox = db.get_inbox(None).await.unwrap();
+    assert!(inbox.iter().all(|t| t.uuid != id));
+}
+
+#[tokio::test]
+async fn test_get_recent_tasks() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+
+    let now = Utc::now().timestamp() as f64;
+    // (title, type, trashed, seconds in the future) — newer than every fixture row
+    for (title, task_type, trashed, offset) in [
+        ("Newest", 0, false, 300.0),
+        ("Trashed newest", 0, true, 400.0),
+        ("Heading newest", 2, false, 500.0),
+        ("Second newest", 0, false, 200.0),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                task_type,
+                created: now + offset,
+                modified: now,
+                trashed,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let recent = db.get_recent_tasks(2).await.unwrap();
+    let titles: Vec<&str> = recent.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["Newest", "Second newest"]);
+}
+
+#[tokio::test]
+async fn test_get_tasks_with_reminders() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap()
+        .with_timezone(chrono_tz::UTC);
+
+    create_test_schema(&db).await.unwrap();
+
+    let epoch = chrono::NaiveDate::from_ymd_opt(2001, 1, 1).unwrap();
+    let now = Utc::now();
+    // (title, status, hours from now) — reminders at whole minutes in UTC
+    for (title, status, hours) in [
+        ("Later", 0, 2),
+        ("Soon", 0, 1),
+        ("Already fired", 0, -2),
+        ("Beyond window", 0, 5),
+        ("Completed", 3, 1),
+    ] {
+        let fires_at = now + chrono::Duration::hours(hours);
+        let start_date = (fires_at.date_naive() - epoch).num_days() * 86400;
+        let reminder_time =
+            (i64::from(fires_at.hour()) << 26) | (i64::from(fires_at.minute()) << 20);
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                status,
+                start_date: Some(start_date),
+                reminder_time: Some(reminder_time),
+                created: now.timestamp() as f64,
+                modified: now.timestamp() as f64,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let reminders = db
+        .get_tasks_with_reminders(chrono::Duration::hours(3))
+        .await
+        .unwrap();
+    let titles: Vec<&str> = reminders.iter().map(|(t, _)| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["Soon", "Later"]);
+    for (_, fires_at) in &reminders {
+        assert!(*fires_at <= now + chrono::Duration::hours(3));
+        assert_eq!(fires_at.second(), 0);
+    }
+}
+
+#[tokio::test]
+async fn test_get_tasks_with_urls() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+
+    let now = Utc::now().timestamp() as f64;
+    // (title, notes, trashed)
+    for (title, notes, trashed) in [
+        (
+            "Reading",
+            "See https://example.com/a, and [docs](HTTP://docs.rs/x?y=1).",
+            false,
+        ),
+        (
+            "Wiki",
+            "(https://en.wikipedia.org/wiki/Rust_(language))",
+            false,
+        ),
+        ("No links", "Mentions http but no URL: https://", false),
+        ("Trashed", "https://trashed.example", true),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                notes: Some(notes),
+                created: now,
+                modified: now,
+                trashed,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let tasks = db.get_tasks_with_urls().await.unwrap();
+    let urls_of = |title: &str| {
+        tasks
+            .iter()
+            .find(|(t, _)| t.title == title)
+            .map(|(_, urls)| urls.clone())
+    };
+    assert_eq!(
+        urls_of("Reading"),
+        Some(vec![
+            "https://example.com/a".to_string(),
+            "HTTP://docs.rs/x?y=1".to_string()
+        ])
+    );
+    assert_eq!(
+        urls_of("Wiki"),
+        Some(vec![
+            "https://en.wikipedia.org/wiki/Rust_(language)".to_string()
+        ])
+    );
+    assert_eq!(urls_of("No links"), None);
+    assert_eq!(urls_of("Trashed"), None);
+}
+
+#[tokio::test]
+async fn test_get_in_progress_tasks() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let now = Utc::now().timestamp() as f64;
+    let days_ago = |days: f64| now - days * 86_400.0;
+    // (title, created, modified, status)
+    for (title, created, modified, status) in [
+        ("Revisited", days_ago(30.0), days_ago(1.0), 0),
+        ("Revisited earlier", days_ago(30.0), days_ago(3.0), 0),
+        ("New", days_ago(2.0), days_ago(1.0), 0),
+        ("Untouched", days_ago(30.0), days_ago(20.0), 0),
+        ("Finished", days_ago(30.0), days_ago(1.0), 3),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                status,
+                created,
+                modified,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let tasks = db.get_in_progress_tasks(7).await.unwrap();
+    let titles: Vec<_> = tasks.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["Revisited", "Revisited earlier"]);
+
+    // Nothing was created before the start of time
+    assert!(db.get_in_progress_tasks(u32::MAX).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_get_stale_today_tasks() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let now = Utc::now().timestamp() as f64;
+    // (title, days since modified, status, todayIndex)
+    for (title, age, status, today_index) in [
+        ("Stale", 21.0, 0, 1),
+        ("Older", 30.0, 0, 2),
+        ("Fresh", 1.0, 0, 3),
+        ("Done", 21.0, 3, 4),
+        ("Not today", 21.0, 0, 0),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                status,
+                created: now,
+                modified: now - age * 86_400.0,
+                today_index: Some(today_index),
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let stale = db.get_stale_today_tasks(14).await.unwrap();
+    let titles: Vec<_> = stale.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["Older", "Stale"]);
+
+    assert!(db.get_stale_today_tasks(60).await.unwrap().is_empty());
+    assert!(db.get_stale_today_tasks(u32::MAX).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_get_all_tasks_reads_stop_date() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let now = Utc::now().timestamp() as f64;
+    let stopped = now - 3_600.0;
+    for (title, status, stop_date) in [("Done", 3, Some(stopped)), ("Open", 0, None)] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                status,
+                stop_date,
+                created: now,
+                modified: now,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let expected = chrono::DateTime::from_timestamp(stopped as i64, 0);
+    let all = db.get_all_tasks().await.unwrap();
+    assert_eq!(all.len(), 2);
+    let done = all.iter().find(|t| t.title == "Done").unwrap();
+    assert_eq!(done.stop_date, expected);
+    let open = all.iter().find(|t| t.title == "Open").unwrap();
+    assert_eq!(open.stop_date, None);
+
+    let completed = db.get_tasks_by_status(TaskStatus::Completed).await.unwrap();
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].stop_date, expected);
+
+    let found = db.search_tasks("Done").await.unwrap();
+    assert_eq!(found[0].stop_date, expected);
+}
+
+#[tokio::test]
+async fn test_get_tasks_by_status_separates_trashed() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    for (title, status, trashed) in [
+        ("Open", 0, false),
+        ("Done", 3, false),
+        ("Trashed done", 3, true),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                status,
+                trashed,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let trashed = db.get_tasks_by_status(TaskStatus::Trashed).await.unwrap();
+    assert_eq!(trashed.len(), 1);
+    assert_eq!(trashed[0].title, "Trashed done");
+
+    let completed = db.get_tasks_by_status(TaskStatus::Completed).await.unwrap();
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].title, "Done");
+
+    let open = db
+        .get_tasks_by_status(TaskStatus::Incomplete)
+        .await
+        .unwrap();
+    assert_eq!(open.len(), 1);
+    assert_eq!(open[0].title, "Open");
+}
+
+#[tokio::test]
+async fn test_get_all_tasks_excluding_trashed() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let now = Utc::now().timestamp() as f64;
+    for (title, task_type, status, trashed) in [
+        ("Open", 0, 0, false),
+        ("Canceled", 0, 2, false),
+        ("Heading", 2, 0, false),
+        ("Project", 1, 0, false),
+        ("Trashed", 0, 0, true),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                task_type,
+                status,
+                created: now,
+                modified: now,
+                trashed,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let tasks = db.get_all_tasks_excluding_trashed().await.unwrap();
+    let mut titles: Vec<_> = tasks.iter().map(|t| t.title.as_str()).collect();
+    titles.sort_unstable();
+    assert_eq!(titles, vec!["Canceled", "Heading", "Open"]);
+}
+
+#[tokio::test]
+async fn test_get_evening_tasks() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let now = Utc::now().timestamp() as f64;
+    // (title, todayIndex, startBucket)
+    for (title, today_index, start_bucket) in [
+        ("Morning run", 1, 0),
+        ("Read", 3, 1),
+        ("Call family", 2, 1),
+        ("Not today", 0, 1),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                created: now,
+                modified: now,
+                today_index: Some(today_index),
+                start_bucket: Some(start_bucket),
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let evening = db.get_evening_tasks().await.unwrap();
+    let titles: Vec<_> = evening.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["Call family", "Read"]);
+    assert!(evening.iter().all(|t| t.evening));
+
+    let today = db.get_today(None).await.unwrap();
+    let flags: Vec<_> = today
+        .iter()
+        .map(|t| (t.title.as_str(), t.evening))
+        .collect();
+    assert_eq!(
+        flags,
+        vec![
+            ("Morning run", false),
+            ("Call family", true),
+            ("Read", true)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_get_someday_and_anytime() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let project = Uuid::new_v4().to_string();
+    // (title, type, start, startDate, todayIndex, project, status, created)
+    for (title, kind, start, start_date, today_index, proj, status, created) in [
+        ("Project", 1, 1, None, 0, None, 0, 0.0),
+        ("Anytime old", 0, 1, None, 0, None, 0, 1.0),
+        ("Anytime new", 0, 1, None, 0, None, 0, 2.0),
+        ("Anytime in project", 0, 1, None, 0, Some(&project), 0, 3.0),
+        ("Today in project", 0, 1, None, 5, Some(&project), 0, 4.0),
+        ("Anytime done", 0, 1, None, 0, None, 3, 5.0),
+        ("Someday", 0, 2, None, 0, None, 0, 6.0),
+        (
+            "Someday scheduled",
+            0,
+            2,
+            Some(900_000_000),
+            0,
+            None,
+            0,
+            7.0,
+        ),
+        ("Inbox", 0, 0, None, 0, None, 0, 8.0),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                uuid: (title == "Project").then_some(project.as_str()),
+                title,
+                task_type: kind,
+                status,
+                start: Some(start),
+                start_date,
+                today_index: Some(today_index),
+                project: proj.map(String::as_str),
+                created,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let titles =
+        |tasks: Vec<things3_core::Task>| tasks.into_iter().map(|t| t.title).collect::<Vec<_>>();
+    assert_eq!(
+        titles(db.get_anytime(None).await.unwrap()),
+        vec!["Anytime in project", "Anytime new", "Anytime old"]
+    );
+    assert_eq!(
+        titles(db.get_anytime(Some(1)).await.unwrap()),
+        vec!["Anytime in project"]
+    );
+    assert_eq!(titles(db.get_someday(None).await.unwrap()), vec!["Someday"]);
+
+    // The project's Today to-do shows up in Today only
+    assert_eq!(
+        titles(db.get_today(None).await.unwrap()),
+        vec!["Today in project"]
+    );
+}
+
+#[tokio::test]
+async fn test_get_upcoming() {
+    use things3_core::database::naive_date_to_things_timestamp;
+
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap()
+        .with_timezone(chrono_tz::UTC);
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let today = Utc::now().date_naive();
+    let day = |offset: i64| naive_date_to_things_timestamp(today + chrono::Duration::days(offset));
+    // (title, startDate, status, trashed)
+    for (title, start_date, status, trashed) in [
+        ("In a month", Some(day(30)), 0, false),
+        ("Tomorrow", Some(day(1)), 0, false),
+        ("Next week", Some(day(7)), 0, false),
+        ("Today", Some(day(0)), 0, false),
+        ("Started", Some(day(-2)), 0, false),
+        ("Unscheduled", None, 0, false),
+        ("Done early", Some(day(2)), 3, false),
+        ("Trashed", Some(day(2)), 0, true),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                status,
+                start_date,
+                trashed,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let titles =
+        |tasks: Vec<things3_core::Task>| tasks.into_iter().map(|t| t.title).collect::<Vec<_>>();
+    assert!(db.get_upcoming(Some(0), None).await.unwrap().is_empty());
+    assert_eq!(
+        titles(db.get_upcoming(Some(7), None).await.unwrap()),
+        vec!["Tomorrow", "Next week"]
+    );
+    assert_eq!(
+        titles(db.get_upcoming(None, None).await.unwrap()),
+        vec!["Tomorrow", "Next week", "In a month"]
+    );
+    assert_eq!(
+        titles(db.get_upcoming(Some(u32::MAX), None).await.unwrap()),
+        vec!["Tomorrow", "Next week", "In a month"]
+    );
+    assert_eq!(
+        titles(db.get_upcoming(None, Some(1)).await.unwrap()),
+        vec!["Tomorrow"]
+    );
+}
+
+#[tokio::test]
+async fn test_get_engage_list() {
+    use things3_core::database::naive_date_to_things_timestamp;
+
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap()
+        .with_timezone(chrono_tz::UTC);
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let today = Utc::now().date_naive();
+    let day = |offset: i64| naive_date_to_things_timestamp(today + chrono::Duration::days(offset));
+    let now = Utc::now().timestamp() as f64;
+    // (title, age in days, start, startDate, deadline, todayIndex)
+    for (title, age, start, start_date, deadline, today_index) in [
+        ("Plain old", 9.0, 1, None, None, 0),
+        ("Started", 8.0, 1, Some(day(-3)), None, 0),
+        ("Pinned", 7.0, 1, None, None, 1),
+        ("Due today", 6.0, 1, None, Some(day(0)), 0),
+        ("Overdue", 5.0, 1, None, Some(day(-1)), 0),
+        ("Plain new", 1.0, 1, None, None, 0),
+        ("Someday", 10.0, 2, None, Some(day(-5)), 0),
+        ("Future", 10.0, 1, Some(day(4)), None, 0),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                start: Some(start),
+                start_date,
+                deadline,
+                today_index: Some(today_index),
+                created: now - age * 86_400.0,
+                modified: now,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let titles =
+        |tasks: Vec<things3_core::Task>| tasks.into_iter().map(|t| t.title).collect::<Vec<_>>();
+    assert_eq!(
+        titles(db.get_engage_list(10).await.unwrap()),
+        vec![
+            "Overdue",
+            "Due today",
+            "Pinned",
+            "Started",
+            "Plain old",
+            "Plain new"
+        ]
+    );
+    assert_eq!(
+        titles(db.get_engage_list(2).await.unwrap()),
+        vec!["Overdue", "Due today"]
+    );
+}
+
+#[tokio::test]
+async fn test_get_stale_tasks() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let now = Utc::now().timestamp() as f64;
+    // (title, days since modified, type, status, trashed)
+    for (title, age, task_type, status, trashed) in [
+        ("Stale", 100.0, 0, 0, false),
+        ("Stalest", 200.0, 0, 0, false),
+        ("Fresh", 5.0, 0, 0, false),
+        ("Completed", 200.0, 0, 3, false),
+        ("Trashed", 200.0, 0, 0, true),
+        ("Old project", 200.0, 1, 0, false),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                task_type,
+                status,
+                created: now,
+                modified: now - age * 86_400.0,
+                trashed,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let stale = db.get_stale_tasks(Duration::days(90)).await.unwrap();
+    let titles: Vec<_> = stale.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["Stalest", "Stale"]);
 
-    // Should have tasks for today
-    assert!(!today.is_empty());
+    assert!(db
+        .get_stale_tasks(Duration::days(365))
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(db.get_stale_tasks(Duration::MAX).await.unwrap().is_empty());
+    assert_eq!(db.get_stale_tasks(Duration::MIN).await.unwrap().len(), 3);
 }
 
 #[tokio::test]
-async fn test_get_projects() {
+async fn test_get_inbox_age_stats() {
     let db = ThingsDatabase::from_connection_string("sqlite::memory:")
         .await
         .unwrap();
 
-    // Create schema and insert test data
     create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
 
-    let projects = db.get_projects(None).await.unwrap();
-
-    // Should have projects from test data
-    assert!(!projects.is_empty());
+    let empty = db.get_inbox_age_stats().await.unwrap();
+    assert_eq!(empty.count, 0);
+    assert!(empty.avg_age_days == 0.0 && empty.max_age_days == 0.0);
+
+    let now = Utc::now().timestamp() as f64;
+    // (age in days, project) - the project task is not in the inbox
+    for (age, project) in [(10.0, None), (2.0, None), (30.0, Some("p"))] {
+        insert_task(
+            &db,
+            TaskRow {
+                title: "Aged",
+                project,
+                created: now - age * 86_400.0,
+                modified: now,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
 
-    // Verify project properties
-    let first_project = &projects[0];
-    assert_eq!(first_project.title, "Website Redesign");
-    assert_eq!(first_project.status, TaskStatus::Incomplete);
+    let stats = db.get_inbox_age_stats().await.unwrap();
+    assert_eq!(stats.count, 2);
+    assert!((stats.avg_age_days - 6.0).abs() < 0.01);
+    assert!((stats.max_age_days - 10.0).abs() < 0.01);
 }
 
 #[tokio::test]
-async fn test_get_areas() {
+async fn test_get_tasks_by_assignee() {
     let db = ThingsDatabase::from_connection_string("sqlite::memory:")
         .await
         .unwrap();
 
-    // Create schema and insert test data
     create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
 
-    let areas = db.get_areas().await.unwrap();
+    let now = Utc::now().timestamp() as f64;
+    // (title, contact, trashed, created offset)
+    for (title, contact, trashed, offset) in [
+        ("Older for Ann", Some("ann"), false, 2.0),
+        ("Newer for Ann", Some("ann"), false, 1.0),
+        ("For Bob", Some("bob"), false, 1.0),
+        ("Trashed for Ann", Some("ann"), true, 1.0),
+        ("Unassigned", None, false, 1.0),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                contact,
+                created: now - offset,
+                modified: now,
+                trashed,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
 
-    // Should have areas from test data
-    assert!(!areas.is_empty());
+    let tasks = db.get_tasks_by_assignee("ann").await.unwrap();
+    let titles: Vec<_> = tasks.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["Newer for Ann", "Older for Ann"]);
+    assert!(tasks.iter().all(|t| t.assignee.as_deref() == Some("ann")));
 
-    // Verify area properties
-    let first_area = &areas[0];
-    assert_eq!(first_area.title, "Work");
+    let inbox = db.get_inbox(None).await.unwrap();
+    let unassigned = inbox.iter().find(|t| t.title == "Unassigned").unwrap();
+    assert_eq!(unassigned.assignee, None);
+    assert!(db.get_tasks_by_assignee("nobody").await.unwrap().is_empty());
 }
 
 #[tokio::test]
-async fn test_search_tasks() {
+async fn test_raw_column() {
     let db = ThingsDatabase::from_connection_string("sqlite::memory:")
         .await
         .unwrap();
 
-    // Create schema and insert test data
     create_test_schema(&db).await.unwrap();
 
-    let results = db.search_tasks("competitors").await.unwrap();
+    let now = Utc::now().timestamp() as f64;
+    let uuid = insert_task(
+        &db,
+        TaskRow {
+            title: "Raw",
+            notes: Some("Some notes"),
+            reminder_time: Some(42),
+            created: now,
+            modified: now,
+            ..TaskRow::default()
+        },
+    )
+    .await;
 
-    // Should find tasks containing "competitors"
-    assert!(!results.is_empty());
+    let id: ThingsId = uuid.parse().unwrap();
+    assert_eq!(
+        db.raw_column(&id, "notes").await.unwrap(),
+        Some("Some notes".to_string())
+    );
+    assert_eq!(
+        db.raw_column(&id, "REMINDERTIME").await.unwrap(),
+        Some("42".to_string())
+    );
+    assert_eq!(db.raw_column(&id, "deadline").await.unwrap(), None);
 
-    // Verify search results contain the search term
-    let found_task = results.iter().find(|t| t.title.contains("competitors"));
-    assert!(found_task.is_some());
+    // Unknown and injected names are rejected before any SQL is built
+    assert!(db.raw_column(&id, "nope").await.is_err());
+    assert!(db.raw_column(&id, "title\" FROM TMTask; --").await.is_err());
+
+    assert!(db.raw_column(&ThingsId::new_v4(), "notes").await.is_err());
 }
 
 #[tokio::test]
@@ -535,6 +1601,7 @@ async fn test_database_helper_functions_indirectly() {
                 | things3_core::models::TaskStatus::Completed
                 | things3_core::models::TaskStatus::Canceled
                 | things3_core::models::TaskStatus::Trashed
+                | things3_core::models::TaskStatus::Unknown(_)
         ));
     }
 
@@ -659,9 +1726,13 @@ async fn create_minimal_task_schema(pool: &sqlx::SqlitePool) {
             project TEXT,
             area TEXT,
             heading TEXT,
+            contact TEXT,
             trashed INTEGER NOT NULL DEFAULT 0,
             cachedTags BLOB,
-            todayIndex INTEGER
+            todayIndex INTEGER,
+            start INTEGER,
+            startBucket INTEGER,
+            'index' INTEGER
         )
         ",
     )
@@ -1089,3 +2160,891 @@ async fn test_get_inbox_includes_headings_without_project() {
     );
     assert_eq!(heading.unwrap().task_type, TaskType::Heading);
 }
+
+#[tokio::test]
+async fn test_find_exact_duplicate_inbox_tasks() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let project = Uuid::new_v4().to_string();
+    for (i, (title, project)) in [
+        ("Buy milk", None),
+        ("  buy MILK ", None),
+        ("Call mom", None),
+        ("Buy milk", Some(project.as_str())),
+        ("Call mom", None),
+        ("Unique", None),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                project,
+                created: 1_700_000_000.0 + i as f64,
+                modified: 1_700_000_000.0 + i as f64,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let groups = db.find_exact_duplicate_inbox_tasks().await.unwrap();
+    let titles: Vec<Vec<_>> = groups
+        .iter()
+        .map(|group| group.iter().map(|t| t.title.as_str()).collect())
+        .collect();
+    assert_eq!(
+        titles,
+        vec![
+            vec!["Buy milk", "  buy MILK "],
+            vec!["Call mom", "Call mom"]
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_get_tasks_with_large_notes() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let big = Uuid::new_v4().to_string();
+    let multibyte = Uuid::new_v4().to_string();
+    for (uuid, notes) in [
+        (big.clone(), Some("x".repeat(5_000))),
+        // 1,000 characters but 3,000 UTF-8 bytes
+        (multibyte.clone(), Some("€".repeat(1_000))),
+        (Uuid::new_v4().to_string(), Some("short".to_string())),
+        (Uuid::new_v4().to_string(), None),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                uuid: Some(&uuid),
+                title: "Task",
+                notes: notes.as_deref(),
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let large = db.get_tasks_with_large_notes(2_000).await.unwrap();
+    let sizes: Vec<_> = large.iter().map(|(id, n)| (id.as_str(), *n)).collect();
+    assert_eq!(
+        sizes,
+        vec![(big.as_str(), 5_000), (multibyte.as_str(), 3_000)]
+    );
+
+    assert_eq!(db.get_tasks_with_large_notes(0).await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_get_creation_buckets() {
+    use chrono::Duration;
+    use things3_core::{BucketSize, Tz};
+
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap()
+        .with_timezone(Tz::UTC);
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let now = Utc::now();
+    // (days ago created, trashed)
+    for (days_ago, trashed) in [(0, false), (1, false), (1, false), (5, false), (0, true)] {
+        insert_task(
+            &db,
+            TaskRow {
+                title: "Task",
+                created: (now - Duration::days(days_ago)).timestamp() as f64,
+                modified: now.timestamp() as f64,
+                trashed,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let today = now.date_naive();
+    let days = db.get_creation_buckets(BucketSize::Day, 3).await.unwrap();
+    assert_eq!(
+        days,
+        vec![
+            (today - Duration::days(2), 0),
+            (today - Duration::days(1), 2),
+            (today, 1),
+        ]
+    );
+
+    let weeks = db.get_creation_buckets(BucketSize::Week, 2).await.unwrap();
+    assert_eq!(weeks.len(), 2);
+    assert_eq!(weeks[1].0, BucketSize::Week.start_of(today));
+    assert_eq!(weeks.iter().map(|(_, n)| n).sum::<u32>(), 4);
+
+    assert!(db
+        .get_creation_buckets(BucketSize::Month, 0)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let clamped = db
+        .get_creation_buckets(BucketSize::Day, u32::MAX)
+        .await
+        .unwrap();
+    assert_eq!(clamped.len(), ThingsDatabase::MAX_BUCKETS as usize);
+    assert_eq!(clamped.last().unwrap().0, today);
+}
+
+#[tokio::test]
+async fn test_get_task_detail_resolves_parents() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let area = Uuid::new_v4().to_string();
+    let project = Uuid::new_v4().to_string();
+    let heading = Uuid::new_v4().to_string();
+    let task = Uuid::new_v4().to_string();
+    let tag = Uuid::new_v4().to_string();
+    sqlx::query("INSERT INTO TMArea (uuid, title) VALUES (?, 'Work')")
+        .bind(&area)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO TMTag (uuid, title) VALUES (?, 'urgent')")
+        .bind(&tag)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO TMTaskTag (tasks, tags) VALUES (?, ?)")
+        .bind(&task)
+        .bind(&tag)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    // (uuid, title, type, project, area, heading)
+    for (uuid, title, kind, proj, area_uuid, head) in [
+        (&project, "Launch", 1, None, Some(&area), None),
+        (&heading, "Prep", 2, Some(&project), None, None),
+        (&task, "Write brief", 0, None, None, Some(&heading)),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                uuid: Some(uuid),
+                title,
+                task_type: kind,
+                project: proj.map(String::as_str),
+                area: area_uuid.map(String::as_str),
+                heading: head.map(String::as_str),
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let id: ThingsId = task.parse().unwrap();
+    let detail = db.get_task_detail(&id).await.unwrap();
+    assert_eq!(detail.task.title, "Write brief");
+    assert_eq!(detail.task.tags, vec!["urgent".to_string()]);
+    assert_eq!(detail.heading_title.as_deref(), Some("Prep"));
+    assert_eq!(detail.project_title.as_deref(), Some("Launch"));
+    assert_eq!(detail.area_title.as_deref(), Some("Work"));
+
+    let missing = ThingsId::new_v4();
+    assert!(matches!(
+        db.get_task_detail(&missing).await,
+        Err(things3_core::ThingsError::TaskNotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_get_effective_area() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let home = Uuid::new_v4().to_string();
+    let work = Uuid::new_v4().to_string();
+    let project = Uuid::new_v4().to_string();
+    let heading = Uuid::new_v4().to_string();
+    let in_project = Uuid::new_v4().to_string();
+    let under_heading = Uuid::new_v4().to_string();
+    let own_area = Uuid::new_v4().to_string();
+    let loose = Uuid::new_v4().to_string();
+    // (uuid, type, project, area, heading)
+    for (uuid, kind, proj, area_uuid, head) in [
+        (&project, 1, None, Some(&work), None),
+        (&heading, 2, Some(&project), None, None),
+        (&in_project, 0, Some(&project), None, None),
+        (&under_heading, 0, None, None, Some(&heading)),
+        (&own_area, 0, Some(&project), Some(&home), None),
+        (&loose, 0, None, None, None),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                uuid: Some(uuid),
+                title: "Task",
+                task_type: kind,
+                project: proj.map(String::as_str),
+                area: area_uuid.map(String::as_str),
+                heading: head.map(String::as_str),
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let area_of = |uuid: &String| {
+        let id: ThingsId = uuid.parse().unwrap();
+        let db = &db;
+        async move {
+            db.get_effective_area(&id)
+                .await
+                .unwrap()
+                .map(|a| a.to_string())
+        }
+    };
+    assert_eq!(area_of(&in_project).await, Some(work.clone()));
+    assert_eq!(area_of(&under_heading).await, Some(work.clone()));
+    assert_eq!(area_of(&own_area).await, Some(home));
+    assert_eq!(area_of(&loose).await, None);
+
+    assert!(matches!(
+        db.get_effective_area(&ThingsId::new_v4()).await,
+        Err(things3_core::ThingsError::TaskNotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_get_task_siblings() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let area = Uuid::new_v4().to_string();
+    let project = Uuid::new_v4().to_string();
+    let heading = Uuid::new_v4().to_string();
+    // (title, type, status, project, area, heading, index)
+    let rows = [
+        ("Project", 1, 0, None, Some(&area), None, 0),
+        ("Heading", 2, 0, Some(&project), None, None, 0),
+        ("P2", 0, 0, Some(&project), None, None, 2),
+        ("P1", 0, 0, Some(&project), None, None, 1),
+        ("P done", 0, 3, Some(&project), None, None, 3),
+        ("H1", 0, 0, None, None, Some(&heading), 1),
+        ("A1", 0, 0, None, Some(&area), None, 1),
+        ("Loose", 0, 0, None, None, None, 1),
+    ];
+    let mut ids = std::collections::HashMap::new();
+    for (title, kind, status, proj, area_uuid, head, index) in rows {
+        let uuid = insert_task(
+            &db,
+            TaskRow {
+                uuid: match title {
+                    "Project" => Some(project.as_str()),
+                    "Heading" => Some(heading.as_str()),
+                    _ => None,
+                },
+                title,
+                task_type: kind,
+                status,
+                project: proj.map(String::as_str),
+                area: area_uuid.map(String::as_str),
+                heading: head.map(String::as_str),
+                index: Some(index),
+                ..TaskRow::default()
+            },
+        )
+        .await;
+        ids.insert(title, uuid.parse::<ThingsId>().unwrap());
+    }
+
+    let titles_for = |title: &str| {
+        let id = ids[title].clone();
+        let db = &db;
+        async move {
+            db.get_task_siblings(&id)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|t| t.title)
+                .collect::<Vec<_>>()
+        }
+    };
+    assert_eq!(titles_for("P2").await, vec!["P1", "P2"]);
+    assert_eq!(titles_for("P done").await, vec!["P1", "P2", "P done"]);
+    assert_eq!(titles_for("H1").await, vec!["H1"]);
+    assert_eq!(titles_for("A1").await, vec!["A1"]);
+    assert_eq!(titles_for("Loose").await, vec!["Loose"]);
+
+    assert!(matches!(
+        db.get_task_siblings(&ThingsId::new_v4()).await,
+        Err(things3_core::ThingsError::TaskNotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_get_all_headings() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let (first, second) = {
+        let mut ids = [Uuid::new_v4().to_string(), Uuid::new_v4().to_string()];
+        ids.sort();
+        let [a, b] = ids;
+        (a, b)
+    };
+    // (title, type, project, index, trashed)
+    for (title, kind, project, index, trashed) in [
+        ("Later", 2, Some(&first), 2, false),
+        ("Sooner", 2, Some(&first), 1, false),
+        ("Other", 2, Some(&second), 1, false),
+        ("Trashed", 2, Some(&second), 0, true),
+        ("No project", 2, None, 0, false),
+        ("A to-do", 0, Some(&first), 0, false),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                task_type: kind,
+                project: project.map(String::as_str),
+                index: Some(index),
+                trashed,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let headings = db.get_all_headings().await.unwrap();
+    let outline: Vec<_> = headings
+        .iter()
+        .map(|(heading, project)| (heading.title.as_str(), project.to_string()))
+        .collect();
+    assert_eq!(
+        outline,
+        vec![
+            ("Sooner", first.clone()),
+            ("Later", first),
+            ("Other", second),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_get_tasks_under_area() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    for table in ["TMTask", "TMArea"] {
+        sqlx::query(&format!("DELETE FROM {table}"))
+            .execute(db.pool())
+            .await
+            .unwrap();
+    }
+
+    let work = Uuid::new_v4().to_string();
+    let home = Uuid::new_v4().to_string();
+    for (uuid, title) in [(&work, "Work"), (&home, "Home")] {
+        sqlx::query("INSERT INTO TMArea (uuid, title, visible, 'index') VALUES (?, ?, 1, 0)")
+            .bind(uuid)
+            .bind(title)
+            .execute(db.pool())
+            .await
+            .unwrap();
+    }
+
+    let project = Uuid::new_v4().to_string();
+    let trashed_project = Uuid::new_v4().to_string();
+    let heading = Uuid::new_v4().to_string();
+    // (uuid, title, type, status, project, area, heading, created, trashed)
+    for (uuid, title, kind, status, proj, area_uuid, head, created, trashed) in [
+        (
+            project.clone(),
+            "Project",
+            1,
+            0,
+            None,
+            Some(&work),
+            None,
+            0.0,
+            0,
+        ),
+        (
+            trashed_project.clone(),
+            "Old project",
+            1,
+            0,
+            None,
+            Some(&work),
+            None,
+            0.0,
+            1,
+        ),
+        (
+            heading.clone(),
+            "Heading",
+            2,
+            0,
+            Some(&project),
+            None,
+            None,
+            0.0,
+            0,
+        ),
+        (
+            Uuid::new_v4().to_string(),
+            "In area",
+            0,
+            0,
+            None,
+            Some(&work),
+            None,
+            3.0,
+            0,
+        ),
+        (
+            Uuid::new_v4().to_string(),
+            "In project",
+            0,
+            3,
+            Some(&project),
+            None,
+            None,
+            1.0,
+            0,
+        ),
+        (
+            Uuid::new_v4().to_string(),
+            "Under heading",
+            0,
+            0,
+            None,
+            None,
+            Some(&heading),
+            2.0,
+            0,
+        ),
+        (
+            Uuid::new_v4().to_string(),
+            "In old project",
+            0,
+            0,
+            Some(&trashed_project),
+            None,
+            None,
+            4.0,
+            0,
+        ),
+        (
+            Uuid::new_v4().to_string(),
+            "Trashed",
+            0,
+            0,
+            None,
+            Some(&work),
+            None,
+            5.0,
+            1,
+        ),
+        (
+            Uuid::new_v4().to_string(),
+            "At home",
+            0,
+            0,
+            None,
+            Some(&home),
+            None,
+            6.0,
+            0,
+        ),
+        (
+            Uuid::new_v4().to_string(),
+            "Loose",
+            0,
+            0,
+            None,
+            None,
+            None,
+            7.0,
+            0,
+        ),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                uuid: Some(&uuid),
+                title,
+                task_type: kind,
+                status,
+                project: proj.map(String::as_str),
+                area: area_uuid.map(String::as_str),
+                heading: head.map(String::as_str),
+                created,
+                trashed: trashed == 1,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let titles_under = |area: &String| {
+        let id: ThingsId = area.parse().unwrap();
+        let db = &db;
+        async move {
+            db.get_tasks_under_area(&id)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|t| t.title)
+                .collect::<Vec<_>>()
+        }
+    };
+    assert_eq!(
+        titles_under(&work).await,
+        vec!["In project", "Under heading", "In area"]
+    );
+    assert_eq!(titles_under(&home).await, vec!["At home"]);
+    assert!(db.get_tasks_under_area(&ThingsId::new_v4()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_get_workload_by_area() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    for table in ["TMTask", "TMArea"] {
+        sqlx::query(&format!("DELETE FROM {table}"))
+            .execute(db.pool())
+            .await
+            .unwrap();
+    }
+
+    let home = Uuid::new_v4().to_string();
+    let work = Uuid::new_v4().to_string();
+    let idle = Uuid::new_v4().to_string();
+    for (index, (uuid, title)) in [(&work, "Work"), (&home, "Home"), (&idle, "Idle")]
+        .into_iter()
+        .enumerate()
+    {
+        sqlx::query("INSERT INTO TMArea (uuid, title, visible, 'index') VALUES (?, ?, 1, ?)")
+            .bind(uuid)
+            .bind(title)
+            .bind(i64::try_from(index).unwrap())
+            .execute(db.pool())
+            .await
+            .unwrap();
+    }
+
+    let project = Uuid::new_v4().to_string();
+    let heading = Uuid::new_v4().to_string();
+    // (uuid, type, status, project, area, heading, trashed)
+    for (uuid, kind, status, proj, area_uuid, head, trashed) in [
+        (project.clone(), 1, 0, None, Some(&work), None, 0),
+        (heading.clone(), 2, 0, Some(&project), None, None, 0),
+        (
+            Uuid::new_v4().to_string(),
+            0,
+            0,
+            Some(&project),
+            None,
+            None,
+            0,
+        ),
+        (
+            Uuid::new_v4().to_string(),
+            0,
+            0,
+            None,
+            None,
+            Some(&heading),
+            0,
+        ),
+        (
+            Uuid::new_v4().to_string(),
+            0,
+            0,
+            Some(&project),
+            Some(&home),
+            None,
+            0,
+        ),
+        (Uuid::new_v4().to_string(), 0, 0, None, Some(&home), None, 0),
+        (Uuid::new_v4().to_string(), 0, 3, None, Some(&home), None, 0),
+        (Uuid::new_v4().to_string(), 0, 0, None, Some(&idle), None, 1),
+        (Uuid::new_v4().to_string(), 0, 0, None, None, None, 0),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                uuid: Some(&uuid),
+                title: "Task",
+                task_type: kind,
+                status,
+                project: proj.map(String::as_str),
+                area: area_uuid.map(String::as_str),
+                heading: head.map(String::as_str),
+                trashed: trashed == 1,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let workload: Vec<_> = db
+        .get_workload_by_area()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(area, count)| (area.title, count))
+        .collect();
+    assert_eq!(
+        workload,
+        vec![
+            ("Work".to_string(), 2),
+            ("Home".to_string(), 2),
+            ("Idle".to_string(), 0),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_get_overdue_to_start() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap()
+        .with_timezone(chrono_tz::UTC);
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let epoch = chrono::NaiveDate::from_ymd_opt(2001, 1, 1).unwrap();
+    let today = Utc::now().date_naive();
+    let now = Utc::now().timestamp() as f64;
+    // (title, status, days from today, todayIndex)
+    for (title, status, days, today_index) in [
+        ("Two days late", 0, -2, 0),
+        ("A week late", 0, -7, 0),
+        ("Already in Today", 0, -1, 5),
+        ("Starts today", 0, 0, 0),
+        ("Finished", 3, -3, 0),
+    ] {
+        let start_date = (today + chrono::Duration::days(days) - epoch).num_days() * 86400;
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                status,
+                start_date: Some(start_date),
+                today_index: Some(today_index),
+                created: now,
+                modified: now,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let tasks = db.get_overdue_to_start().await.unwrap();
+    let titles: Vec<&str> = tasks.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["A week late", "Two days late"]);
+}
+
+#[tokio::test]
+async fn test_check_integrity() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    for table in ["TMTask", "TMArea", "TMTag"] {
+        sqlx::query(&format!("DELETE FROM {table}"))
+            .execute(db.pool())
+            .await
+            .unwrap();
+    }
+    assert!(db.check_integrity().await.unwrap().is_clean());
+
+    let now = Utc::now().timestamp() as f64;
+    sqlx::query("INSERT INTO TMArea (uuid, title) VALUES ('area-1', 'Home')")
+        .execute(db.pool())
+        .await
+        .unwrap();
+    // (uuid, type, project, area, heading, startDate, deadline)
+    for (uuid, task_type, project, area, heading, start_date, deadline) in [
+        ("project-1", 1, None, Some("area-1"), None, None, None),
+        ("heading-1", 2, Some("project-1"), None, None, None, None),
+        (
+            "valid",
+            0,
+            Some("project-1"),
+            None,
+            Some("heading-1"),
+            None,
+            None,
+        ),
+        ("bad-project", 0, Some("gone"), None, None, None, None),
+        ("bad-area", 1, None, Some("gone"), None, None, None),
+        ("bad-heading", 0, None, None, Some("gone"), None, None),
+        (
+            "bad-dates",
+            0,
+            None,
+            None,
+            None,
+            Some(7_000_000),
+            Some(6_000_000),
+        ),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                uuid: Some(uuid),
+                title: uuid,
+                task_type,
+                project,
+                area,
+                heading,
+                start_date,
+                deadline,
+                created: now,
+                modified: now,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+    for (uuid, parent) in [
+        ("tag-1", None),
+        ("tag-2", Some("tag-1")),
+        ("tag-3", Some("gone")),
+    ] {
+        sqlx::query("INSERT INTO TMTag (uuid, title, parent) VALUES (?, ?, ?)")
+            .bind(uuid)
+            .bind(uuid)
+            .bind(parent)
+            .execute(db.pool())
+            .await
+            .unwrap();
+    }
+
+    let report = db.check_integrity().await.unwrap();
+    let ids = |ids: &[ThingsId]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+    assert_eq!(ids(&report.orphaned_tasks), vec!["bad-area", "bad-project"]);
+    assert_eq!(ids(&report.missing_headings), vec!["bad-heading"]);
+    assert_eq!(ids(&report.orphaned_tags), vec!["tag-3"]);
+    assert_eq!(ids(&report.invalid_date_ranges), vec!["bad-dates"]);
+    assert!(!report.is_clean());
+}
+
+#[tokio::test]
+async fn test_find_invalid_date_ranges() {
+    let db = ThingsDatabase::from_connection_string("sqlite::memory:")
+        .await
+        .unwrap();
+
+    create_test_schema(&db).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let now = Utc::now().timestamp() as f64;
+    // (title, type, trashed, startDate, deadline)
+    for (title, task_type, trashed, start_date, deadline) in [
+        (
+            "Due before start",
+            0,
+            false,
+            Some(7_000_000),
+            Some(6_900_000),
+        ),
+        (
+            "Project due before start",
+            1,
+            false,
+            Some(8_000_000),
+            Some(7_000_000),
+        ),
+        ("Valid range", 0, false, Some(6_000_000), Some(6_500_000)),
+        ("Same day", 0, false, Some(6_000_000), Some(6_000_000)),
+        ("Only a deadline", 0, false, None, Some(1_000)),
+        ("Trashed", 0, true, Some(9_000_000), Some(1_000)),
+    ] {
+        insert_task(
+            &db,
+            TaskRow {
+                title,
+                task_type,
+                start_date,
+                deadline,
+                created: now,
+                modified: now,
+                trashed,
+                ..TaskRow::default()
+            },
+        )
+        .await;
+    }
+
+    let tasks = db.find_invalid_date_ranges().await.unwrap();
+    let titles: Vec<&str> = tasks.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["Due before start", "Project due before start"]);
+}
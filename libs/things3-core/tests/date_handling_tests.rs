@@ -254,21 +254,33 @@ fn test_date_arithmetic_overflow() {
 
 #[test]
 fn test_date_comparison_edge_cases() {
-    let today = chrono::Utc::now().date_naive();
+    let tz = things3_core::database::system_timezone();
+    let today = things3_core::database::today_in(tz);
 
     // Past date
     let yesterday = today - chrono::Duration::days(1);
-    assert!(is_date_in_past(yesterday));
-    assert!(!is_date_in_future(yesterday));
+    assert!(is_date_in_past(yesterday, tz));
+    assert!(!is_date_in_future(yesterday, tz));
 
     // Future date
     let tomorrow = today + chrono::Duration::days(1);
-    assert!(is_date_in_future(tomorrow));
-    assert!(!is_date_in_past(tomorrow));
+    assert!(is_date_in_future(tomorrow, tz));
+    assert!(!is_date_in_past(tomorrow, tz));
 
     // Today should be neither past nor future
-    assert!(!is_date_in_past(today));
-    assert!(!is_date_in_future(today));
+    assert!(!is_date_in_past(today, tz));
+    assert!(!is_date_in_future(today, tz));
+}
+
+#[test]
+fn test_date_comparison_uses_given_timezone() {
+    // Kiribati (UTC+14) and Baker Island (UTC-12) are always on different dates
+    let ahead: things3_core::Tz = "Pacific/Kiritimati".parse().unwrap();
+    let behind: things3_core::Tz = "Etc/GMT+12".parse().unwrap();
+    let today_ahead = things3_core::database::today_in(ahead);
+
+    assert!(is_date_in_future(today_ahead, behind));
+    assert!(!is_date_in_future(today_ahead, ahead));
 }
 
 // ================================
@@ -312,13 +324,13 @@ async fn test_update_task_deadline_before_start() {
     let update_request = things3_core::models::UpdateTaskRequest {
         uuid: task_uuid,
         title: None,
-        notes: None,
-        start_date: Some(start),
-        deadline: Some(deadline),
+        notes: things3_core::models::FieldUpdate::Unchanged,
+        start_date: things3_core::models::FieldUpdate::Set(start),
+        deadline: things3_core::models::FieldUpdate::Set(deadline),
         status: None,
         tags: None,
-        project_uuid: None,
-        area_uuid: None,
+        project_uuid: things3_core::models::FieldUpdate::Unchanged,
+        area_uuid: things3_core::models::FieldUpdate::Unchanged,
     };
 
     // This should fail validation
@@ -376,13 +388,13 @@ async fn test_update_task_dates_successfully() {
     let update_request = things3_core::models::UpdateTaskRequest {
         uuid: task_uuid.clone(),
         title: None,
-        notes: None,
-        start_date: Some(start),
-        deadline: Some(deadline),
+        notes: things3_core::models::FieldUpdate::Unchanged,
+        start_date: things3_core::models::FieldUpdate::Set(start),
+        deadline: things3_core::models::FieldUpdate::Set(deadline),
         status: None,
         tags: None,
-        project_uuid: None,
-        area_uuid: None,
+        project_uuid: things3_core::models::FieldUpdate::Unchanged,
+        area_uuid: things3_core::models::FieldUpdate::Unchanged,
     };
 
     // This should succeed
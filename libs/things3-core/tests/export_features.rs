@@ -79,6 +79,7 @@ fn test_export_config_customization() {
         include_tags: false,
         date_format: "%Y-%m-%d".to_string(),
         timezone: "America/New_York".to_string(),
+        pretty: false,
     };
 
     assert!(!config.include_metadata);
@@ -86,6 +87,7 @@ fn test_export_config_customization() {
     assert!(!config.include_tags);
     assert_eq!(config.date_format, "%Y-%m-%d");
     assert_eq!(config.timezone, "America/New_York");
+    assert!(!config.pretty);
 }
 
 #[test]
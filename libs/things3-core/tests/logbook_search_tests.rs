@@ -2,7 +2,7 @@
 
 use chrono::Utc;
 use things3_core::{
-    database::ThingsDatabase,
+    database::{start_of_day_in, today_in, ThingsDatabase},
     models::CreateTaskRequest,
     test_utils::{create_test_database_and_connect, TaskRequestBuilder},
     ThingsId,
@@ -766,6 +766,84 @@ async fn test_search_logbook_limit() {
     );
 }
 
+#[tokio::test]
+async fn test_search_logbook_limit_above_500() {
+    let (db, _temp_file) = create_test_database_and_connect()
+        .await
+        .expect("Failed to create database");
+
+    // Bulk-insert 600 completed to-dos directly; the API path would be slow
+    let now = Utc::now().timestamp() as f64;
+    sqlx::query(
+        "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 600)
+         INSERT INTO TMTask (uuid, title, type, status, stopDate, creationDate, userModificationDate, trashed)
+         SELECT 'bulk-' || i, 'Bulk ' || i, 0, 3, ?, ?, ?, 0 FROM n",
+    )
+    .bind(now)
+    .bind(now)
+    .bind(now)
+    .execute(db.pool())
+    .await
+    .expect("Failed to insert tasks");
+
+    let results = db
+        .search_logbook(None, None, None, None, None, None, Some(600), None)
+        .await
+        .expect("Failed to search logbook");
+    assert!(results.len() >= 600, "Limits above 500 should be honored");
+
+    let capped = db
+        .search_logbook(None, None, None, None, None, None, Some(u32::MAX), None)
+        .await
+        .expect("Failed to search logbook");
+    assert!(capped.len() <= ThingsDatabase::MAX_QUERY_LIMIT as usize);
+}
+
+#[tokio::test]
+async fn test_search_logbook_pages_are_stable_on_tied_stop_dates() {
+    let (db, _temp_file) = create_test_database_and_connect()
+        .await
+        .expect("Failed to create database");
+
+    // Every row shares one stopDate, so only the uuid tie-breaker orders them
+    let now = Utc::now().timestamp() as f64;
+    sqlx::query(
+        "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 30)
+         INSERT INTO TMTask (uuid, title, type, status, stopDate, creationDate, userModificationDate, trashed)
+         SELECT 'tie-' || (i * 7919 % 30), 'Tie ' || i, 0, 3, ?, ?, ?, 0 FROM n",
+    )
+    .bind(now)
+    .bind(now)
+    .bind(now)
+    .execute(db.pool())
+    .await
+    .expect("Failed to insert tasks");
+
+    let mut paged = Vec::new();
+    for offset in (0..30).step_by(7) {
+        let page = db
+            .search_logbook(
+                Some("Tie ".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(7),
+                Some(offset),
+            )
+            .await
+            .expect("Failed to search logbook");
+        paged.extend(page.into_iter().map(|task| task.uuid.to_string()));
+    }
+
+    let mut expected = paged.clone();
+    expected.sort();
+    expected.dedup();
+    assert_eq!(expected.len(), 30, "Pages should not skip or repeat rows");
+    assert_eq!(paged, expected, "Ties should be ordered by uuid");
+}
+
 #[tokio::test]
 async fn test_search_logbook_empty_results() {
     let (db, _temp_file) = create_test_database_and_connect()
@@ -857,3 +935,108 @@ async fn test_search_logbook_excludes_trashed() {
         "Trashed task should not appear"
     );
 }
+
+/// Helper to create a completed task whose stopDate is noon `days_ago` days back in the
+/// database's timezone
+async fn create_completed_days_ago(db: &ThingsDatabase, days_ago: i64) {
+    let uuid = create_and_complete_task(
+        db,
+        TaskRequestBuilder::new()
+            .title(format!("Done {days_ago} day(s) ago"))
+            .build(),
+    )
+    .await;
+
+    let tz = db.timezone();
+    let day = today_in(tz) - chrono::Duration::days(days_ago);
+    let stop_date = (start_of_day_in(day, tz) + chrono::Duration::hours(12)).timestamp() as f64;
+
+    sqlx::query("UPDATE TMTask SET stopDate = ? WHERE uuid = ?")
+        .bind(stop_date)
+        .bind(uuid.as_str())
+        .execute(db.pool())
+        .await
+        .expect("Failed to set stopDate");
+}
+
+#[tokio::test]
+async fn test_completion_streak_stops_at_gap() {
+    let (db, _temp_file) = create_test_database_and_connect()
+        .await
+        .expect("Failed to create database");
+
+    for days_ago in [0, 0, 1, 2, 4] {
+        create_completed_days_ago(&db, days_ago).await;
+    }
+
+    let streak = db.get_completion_streak().await.unwrap();
+    assert_eq!(streak, 3);
+}
+
+#[tokio::test]
+async fn test_completion_streak_zero_without_completion_today() {
+    let (db, _temp_file) = create_test_database_and_connect()
+        .await
+        .expect("Failed to create database");
+
+    create_completed_days_ago(&db, 1).await;
+
+    let streak = db.get_completion_streak().await.unwrap();
+    assert_eq!(streak, 0);
+}
+
+#[tokio::test]
+async fn test_search_logbook_date_range_uses_configured_timezone() {
+    let (db, _temp_file) = create_test_database_and_connect()
+        .await
+        .expect("Failed to create database");
+
+    let uuid =
+        create_and_complete_task(&db, TaskRequestBuilder::new().title("Late evening").build())
+            .await;
+
+    // 03:00 UTC on Jan 16 is 19:00 on Jan 15 in Los Angeles
+    let stop_date = chrono::DateTime::parse_from_rfc3339("2026-01-16T03:00:00Z")
+        .unwrap()
+        .timestamp() as f64;
+    sqlx::query("UPDATE TMTask SET stopDate = ? WHERE uuid = ?")
+        .bind(stop_date)
+        .bind(uuid.as_str())
+        .execute(db.pool())
+        .await
+        .expect("Failed to set stopDate");
+
+    let jan_15 = chrono::NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+
+    let db = db.with_timezone("America/Los_Angeles".parse().unwrap());
+    let results = db
+        .search_logbook(
+            None,
+            Some(jan_15),
+            Some(jan_15),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to search logbook");
+    assert!(results.iter().any(|t| t.uuid == uuid));
+
+    let db = db.with_timezone(things3_core::Tz::UTC);
+    let results = db
+        .search_logbook(
+            None,
+            Some(jan_15),
+            Some(jan_15),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to search logbook");
+    assert!(!results.iter().any(|t| t.uuid == uuid));
+}
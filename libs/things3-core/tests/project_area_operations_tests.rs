@@ -71,6 +71,54 @@ async fn test_create_project_with_area() {
     assert_eq!(task.area_uuid, Some(area_uuid));
 }
 
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_set_project_on_hold_moves_it_to_someday() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let request = CreateProjectRequest {
+        title: "Parked".to_string(),
+        notes: None,
+        area_uuid: None,
+        start_date: None,
+        deadline: None,
+        tags: None,
+    };
+    let uuid = db.create_project(request).await.unwrap();
+    let active_uuids = |projects: Vec<things3_core::Project>| {
+        projects.into_iter().map(|p| p.uuid).collect::<Vec<_>>()
+    };
+
+    db.set_project_on_hold(&uuid, true).await.unwrap();
+    let start: i64 = sqlx::query_scalar("SELECT start FROM TMTask WHERE uuid = ?")
+        .bind(uuid.as_str())
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(start, 2);
+    let all = active_uuids(db.get_all_projects().await.unwrap());
+    let active = active_uuids(db.get_all_projects_with_options(false).await.unwrap());
+    assert!(all.contains(&uuid));
+    assert!(!active.contains(&uuid));
+    assert_eq!(
+        active.len(),
+        all.len() - 1,
+        "Only the held project is left out"
+    );
+
+    db.set_project_on_hold(&uuid, false).await.unwrap();
+    assert!(active_uuids(db.get_all_projects_with_options(false).await.unwrap()).contains(&uuid));
+
+    let missing = things3_core::ThingsId::new_v4();
+    assert!(matches!(
+        db.set_project_on_hold(&missing, true).await,
+        Err(things3_core::ThingsError::ProjectNotFound { .. })
+    ));
+}
+
 #[tokio::test]
 #[cfg(feature = "test-utils")]
 async fn test_update_project_success() {
@@ -138,6 +186,207 @@ async fn test_complete_project_success() {
     assert!(task.stop_date.is_some());
 }
 
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_project_dates_round_trip() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let start_date = chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+    let deadline = chrono::NaiveDate::from_ymd_opt(2026, 3, 31).unwrap();
+    let request = CreateProjectRequest {
+        title: "Dated Project".to_string(),
+        notes: None,
+        area_uuid: None,
+        start_date: Some(start_date),
+        deadline: Some(deadline),
+        tags: None,
+    };
+    let uuid = db.create_project(request).await.unwrap();
+
+    let project = db.get_project_by_uuid(&uuid).await.unwrap().unwrap();
+    assert_eq!(project.start_date, Some(start_date));
+    assert_eq!(project.deadline, Some(deadline));
+
+    let projects = db.get_all_projects().await.unwrap();
+    let listed = projects.iter().find(|p| p.uuid == uuid).unwrap();
+    assert_eq!(listed.deadline, Some(deadline));
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_all_projects_including_trashed() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let request = CreateProjectRequest {
+        title: "Trashed Project".to_string(),
+        notes: None,
+        area_uuid: None,
+        start_date: None,
+        deadline: None,
+        tags: None,
+    };
+    let uuid = db.create_project(request).await.unwrap();
+    db.delete_project(&uuid, ProjectChildHandling::Error)
+        .await
+        .unwrap();
+
+    let active = db.get_all_projects().await.unwrap();
+    assert!(active.iter().all(|p| p.uuid != uuid && !p.trashed));
+
+    let all = db.get_all_projects_including_trashed().await.unwrap();
+    assert_eq!(all.len(), active.len() + 1);
+    let trashed = all.iter().find(|p| p.uuid == uuid).unwrap();
+    assert!(trashed.trashed);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_completed_projects() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let request = CreateProjectRequest {
+        title: "Finished Project".to_string(),
+        notes: None,
+        area_uuid: None,
+        start_date: None,
+        deadline: None,
+        tags: None,
+    };
+    let uuid = db.create_project(request).await.unwrap();
+    assert!(db.get_completed_projects().await.unwrap().is_empty());
+
+    db.complete_project(&uuid, ProjectChildHandling::Error)
+        .await
+        .unwrap();
+
+    let completed = db.get_completed_projects().await.unwrap();
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].uuid, uuid);
+    assert_eq!(
+        completed[0].status,
+        things3_core::models::TaskStatus::Completed
+    );
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_project_tags() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let request = CreateProjectRequest {
+        title: "Tagged Project".to_string(),
+        notes: None,
+        area_uuid: None,
+        start_date: None,
+        deadline: None,
+        tags: Some(vec!["work".to_string(), "Errands".to_string()]),
+    };
+    let uuid = db.create_project(request).await.unwrap();
+
+    let tags = db.get_project_tags(&uuid).await.unwrap();
+    assert_eq!(tags, vec!["Errands".to_string(), "work".to_string()]);
+
+    let missing = db.get_project_tags(&things3_core::ThingsId::new_v4()).await;
+    assert!(missing.is_err());
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_projects_with_area_names() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let area = db
+        .create_area(CreateAreaRequest {
+            title: "Home".to_string(),
+        })
+        .await
+        .unwrap();
+    let new_project = |title: &str, area_uuid| CreateProjectRequest {
+        title: title.to_string(),
+        notes: None,
+        area_uuid,
+        start_date: None,
+        deadline: None,
+        tags: None,
+    };
+    let in_area = db
+        .create_project(new_project("Garden", Some(area)))
+        .await
+        .unwrap();
+    let loose = db.create_project(new_project("Loose", None)).await.unwrap();
+
+    let projects = db.get_projects_with_area_names().await.unwrap();
+    assert_eq!(projects.len(), db.get_all_projects().await.unwrap().len());
+    let area_of = |uuid| {
+        projects
+            .iter()
+            .find(|(p, _)| p.uuid == uuid)
+            .map(|(_, area)| area.clone())
+            .unwrap()
+    };
+    assert_eq!(area_of(in_area), Some("Home".to_string()));
+    assert_eq!(area_of(loose), None);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_stalled_projects() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let new_project = |title: &str| CreateProjectRequest {
+        title: title.to_string(),
+        notes: None,
+        area_uuid: None,
+        start_date: None,
+        deadline: None,
+        tags: None,
+    };
+    let stalled = db.create_project(new_project("Stalled")).await.unwrap();
+    let active = db.create_project(new_project("Active")).await.unwrap();
+
+    // Completed children don't keep a project moving
+    let done = db
+        .create_task(
+            TaskRequestBuilder::new()
+                .title("Done")
+                .project(stalled.clone())
+                .build(),
+        )
+        .await
+        .unwrap();
+    db.complete_task(&done).await.unwrap();
+    db.create_task(
+        TaskRequestBuilder::new()
+            .title("Next action")
+            .project(active.clone())
+            .build(),
+    )
+    .await
+    .unwrap();
+
+    let projects = db.get_stalled_projects().await.unwrap();
+    assert!(projects.iter().any(|p| p.uuid == stalled));
+    assert!(!projects.iter().any(|p| p.uuid == active));
+}
+
 #[tokio::test]
 #[cfg(feature = "test-utils")]
 async fn test_complete_project_with_children_cascade() {
@@ -177,6 +426,53 @@ async fn test_complete_project_with_children_cascade() {
     assert_eq!(task.status, things3_core::models::TaskStatus::Completed);
 }
 
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_tasks_in_completed_projects() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let new_project = |title: &str| CreateProjectRequest {
+        title: title.to_string(),
+        notes: None,
+        area_uuid: None,
+        start_date: None,
+        deadline: None,
+        tags: None,
+    };
+    let finished = db.create_project(new_project("Finished")).await.unwrap();
+    let ongoing = db.create_project(new_project("Ongoing")).await.unwrap();
+
+    let archived = db
+        .create_task(
+            TaskRequestBuilder::new()
+                .title("Archive me")
+                .project(finished.clone())
+                .build(),
+        )
+        .await
+        .unwrap();
+    let active = db
+        .create_task(
+            TaskRequestBuilder::new()
+                .title("Keep me")
+                .project(ongoing.clone())
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    db.complete_project(&finished, ProjectChildHandling::Cascade)
+        .await
+        .unwrap();
+
+    let tasks = db.get_tasks_in_completed_projects().await.unwrap();
+    assert!(tasks.iter().any(|t| t.uuid == archived));
+    assert!(!tasks.iter().any(|t| t.uuid == active));
+}
+
 #[tokio::test]
 #[cfg(feature = "test-utils")]
 async fn test_delete_project_with_children_error() {
@@ -248,6 +544,114 @@ async fn test_delete_project_with_children_orphan() {
     assert_eq!(task.project_uuid, None);
 }
 
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_restore_project_cascade() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let project_request = CreateProjectRequest {
+        title: "Accidentally Trashed".to_string(),
+        notes: None,
+        area_uuid: None,
+        start_date: None,
+        deadline: None,
+        tags: None,
+    };
+    let project_uuid = db.create_project(project_request).await.unwrap();
+    let task_request = TaskRequestBuilder::new()
+        .title("Child Task")
+        .project(project_uuid.clone())
+        .build();
+    let task_uuid = db.create_task(task_request).await.unwrap();
+    let discarded = db
+        .create_task(
+            TaskRequestBuilder::new()
+                .title("Trashed on purpose")
+                .project(project_uuid.clone())
+                .build(),
+        )
+        .await
+        .unwrap();
+    // Trashed by the user a day before the project
+    sqlx::query(
+        "UPDATE TMTask SET trashed = 1, userModificationDate = userModificationDate - 86400 WHERE uuid = ?",
+    )
+    .bind(discarded.as_str())
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    // Restoring a live project is rejected
+    assert!(db
+        .restore_project(&project_uuid, ProjectChildHandling::Cascade)
+        .await
+        .is_err());
+
+    db.delete_project(&project_uuid, ProjectChildHandling::Cascade)
+        .await
+        .unwrap();
+
+    // Error mode refuses while trashed children exist
+    assert!(db
+        .restore_project(&project_uuid, ProjectChildHandling::Error)
+        .await
+        .is_err());
+
+    db.restore_project(&project_uuid, ProjectChildHandling::Cascade)
+        .await
+        .unwrap();
+
+    assert!(db
+        .get_project_by_uuid(&project_uuid)
+        .await
+        .unwrap()
+        .is_some());
+    let task = db.get_task_by_uuid(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.project_uuid, Some(project_uuid));
+    assert!(db.get_task_by_uuid(&discarded).await.unwrap().is_none());
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_restore_project_orphan_leaves_children_trashed() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let project_request = CreateProjectRequest {
+        title: "Partially Restored".to_string(),
+        notes: None,
+        area_uuid: None,
+        start_date: None,
+        deadline: None,
+        tags: None,
+    };
+    let project_uuid = db.create_project(project_request).await.unwrap();
+    let task_request = TaskRequestBuilder::new()
+        .title("Child Task")
+        .project(project_uuid.clone())
+        .build();
+    let task_uuid = db.create_task(task_request).await.unwrap();
+
+    db.delete_project(&project_uuid, ProjectChildHandling::Cascade)
+        .await
+        .unwrap();
+    db.restore_project(&project_uuid, ProjectChildHandling::Orphan)
+        .await
+        .unwrap();
+
+    assert!(db
+        .get_project_by_uuid(&project_uuid)
+        .await
+        .unwrap()
+        .is_some());
+    assert!(db.get_task_by_uuid(&task_uuid).await.unwrap().is_none());
+}
+
 #[tokio::test]
 #[cfg(feature = "test-utils")]
 async fn test_create_area_success() {
@@ -270,6 +674,30 @@ async fn test_create_area_success() {
         .any(|a| a.uuid == uuid && a.title == "Personal"));
 }
 
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_area_by_uuid() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let request = CreateAreaRequest {
+        title: "Personal".to_string(),
+    };
+    let uuid = db.create_area(request).await.unwrap();
+
+    let area = db.get_area_by_uuid(&uuid).await.unwrap().unwrap();
+    assert_eq!(area.uuid, uuid);
+    assert_eq!(area.title, "Personal");
+
+    let missing = db
+        .get_area_by_uuid(&things3_core::ThingsId::new_v4())
+        .await
+        .unwrap();
+    assert!(missing.is_none());
+}
+
 #[tokio::test]
 #[cfg(feature = "test-utils")]
 async fn test_update_area_success() {
@@ -355,3 +783,517 @@ async fn test_delete_area_empty() {
     let areas = db.get_all_areas().await.unwrap();
     assert!(!areas.iter().any(|a| a.uuid == uuid));
 }
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_projects_by_size() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let new_project = |title: &str| CreateProjectRequest {
+        title: title.to_string(),
+        notes: None,
+        area_uuid: None,
+        start_date: None,
+        deadline: None,
+        tags: None,
+    };
+    let big = db.create_project(new_project("Big")).await.unwrap();
+    let small = db.create_project(new_project("Small")).await.unwrap();
+    for (project, title) in [(&big, "A"), (&big, "B"), (&big, "Trashed"), (&small, "C")] {
+        db.create_task(
+            TaskRequestBuilder::new()
+                .title(title)
+                .project(project.clone())
+                .build(),
+        )
+        .await
+        .unwrap();
+    }
+    sqlx::query("UPDATE TMTask SET trashed = 1 WHERE title = 'Trashed'")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let sized = db.get_projects_by_size(usize::MAX).await.unwrap();
+    assert!(sized.windows(2).all(|w| w[0].1 >= w[1].1));
+    let count_of = |uuid| sized.iter().find(|(p, _)| p.uuid == uuid).unwrap().1;
+    assert_eq!(count_of(big), 2);
+    assert_eq!(count_of(small), 1);
+
+    assert_eq!(db.get_projects_by_size(1).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_snapshot_nests_entities() {
+    use things3_core::TaskType;
+
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let area = db
+        .create_area(CreateAreaRequest {
+            title: "Snapshot Area".to_string(),
+        })
+        .await
+        .unwrap();
+    let project = db
+        .create_project(CreateProjectRequest {
+            title: "Snapshot Project".to_string(),
+            notes: None,
+            area_uuid: Some(area.clone()),
+            start_date: None,
+            deadline: None,
+            tags: None,
+        })
+        .await
+        .unwrap();
+    let create = |builder: TaskRequestBuilder| db.create_task(builder.build());
+    let direct = create(
+        TaskRequestBuilder::new()
+            .title("Direct")
+            .project(project.clone()),
+    )
+    .await
+    .unwrap();
+    let heading = create(
+        TaskRequestBuilder::new()
+            .title("Heading")
+            .task_type(TaskType::Heading)
+            .project(project.clone()),
+    )
+    .await
+    .unwrap();
+    let under_heading = create(
+        TaskRequestBuilder::new()
+            .title("Under heading")
+            .parent(heading.clone()),
+    )
+    .await
+    .unwrap();
+    let inbox = create(TaskRequestBuilder::new().title("Loose"))
+        .await
+        .unwrap();
+
+    let snapshot = db.snapshot().await.unwrap();
+
+    let snap_area = snapshot.areas.iter().find(|a| a.uuid == area).unwrap();
+    assert_eq!(snap_area.projects.len(), 1);
+    let snap_project = &snap_area.projects[0];
+    assert_eq!(snap_project.uuid, project);
+    assert!(!snapshot.projects.iter().any(|p| p.uuid == project));
+
+    let mut project_items: Vec<_> = snap_project.tasks.iter().map(|t| &t.uuid).collect();
+    project_items.sort();
+    let mut expected = vec![&direct, &heading];
+    expected.sort();
+    assert_eq!(project_items, expected);
+    let snap_heading = snap_project
+        .tasks
+        .iter()
+        .find(|t| t.uuid == heading)
+        .unwrap();
+    assert_eq!(snap_heading.children.len(), 1);
+    assert_eq!(snap_heading.children[0].uuid, under_heading);
+
+    assert!(snapshot.tasks.iter().any(|t| t.uuid == inbox));
+    assert!(!snapshot.tasks.iter().any(|t| t.uuid == direct));
+    assert!(!snapshot.tasks.iter().any(|t| t.uuid == under_heading));
+
+    let again = db.snapshot().await.unwrap();
+    assert_eq!(
+        serde_json::to_string(&snapshot).unwrap(),
+        serde_json::to_string(&again).unwrap()
+    );
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_project_velocity() {
+    use chrono::{Datelike, Duration, Utc};
+    use things3_core::{TaskType, ThingsId, Tz};
+
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path)
+        .await
+        .unwrap()
+        .with_timezone(Tz::UTC);
+
+    let new_project = |title: &str| CreateProjectRequest {
+        title: title.to_string(),
+        notes: None,
+        area_uuid: None,
+        start_date: None,
+        deadline: None,
+        tags: None,
+    };
+    let project = db.create_project(new_project("Tracked")).await.unwrap();
+    let other = db.create_project(new_project("Other")).await.unwrap();
+    let heading = db
+        .create_task(
+            TaskRequestBuilder::new()
+                .title("Heading")
+                .task_type(TaskType::Heading)
+                .project(project.clone())
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let now = Utc::now();
+    // (title, builder, days ago completed)
+    let completions = [
+        (
+            "Today",
+            TaskRequestBuilder::new().project(project.clone()),
+            0,
+        ),
+        (
+            "Under heading",
+            TaskRequestBuilder::new().parent(heading),
+            0,
+        ),
+        (
+            "Last week",
+            TaskRequestBuilder::new().project(project.clone()),
+            7,
+        ),
+        (
+            "Too old",
+            TaskRequestBuilder::new().project(project.clone()),
+            30,
+        ),
+        ("Elsewhere", TaskRequestBuilder::new().project(other), 0),
+    ];
+    for (title, builder, days_ago) in completions {
+        let uuid = db.create_task(builder.title(title).build()).await.unwrap();
+        sqlx::query("UPDATE TMTask SET status = 3, stopDate = ? WHERE uuid = ?")
+            .bind((now - Duration::days(days_ago)).timestamp() as f64)
+            .bind(uuid.as_str())
+            .execute(db.pool())
+            .await
+            .unwrap();
+    }
+
+    let today = now.date_naive();
+    let this_week = today - Duration::days(i64::from(today.weekday().num_days_from_monday()));
+    let velocity = db.get_project_velocity(&project, 3).await.unwrap();
+    assert_eq!(
+        velocity,
+        vec![
+            (this_week - Duration::weeks(2), 0),
+            (this_week - Duration::weeks(1), 1),
+            (this_week, 2),
+        ]
+    );
+
+    assert!(db
+        .get_project_velocity(&project, 0)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let clamped = db.get_project_velocity(&project, u32::MAX).await.unwrap();
+    assert_eq!(clamped.len(), ThingsDatabase::MAX_BUCKETS as usize);
+    assert_eq!(clamped.last(), Some(&(this_week, 2)));
+
+    assert!(db
+        .get_project_velocity(&ThingsId::new_v4(), 3)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_list_picker_items() {
+    use things3_core::models::ListItemKind;
+
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+    sqlx::query("DELETE FROM TMTask")
+        .execute(db.pool())
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM TMArea")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let home = db
+        .create_area(CreateAreaRequest {
+            title: "Home".to_string(),
+        })
+        .await
+        .unwrap();
+    let work = db
+        .create_area(CreateAreaRequest {
+            title: "Work".to_string(),
+        })
+        .await
+        .unwrap();
+    let new_project = |title: &str, area| CreateProjectRequest {
+        title: title.to_string(),
+        notes: None,
+        area_uuid: area,
+        start_date: None,
+        deadline: None,
+        tags: None,
+    };
+    let launch = db
+        .create_project(new_project("Launch", Some(work.clone())))
+        .await
+        .unwrap();
+    let budget = db
+        .create_project(new_project("Budget", Some(work.clone())))
+        .await
+        .unwrap();
+    let loose = db.create_project(new_project("Loose", None)).await.unwrap();
+    let done = db
+        .create_project(new_project("Done", Some(home.clone())))
+        .await
+        .unwrap();
+    db.complete_project(&done, ProjectChildHandling::Error)
+        .await
+        .unwrap();
+
+    let items = db.get_list_picker_items().await.unwrap();
+    let summary: Vec<_> = items
+        .iter()
+        .map(|i| (i.uuid.clone(), i.kind, i.area_uuid.clone()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (loose, ListItemKind::Project, None),
+            (home, ListItemKind::Area, None),
+            (work.clone(), ListItemKind::Area, None),
+            (budget, ListItemKind::Project, Some(work.clone())),
+            (launch, ListItemKind::Project, Some(work)),
+        ]
+    );
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_deleted_since() {
+    use things3_core::models::{DeleteChildHandling, TaskType};
+
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let project = db
+        .create_project(CreateProjectRequest {
+            title: "Retired".to_string(),
+            notes: None,
+            area_uuid: None,
+            start_date: None,
+            deadline: None,
+            tags: None,
+        })
+        .await
+        .unwrap();
+    let task = db
+        .create_task(TaskRequestBuilder::new().title("Dropped").build())
+        .await
+        .unwrap();
+    let old = db
+        .create_task(TaskRequestBuilder::new().title("Long gone").build())
+        .await
+        .unwrap();
+    db.create_task(TaskRequestBuilder::new().title("Kept").build())
+        .await
+        .unwrap();
+
+    let since = chrono::Utc::now() - chrono::Duration::minutes(5);
+    db.delete_project(&project, ProjectChildHandling::Error)
+        .await
+        .unwrap();
+    db.delete_task(&task, DeleteChildHandling::Error)
+        .await
+        .unwrap();
+    // Trashed before the last sync
+    sqlx::query("UPDATE TMTask SET trashed = 1, userModificationDate = ? WHERE uuid = ?")
+        .bind((since - chrono::Duration::days(1)).timestamp() as f64)
+        .bind(old.as_str())
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let mut deleted = db.get_deleted_since(since).await.unwrap();
+    deleted.sort_by_key(|(_, kind)| *kind as i32);
+    assert_eq!(
+        deleted,
+        vec![(task, TaskType::Todo), (project, TaskType::Project)]
+    );
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_at_risk_projects() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path)
+        .await
+        .unwrap()
+        .with_timezone(chrono_tz::UTC);
+
+    let today = chrono::Utc::now().date_naive();
+    let new_project = |title: &str, days: i64| CreateProjectRequest {
+        title: title.to_string(),
+        notes: None,
+        area_uuid: None,
+        start_date: None,
+        deadline: Some(today + chrono::Duration::days(days)),
+        tags: None,
+    };
+    let friday = db
+        .create_project(new_project("Due Friday", 3))
+        .await
+        .unwrap();
+    let overdue = db.create_project(new_project("Overdue", -2)).await.unwrap();
+    let later = db
+        .create_project(new_project("Due later", 30))
+        .await
+        .unwrap();
+    let finished = db
+        .create_project(new_project("Nothing left", 1))
+        .await
+        .unwrap();
+
+    for (project, title) in [
+        (&friday, "Open 1"),
+        (&friday, "Open 2"),
+        (&overdue, "Open 3"),
+        (&later, "Open 4"),
+    ] {
+        db.create_task(
+            TaskRequestBuilder::new()
+                .title(title)
+                .project(project.clone())
+                .build(),
+        )
+        .await
+        .unwrap();
+    }
+    for project in [&friday, &finished] {
+        let done = db
+            .create_task(
+                TaskRequestBuilder::new()
+                    .title("Done")
+                    .project(project.clone())
+                    .build(),
+            )
+            .await
+            .unwrap();
+        db.complete_task(&done).await.unwrap();
+    }
+
+    let ours = [&friday, &overdue, &later, &finished];
+    let at_risk: Vec<_> = db
+        .get_at_risk_projects(7)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|(p, _)| ours.contains(&&p.uuid))
+        .map(|(p, count)| (p.uuid, count))
+        .collect();
+    assert_eq!(at_risk, vec![(overdue.clone(), 1), (friday.clone(), 2)]);
+
+    let all_due: Vec<_> = db
+        .get_at_risk_projects(u32::MAX)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|(p, _)| ours.contains(&&p.uuid))
+        .map(|(p, count)| (p.uuid, count))
+        .collect();
+    assert_eq!(all_due, vec![(overdue, 1), (friday, 2), (later, 1)]);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_global_search_ranks_across_kinds() {
+    use things3_core::models::SearchHit;
+
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let area = db
+        .create_area(CreateAreaRequest {
+            title: "Invoicing".to_string(),
+        })
+        .await
+        .unwrap();
+    let project = db
+        .create_project(CreateProjectRequest {
+            title: "Invoice".to_string(),
+            notes: None,
+            area_uuid: None,
+            start_date: None,
+            deadline: None,
+            tags: None,
+        })
+        .await
+        .unwrap();
+    let task = db
+        .create_task(
+            TaskRequestBuilder::new()
+                .title("Send the March invoice")
+                .build(),
+        )
+        .await
+        .unwrap();
+    let noted = db
+        .create_task(
+            TaskRequestBuilder::new()
+                .title("Email accountant")
+                .notes("attach the invoice PDF")
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    // "Invoice" is a title prefix of both the project and the area
+    let hits = db.global_search("invoic", 10).await.unwrap();
+    let kinds_and_ids: Vec<_> = hits
+        .iter()
+        .map(|hit| {
+            let kind = match hit {
+                SearchHit::Task(_) => "task",
+                SearchHit::Project(_) => "project",
+                SearchHit::Area(_) => "area",
+            };
+            (kind, hit.uuid().clone())
+        })
+        .collect();
+    assert_eq!(
+        kinds_and_ids,
+        vec![
+            ("area", area),
+            ("project", project),
+            ("task", task),
+            ("task", noted),
+        ]
+    );
+
+    // An exact title outranks a prefix
+    let capped = db.global_search("invoice", 2).await.unwrap();
+    assert_eq!(capped.len(), 2);
+    assert_eq!(capped[0].title(), "Invoice");
+    assert_eq!(capped[1].title(), "Send the March invoice");
+    assert!(db.global_search("invoice", 0).await.unwrap().is_empty());
+}
@@ -6,7 +6,9 @@ use things3_core::{
 };
 
 #[cfg(feature = "test-utils")]
-use things3_core::test_utils::create_test_database_and_connect;
+use things3_core::test_utils::{
+    create_test_database_and_connect, create_test_database_with_tags_and_connect,
+};
 
 // ========================================================================
 // TAG NORMALIZATION AND SIMILARITY TESTS
@@ -132,6 +134,63 @@ async fn test_create_tag_suggests_similar() {
     }
 }
 
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_tag_similarity_threshold_per_call() {
+    use things3_core::{models::TagAssignmentResult, test_utils::TaskRequestBuilder, ThingsError};
+
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let request = |title: &str| CreateTagRequest {
+        title: title.to_string(),
+        shortcut: None,
+        parent_uuid: None,
+    };
+    db.create_tag_force(request("important")).await.unwrap();
+    let task = db
+        .create_task(TaskRequestBuilder::new().title("Tagged").build())
+        .await
+        .unwrap();
+
+    // "importnt" scores ~0.89 against "important"
+    assert!(matches!(
+        db.create_tag_smart_with_threshold(request("importnt"), 0.95)
+            .await
+            .unwrap(),
+        TagCreationResult::Created { .. }
+    ));
+    assert!(matches!(
+        db.add_tag_to_task_with_threshold(&task, "imprtant", 0.5)
+            .await
+            .unwrap(),
+        TagAssignmentResult::Suggestions { .. }
+    ));
+    assert!(matches!(
+        db.add_tag_to_task_with_threshold(&task, "imprtant", 1.0)
+            .await
+            .unwrap(),
+        TagAssignmentResult::Assigned { .. }
+    ));
+
+    let loose = db
+        .set_task_tags_with_threshold(&task, vec!["imprtnt".to_string()], 0.5)
+        .await
+        .unwrap();
+    assert!(!loose.is_empty());
+    let strict = db
+        .set_task_tags_with_threshold(&task, vec!["imp".to_string()], 1.0)
+        .await
+        .unwrap();
+    assert!(strict.is_empty());
+
+    for invalid in [-0.1, 1.5, f32::NAN] {
+        assert!(matches!(
+            db.create_tag_smart_with_threshold(request("other"), invalid)
+                .await,
+            Err(ThingsError::Validation { .. })
+        ));
+    }
+}
+
 #[tokio::test]
 #[cfg(feature = "test-utils")]
 async fn test_create_tag_force_skips_check() {
@@ -234,6 +293,45 @@ async fn test_update_tag_checks_duplicates() {
     assert!(result.is_err());
 }
 
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_tag_shortcuts_must_be_unique() {
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+
+    let new_tag = |title: &str, shortcut: Option<&str>| CreateTagRequest {
+        title: title.to_string(),
+        shortcut: shortcut.map(str::to_string),
+        parent_uuid: None,
+    };
+    let work = db
+        .create_tag_force(new_tag("work", Some("w")))
+        .await
+        .unwrap();
+
+    // Case-insensitive collision is rejected on create, naming the conflicting tag
+    let err = db
+        .create_tag_force(new_tag("writing", Some("W")))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("'work'"));
+    let result = db.create_tag_smart(new_tag("garden", Some("w"))).await;
+    assert!(result.is_err());
+
+    // ...and on update, unless the tag keeps its own shortcut
+    let home = db
+        .create_tag_force(new_tag("home", Some("h")))
+        .await
+        .unwrap();
+    let update = |uuid, shortcut: &str| UpdateTagRequest {
+        uuid,
+        title: None,
+        shortcut: Some(shortcut.to_string()),
+        parent_uuid: None,
+    };
+    assert!(db.update_tag(update(home, "w")).await.is_err());
+    db.update_tag(update(work, "W")).await.unwrap();
+}
+
 #[tokio::test]
 #[cfg(feature = "test-utils")]
 async fn test_update_tag_success() {
@@ -349,6 +447,35 @@ async fn test_get_all_tags() {
     }
 }
 
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_all_tags_with_seeded_real_schema() {
+    let (db, _temp_file) = create_test_database_with_tags_and_connect().await.unwrap();
+
+    let all_tags = db.get_all_tags().await.unwrap();
+    let titles: Vec<&str> = all_tags.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["errands", "home", "urgent", "work"]);
+
+    let by_title = |title: &str| all_tags.iter().find(|t| t.title == title).unwrap();
+    assert_eq!(by_title("work").shortcut.as_deref(), Some("w"));
+    assert_eq!(by_title("work").usage_count, 1);
+    assert_eq!(by_title("urgent").usage_count, 2);
+    assert!(by_title("urgent").last_used.is_some());
+    assert_eq!(by_title("home").usage_count, 0);
+    assert_eq!(
+        by_title("errands").parent_uuid.as_ref(),
+        Some(&by_title("home").uuid)
+    );
+
+    let popular = db.get_popular_tags(1).await.unwrap();
+    assert_eq!(popular[0].title, "urgent");
+
+    let tasks = db.search_tasks("Research competitors").await.unwrap();
+    let mut tags = tasks[0].tags.clone();
+    tags.sort();
+    assert_eq!(tags, vec!["urgent", "work"]);
+}
+
 #[tokio::test]
 #[cfg(feature = "test-utils")]
 async fn test_search_tags() {
@@ -461,3 +588,526 @@ async fn test_find_duplicate_tags() {
         assert!(duplicates[i - 1].similarity >= duplicates[i].similarity);
     }
 }
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_suggest_tag_merges_keeps_more_used_tag() {
+    let (db, _temp_file) = create_test_database_with_tags_and_connect().await.unwrap();
+
+    let request = CreateTagRequest {
+        title: "urgnt".to_string(),
+        shortcut: None,
+        parent_uuid: None,
+    };
+    db.create_tag_force(request).await.unwrap();
+
+    let suggestions = db.suggest_tag_merges(0.8).await.unwrap();
+    let suggestion = suggestions
+        .iter()
+        .find(|s| s.merge.title == "urgnt")
+        .expect("urgnt should be suggested for merging");
+    assert_eq!(suggestion.keep.title, "urgent");
+    assert_eq!(suggestion.affected_task_count, 2);
+    assert!(suggestion.similarity >= 0.8);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_tasks_with_tag_uuid() {
+    use things3_core::{models::TagAssignmentResult, test_utils::TaskRequestBuilder};
+
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let tagged = db
+        .create_task(TaskRequestBuilder::new().title("Tagged").build())
+        .await
+        .unwrap();
+    db.create_task(TaskRequestBuilder::new().title("Untagged").build())
+        .await
+        .unwrap();
+
+    let TagAssignmentResult::Assigned { tag_uuid } =
+        db.add_tag_to_task(&tagged, "errand").await.unwrap()
+    else {
+        panic!("tag should be assigned");
+    };
+
+    // Renaming the tag doesn't affect a UUID lookup
+    db.update_tag(UpdateTagRequest {
+        uuid: tag_uuid.clone(),
+        title: Some("errands".to_string()),
+        shortcut: None,
+        parent_uuid: None,
+    })
+    .await
+    .unwrap();
+
+    let tasks = db.get_tasks_with_tag_uuid(&tag_uuid).await.unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].uuid, tagged);
+    assert_eq!(tasks[0].tags, vec!["errands".to_string()]);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_recompute_tag_usage() {
+    use things3_core::{models::TagAssignmentResult, test_utils::TaskRequestBuilder};
+
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let tagged = db
+        .create_task(TaskRequestBuilder::new().title("Tagged").build())
+        .await
+        .unwrap();
+    let TagAssignmentResult::Assigned { tag_uuid: used } =
+        db.add_tag_to_task(&tagged, "errand").await.unwrap()
+    else {
+        panic!("tag should be assigned");
+    };
+    let unused = db
+        .create_tag_force(CreateTagRequest {
+            title: "someday".to_string(),
+            shortcut: None,
+            parent_uuid: None,
+        })
+        .await
+        .unwrap();
+
+    // Drift: a stale date on the used tag, a date on a tag no task carries
+    let modified = 800_000_000.0_f64;
+    sqlx::query("UPDATE TMTask SET userModificationDate = ? WHERE uuid = ?")
+        .bind(modified)
+        .bind(tagged.as_str())
+        .execute(db.pool())
+        .await
+        .unwrap();
+    sqlx::query("UPDATE TMTag SET usedDate = 1.0")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let report = db.recompute_tag_usage().await.unwrap();
+    assert!(report.tags_scanned >= 2);
+    let change_for = |uuid| report.changed.iter().find(|c| &c.uuid == uuid).unwrap();
+    let used_change = change_for(&used);
+    assert_eq!(used_change.usage_count, 1);
+    assert_eq!(
+        used_change.last_used.map(|d| d.timestamp()),
+        Some(modified as i64)
+    );
+    assert_eq!(
+        used_change.previous_last_used.map(|d| d.timestamp()),
+        Some(1)
+    );
+    let unused_change = change_for(&unused);
+    assert_eq!(unused_change.usage_count, 0);
+    assert!(unused_change.last_used.is_none());
+
+    let used_date: Option<f64> = sqlx::query_scalar("SELECT usedDate FROM TMTag WHERE uuid = ?")
+        .bind(used.as_str())
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(used_date, Some(modified));
+
+    // Nothing left to correct
+    assert!(db.recompute_tag_usage().await.unwrap().changed.is_empty());
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_custom_tag_normalization_strips_prefixes() {
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let request = |title: &str| CreateTagRequest {
+        title: title.to_string(),
+        shortcut: None,
+        parent_uuid: None,
+    };
+    db.create_tag_force(request("@home")).await.unwrap();
+
+    // Default rules keep the prefix, so "home" is only similar
+    let result = db.create_tag_smart(request("home")).await.unwrap();
+    assert!(!matches!(result, TagCreationResult::Existing { .. }));
+
+    let db = db.with_tag_normalization(TagNormalizationConfig {
+        strip_leading: "@#".to_string(),
+        ..TagNormalizationConfig::default()
+    });
+    match db.create_tag_smart(request("#Home")).await.unwrap() {
+        TagCreationResult::Existing { tag, .. } => assert_eq!(tag.title, "@home"),
+        other => panic!("Expected existing tag, got {other:?}"),
+    }
+
+    db.create_tag_force(request("home")).await.unwrap();
+    let pairs = db.find_duplicate_tags(1.0).await.unwrap();
+    assert_eq!(pairs.len(), 1);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_tag_by_shortcut() {
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let uuid = db
+        .create_tag_force(CreateTagRequest {
+            title: "Errand".to_string(),
+            shortcut: Some("e".to_string()),
+            parent_uuid: None,
+        })
+        .await
+        .unwrap();
+
+    let tag = db.get_tag_by_shortcut("e").await.unwrap().unwrap();
+    assert_eq!(tag.uuid, uuid);
+    assert_eq!(tag.title, "Errand");
+    assert_eq!(tag.shortcut.as_deref(), Some("e"));
+
+    assert!(db.get_tag_by_shortcut("E").await.unwrap().is_none());
+    assert!(db.get_tag_by_shortcut("x").await.unwrap().is_none());
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_tag_completion_stats() {
+    use things3_core::test_utils::TaskRequestBuilder;
+
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let mut errands = Vec::new();
+    for i in 0..5 {
+        let id = db
+            .create_task(
+                TaskRequestBuilder::new()
+                    .title(format!("Errand {i}"))
+                    .build(),
+            )
+            .await
+            .unwrap();
+        db.add_tag_to_task(&id, "errands").await.unwrap();
+        errands.push(id);
+    }
+    for id in &errands[..3] {
+        db.complete_task(id).await.unwrap();
+    }
+    let someday = db
+        .create_task(TaskRequestBuilder::new().title("Learn piano").build())
+        .await
+        .unwrap();
+    db.add_tag_to_task(&someday, "someday").await.unwrap();
+
+    // Trashed to-dos are not counted
+    db.add_tag_to_task(&errands[4], "someday").await.unwrap();
+    db.delete_task(
+        &errands[4],
+        things3_core::models::DeleteChildHandling::Error,
+    )
+    .await
+    .unwrap();
+
+    let stats = db.get_tag_completion_stats().await.unwrap();
+    let titles: Vec<&str> = stats.iter().map(|s| s.title.as_str()).collect();
+    assert_eq!(titles, ["errands", "someday"]);
+
+    assert_eq!(stats[0].total, 4);
+    assert_eq!(stats[0].completed, 3);
+    assert!((stats[0].completion_ratio - 0.75).abs() < f64::EPSILON);
+
+    assert_eq!(stats[1].total, 1);
+    assert_eq!(stats[1].completed, 0);
+    assert!(stats[1].completion_ratio.abs() < f64::EPSILON);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_get_tag_usage_ranking() {
+    use things3_core::test_utils::TaskRequestBuilder;
+
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    // (title, tags)
+    for (title, tags) in [
+        ("Buy milk", &["errands", "home"][..]),
+        ("Post parcel", &["errands"][..]),
+        ("Fix tap", &["home"][..]),
+        ("Call bank", &["errands", "phone"][..]),
+    ] {
+        let id = db
+            .create_task(TaskRequestBuilder::new().title(title).build())
+            .await
+            .unwrap();
+        for tag in tags {
+            db.add_tag_to_task(&id, tag).await.unwrap();
+        }
+    }
+    db.create_tag_force(CreateTagRequest {
+        title: "unused".to_string(),
+        shortcut: None,
+        parent_uuid: None,
+    })
+    .await
+    .unwrap();
+
+    let ranking = db.get_tag_usage_ranking(10).await.unwrap();
+    assert_eq!(
+        ranking,
+        vec![
+            ("errands".to_string(), 3),
+            ("home".to_string(), 2),
+            ("phone".to_string(), 1),
+        ]
+    );
+    assert_eq!(db.get_tag_usage_ranking(1).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_create_task_with_defaults_merges_default_tags() {
+    use things3_core::test_utils::TaskRequestBuilder;
+
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let db = db.with_default_tags(vec!["inbox-review".to_string(), "Work".to_string()]);
+    db.create_tag_force(CreateTagRequest {
+        title: "Inbox-Review".to_string(),
+        shortcut: None,
+        parent_uuid: None,
+    })
+    .await
+    .unwrap();
+
+    let id = db
+        .create_task_with_defaults(
+            TaskRequestBuilder::new()
+                .title("Capture")
+                .tags(vec!["work".to_string(), "errand".to_string()])
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let task = db.get_task_by_uuid(&id).await.unwrap().unwrap();
+    let mut tags = task.tags.clone();
+    tags.sort();
+    assert_eq!(tags, ["Inbox-Review", "errand", "work"]);
+
+    // The default resolved to the existing tag instead of creating another
+    let all_tags = db.get_all_tags().await.unwrap();
+    let review_tags = all_tags
+        .iter()
+        .filter(|t| t.title.eq_ignore_ascii_case("inbox-review"))
+        .count();
+    assert_eq!(review_tags, 1);
+
+    // Without defaults configured it behaves like create_task
+    let db = db.with_default_tags(Vec::new());
+    let plain = db
+        .create_task_with_defaults(TaskRequestBuilder::new().title("Plain").build())
+        .await
+        .unwrap();
+    let task = db.get_task_by_uuid(&plain).await.unwrap().unwrap();
+    assert!(task.tags.is_empty());
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_tag_search_results() {
+    use things3_core::test_utils::TaskRequestBuilder;
+
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let planning = db
+        .create_task(TaskRequestBuilder::new().title("Q3 planning").build())
+        .await
+        .unwrap();
+    let review = db
+        .create_task(
+            TaskRequestBuilder::new()
+                .title("Budget review")
+                .notes("Numbers for Q3")
+                .build(),
+        )
+        .await
+        .unwrap();
+    let unrelated = db
+        .create_task(TaskRequestBuilder::new().title("Water plants").build())
+        .await
+        .unwrap();
+    db.add_tag_to_task(&planning, "quarterly").await.unwrap();
+
+    let tagged = db.tag_search_results("Q3", "Quarterly").await.unwrap();
+    assert_eq!(tagged, 1);
+
+    for id in [&planning, &review] {
+        let task = db.get_task_by_uuid(id).await.unwrap().unwrap();
+        assert_eq!(task.tags, vec!["quarterly".to_string()]);
+    }
+    let task = db.get_task_by_uuid(&unrelated).await.unwrap().unwrap();
+    assert!(task.tags.is_empty());
+
+    // Re-running is a no-op
+    assert_eq!(db.tag_search_results("Q3", "quarterly").await.unwrap(), 0);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_tag_search_results_creates_tag_in_transaction() {
+    use things3_core::test_utils::TaskRequestBuilder;
+
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let task = db
+        .create_task(TaskRequestBuilder::new().title("Q3 planning").build())
+        .await
+        .unwrap();
+
+    // Make every assignment fail after the tag would have been created
+    sqlx::query(
+        "CREATE TRIGGER reject_task_tag BEFORE INSERT ON TMTaskTag \
+         BEGIN SELECT RAISE(ABORT, 'rejected'); END",
+    )
+    .execute(db.pool())
+    .await
+    .unwrap();
+    assert!(db.tag_search_results("Q3", "Fresh").await.is_err());
+    assert!(db
+        .find_tag_by_normalized_title("fresh")
+        .await
+        .unwrap()
+        .is_none());
+
+    sqlx::query("DROP TRIGGER reject_task_tag")
+        .execute(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(db.tag_search_results("Q3", "Fresh").await.unwrap(), 1);
+    let task = db.get_task_by_uuid(&task).await.unwrap().unwrap();
+    assert_eq!(task.tags, vec!["Fresh".to_string()]);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_scan_all_task_tags() {
+    use things3_core::test_utils::TaskRequestBuilder;
+
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let before = db.scan_all_task_tags().await.unwrap().len();
+
+    let errand = db
+        .create_task(
+            TaskRequestBuilder::new()
+                .title("Pick up dry cleaning")
+                .tags(vec!["town".to_string(), "chores".to_string()])
+                .build(),
+        )
+        .await
+        .unwrap();
+    db.create_task(TaskRequestBuilder::new().title("Untagged").build())
+        .await
+        .unwrap();
+
+    let scanned = db.scan_all_task_tags().await.unwrap();
+    assert_eq!(scanned.len(), before + 1);
+    let (_, tags) = scanned.iter().find(|(id, _)| *id == errand).unwrap();
+    assert_eq!(tags, &vec!["chores".to_string(), "town".to_string()]);
+
+    // Usage counts built on the scan match it
+    let all_tags = db.get_all_tags().await.unwrap();
+    let town = all_tags.iter().find(|t| t.title == "town").unwrap();
+    assert_eq!(town.usage_count, 1);
+    let stats = db.get_tag_statistics(&town.uuid).await.unwrap();
+    assert_eq!(stats.related_tags, vec![("chores".to_string(), 1)]);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_find_and_clean_orphan_tags() {
+    use things3_core::test_utils::TaskRequestBuilder;
+
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    assert!(db.find_tasks_with_orphan_tags().await.unwrap().is_empty());
+
+    let create = |title: &str, tags: &[&str]| {
+        TaskRequestBuilder::new()
+            .title(title)
+            .tags(tags.iter().map(|t| t.to_string()).collect())
+            .build()
+    };
+    let both = db
+        .create_task(create("Both", &["town", "chores"]))
+        .await
+        .unwrap();
+    let town_only = db
+        .create_task(create("Town only", &["town"]))
+        .await
+        .unwrap();
+    db.create_task(create("Chores only", &["chores"]))
+        .await
+        .unwrap();
+
+    let all_tags = db.get_all_tags().await.unwrap();
+    let town = all_tags.iter().find(|t| t.title == "town").unwrap();
+    db.delete_tag(&town.uuid, false).await.unwrap();
+
+    let orphans = db.find_tasks_with_orphan_tags().await.unwrap();
+    let mut ids: Vec<_> = orphans.iter().map(|(task, _)| task.uuid.clone()).collect();
+    ids.sort();
+    let mut expected = vec![both.clone(), town_only];
+    expected.sort();
+    assert_eq!(ids, expected);
+    for (task, missing) in &orphans {
+        assert_eq!(missing, &vec![town.uuid.to_string()]);
+        assert!(!task.tags.contains(&"town".to_string()));
+    }
+    let (both_task, _) = orphans.iter().find(|(task, _)| task.uuid == both).unwrap();
+    assert_eq!(both_task.tags, vec!["chores".to_string()]);
+
+    assert_eq!(db.clean_orphan_tags().await.unwrap(), 2);
+    assert!(db.find_tasks_with_orphan_tags().await.unwrap().is_empty());
+    assert_eq!(db.clean_orphan_tags().await.unwrap(), 0);
+    let task = db.get_task_by_uuid(&both).await.unwrap().unwrap();
+    assert_eq!(task.tags, vec!["chores".to_string()]);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_tag_usage_counts_per_tag_uuid() {
+    use things3_core::test_utils::TaskRequestBuilder;
+
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let mut tasks = Vec::new();
+    for title in ["Work task", "Home task"] {
+        tasks.push(
+            db.create_task(TaskRequestBuilder::new().title(title).build())
+                .await
+                .unwrap(),
+        );
+    }
+
+    // Two "urgent" tags under different parents, one on each task
+    for ((uuid, parent), task) in [("urgent-work", "work"), ("urgent-home", "home")]
+        .into_iter()
+        .zip(&tasks)
+    {
+        sqlx::query("INSERT INTO TMTag (uuid, title, parent) VALUES (?, 'urgent', ?)")
+            .bind(uuid)
+            .bind(parent)
+            .execute(db.pool())
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO TMTaskTag (tasks, tags) VALUES (?, ?)")
+            .bind(task.as_str())
+            .bind(uuid)
+            .execute(db.pool())
+            .await
+            .unwrap();
+    }
+
+    let all_tags = db.get_all_tags().await.unwrap();
+    let usage = |uuid: &str| {
+        all_tags
+            .iter()
+            .find(|t| t.uuid.as_str() == uuid)
+            .unwrap()
+            .usage_count
+    };
+    assert_eq!(usage("urgent-work"), 1);
+    assert_eq!(usage("urgent-home"), 1);
+
+    let popular = db.get_popular_tags(100).await.unwrap();
+    assert!(popular
+        .iter()
+        .filter(|t| t.title == "urgent")
+        .all(|t| t.usage_count == 1));
+}
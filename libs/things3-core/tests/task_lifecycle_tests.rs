@@ -942,6 +942,68 @@ async fn test_delete_then_query_excluded() {
     assert!(task.is_none(), "Deleted task should not be found");
 }
 
+// ============================================================================
+// Defer Task Tests (3 tests)
+// ============================================================================
+
+fn simple_task(title: &str, deadline: Option<chrono::NaiveDate>) -> CreateTaskRequest {
+    CreateTaskRequest {
+        title: title.to_string(),
+        task_type: None,
+        notes: None,
+        start_date: None,
+        deadline,
+        project_uuid: None,
+        area_uuid: None,
+        parent_uuid: None,
+        tags: None,
+        status: None,
+    }
+}
+
+#[tokio::test]
+async fn test_defer_task_sets_start_date() {
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let task_uuid = db
+        .create_task(simple_task("Snooze me", None))
+        .await
+        .unwrap();
+    let before = db.get_task_by_uuid(&task_uuid).await.unwrap().unwrap();
+
+    db.defer_task(&task_uuid, 3).await.unwrap();
+    let task = db.get_task_by_uuid(&task_uuid).await.unwrap().unwrap();
+    let today = things3_core::database::today_in(db.timezone());
+    assert_eq!(task.start_date, Some(today + chrono::Duration::days(3)));
+    assert!(task.modified >= before.modified);
+
+    db.defer_task(&task_uuid, -2).await.unwrap();
+    let task = db.get_task_by_uuid(&task_uuid).await.unwrap().unwrap();
+    assert_eq!(task.start_date, Some(today - chrono::Duration::days(2)));
+}
+
+#[tokio::test]
+async fn test_defer_task_past_deadline_fails() {
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let deadline = things3_core::database::today_in(db.timezone()) + chrono::Duration::days(1);
+    let task_uuid = db
+        .create_task(simple_task("Due tomorrow", Some(deadline)))
+        .await
+        .unwrap();
+
+    let result = db.defer_task(&task_uuid, 5).await;
+    assert!(
+        result.is_err(),
+        "Start date after deadline should be rejected"
+    );
+}
+
+#[tokio::test]
+async fn test_defer_task_nonexistent() {
+    let (db, _temp_file) = create_test_database_and_connect().await.unwrap();
+    let result = db.defer_task(&ThingsId::new_v4(), 1).await;
+    assert!(result.is_err());
+}
+
 // ============================================================================
 // Edge Cases (4 tests)
 // ============================================================================
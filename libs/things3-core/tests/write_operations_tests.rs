@@ -1,6 +1,7 @@
 use chrono::NaiveDate;
 use things3_core::{
-    CreateTaskRequest, TaskStatus, TaskType, ThingsDatabase, ThingsId, UpdateTaskRequest,
+    CreateTaskRequest, FieldUpdate, TaskStatus, TaskType, ThingsDatabase, ThingsId,
+    UpdateTaskRequest,
 };
 
 #[cfg(feature = "test-utils")]
@@ -299,6 +300,104 @@ async fn test_create_task_with_valid_project() {
     assert!(result.is_ok(), "Should succeed with valid project UUID");
 }
 
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_create_task_appends_index_within_container() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let request = |title: &str, task_type, project_uuid| CreateTaskRequest {
+        title: title.to_string(),
+        task_type,
+        notes: None,
+        start_date: None,
+        deadline: None,
+        project_uuid,
+        area_uuid: None,
+        parent_uuid: None,
+        tags: None,
+        status: None,
+    };
+    let project_uuid = db
+        .create_task(request("Ordered Project", Some(TaskType::Project), None))
+        .await
+        .unwrap();
+
+    let mut indexes = Vec::new();
+    for title in ["First", "Second", "Third"] {
+        let uuid = db
+            .create_task(request(title, None, Some(project_uuid.clone())))
+            .await
+            .unwrap();
+        let task = db.get_task_by_uuid(&uuid).await.unwrap().unwrap();
+        indexes.push(task.index);
+    }
+    assert_eq!(indexes, vec![Some(0), Some(1), Some(2)]);
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_create_tasks_from_titles() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let project_uuid = db
+        .create_task(CreateTaskRequest {
+            title: "Brainstorm".to_string(),
+            task_type: Some(TaskType::Project),
+            notes: None,
+            start_date: None,
+            deadline: None,
+            project_uuid: None,
+            area_uuid: None,
+            parent_uuid: None,
+            tags: None,
+            status: None,
+        })
+        .await
+        .unwrap();
+
+    let lines: Vec<String> = ["Call venue", "", "  Book caterer\r", "   ", "Send invites"]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    let ids = db
+        .create_tasks_from_titles(&lines, Some(project_uuid.clone()))
+        .await
+        .unwrap();
+    assert_eq!(ids.len(), 3);
+
+    let mut created = Vec::new();
+    for id in &ids {
+        let task = db.get_task_by_uuid(id).await.unwrap().unwrap();
+        assert_eq!(task.project_uuid.as_ref(), Some(&project_uuid));
+        created.push((task.title, task.index));
+    }
+    assert_eq!(
+        created,
+        vec![
+            ("Call venue".to_string(), Some(0)),
+            ("Book caterer".to_string(), Some(1)),
+            ("Send invites".to_string(), Some(2)),
+        ]
+    );
+
+    let missing = ThingsId::new_v4();
+    assert!(db
+        .create_tasks_from_titles(&lines, Some(missing))
+        .await
+        .is_err());
+    assert!(db
+        .create_tasks_from_titles(&[String::new()], None)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
 #[tokio::test]
 #[cfg(feature = "test-utils")]
 async fn test_create_task_with_valid_dates() {
@@ -493,12 +592,12 @@ async fn test_update_task_title() {
     let update_request = UpdateTaskRequest {
         uuid,
         title: Some("Updated Title".to_string()),
-        notes: None,
-        start_date: None,
-        deadline: None,
+        notes: FieldUpdate::Unchanged,
+        start_date: FieldUpdate::Unchanged,
+        deadline: FieldUpdate::Unchanged,
         status: None,
-        project_uuid: None,
-        area_uuid: None,
+        project_uuid: FieldUpdate::Unchanged,
+        area_uuid: FieldUpdate::Unchanged,
         tags: None,
     };
 
@@ -533,12 +632,12 @@ async fn test_update_task_notes() {
     let update_request = UpdateTaskRequest {
         uuid,
         title: None,
-        notes: Some("Updated notes".to_string()),
-        start_date: None,
-        deadline: None,
+        notes: FieldUpdate::Set("Updated notes".to_string()),
+        start_date: FieldUpdate::Unchanged,
+        deadline: FieldUpdate::Unchanged,
         status: None,
-        project_uuid: None,
-        area_uuid: None,
+        project_uuid: FieldUpdate::Unchanged,
+        area_uuid: FieldUpdate::Unchanged,
         tags: None,
     };
 
@@ -573,17 +672,59 @@ async fn test_update_task_dates() {
     let update_request = UpdateTaskRequest {
         uuid,
         title: None,
-        notes: None,
+        notes: FieldUpdate::Unchanged,
+        start_date: FieldUpdate::Set(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()),
+        deadline: FieldUpdate::Set(NaiveDate::from_ymd_opt(2025, 2, 28).unwrap()),
+        status: None,
+        project_uuid: FieldUpdate::Unchanged,
+        area_uuid: FieldUpdate::Unchanged,
+        tags: None,
+    };
+
+    let result = db.update_task(update_request).await;
+    assert!(result.is_ok(), "Should update task dates");
+}
+
+#[tokio::test]
+#[cfg(feature = "test-utils")]
+async fn test_update_task_clear_fields() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let db_path = temp_file.path();
+    create_test_database(db_path).await.unwrap();
+    let db = ThingsDatabase::new(db_path).await.unwrap();
+
+    let create_request = CreateTaskRequest {
+        title: "Task to Clear".to_string(),
+        task_type: None,
+        notes: Some("Some notes".to_string()),
         start_date: Some(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()),
         deadline: Some(NaiveDate::from_ymd_opt(2025, 2, 28).unwrap()),
-        status: None,
         project_uuid: None,
         area_uuid: None,
+        parent_uuid: None,
         tags: None,
+        status: None,
     };
+    let uuid = db.create_task(create_request).await.unwrap();
 
-    let result = db.update_task(update_request).await;
-    assert!(result.is_ok(), "Should update task dates");
+    // Clear notes and deadline, leave start date alone
+    let update_request = UpdateTaskRequest {
+        uuid: uuid.clone(),
+        title: None,
+        notes: FieldUpdate::Clear,
+        start_date: FieldUpdate::Unchanged,
+        deadline: FieldUpdate::Clear,
+        status: None,
+        project_uuid: FieldUpdate::Unchanged,
+        area_uuid: FieldUpdate::Unchanged,
+        tags: None,
+    };
+    db.update_task(update_request).await.unwrap();
+
+    let task = db.get_task_by_uuid(&uuid).await.unwrap().unwrap();
+    assert_eq!(task.notes, None);
+    assert_eq!(task.deadline, None);
+    assert_eq!(task.start_date, NaiveDate::from_ymd_opt(2025, 2, 1));
 }
 
 #[tokio::test]
@@ -628,12 +769,12 @@ async fn test_update_task_project_assignment() {
     let update_request = UpdateTaskRequest {
         uuid: task_uuid,
         title: None,
-        notes: None,
-        start_date: None,
-        deadline: None,
+        notes: FieldUpdate::Unchanged,
+        start_date: FieldUpdate::Unchanged,
+        deadline: FieldUpdate::Unchanged,
         status: None,
-        project_uuid: Some(project_uuid),
-        area_uuid: None,
+        project_uuid: FieldUpdate::Set(project_uuid),
+        area_uuid: FieldUpdate::Unchanged,
         tags: None,
     };
 
@@ -668,12 +809,12 @@ async fn test_update_task_status() {
     let update_request = UpdateTaskRequest {
         uuid,
         title: None,
-        notes: None,
-        start_date: None,
-        deadline: None,
+        notes: FieldUpdate::Unchanged,
+        start_date: FieldUpdate::Unchanged,
+        deadline: FieldUpdate::Unchanged,
         status: Some(TaskStatus::Completed),
-        project_uuid: None,
-        area_uuid: None,
+        project_uuid: FieldUpdate::Unchanged,
+        area_uuid: FieldUpdate::Unchanged,
         tags: None,
     };
 
@@ -708,12 +849,12 @@ async fn test_update_task_tags() {
     let update_request = UpdateTaskRequest {
         uuid,
         title: None,
-        notes: None,
-        start_date: None,
-        deadline: None,
+        notes: FieldUpdate::Unchanged,
+        start_date: FieldUpdate::Unchanged,
+        deadline: FieldUpdate::Unchanged,
         status: None,
-        project_uuid: None,
-        area_uuid: None,
+        project_uuid: FieldUpdate::Unchanged,
+        area_uuid: FieldUpdate::Unchanged,
         tags: Some(vec!["tag1".to_string(), "tag2".to_string()]),
     };
 
@@ -748,12 +889,12 @@ async fn test_update_task_partial() {
     let update_request = UpdateTaskRequest {
         uuid,
         title: Some("Updated Task".to_string()),
-        notes: None,
-        start_date: None,
-        deadline: None,
+        notes: FieldUpdate::Unchanged,
+        start_date: FieldUpdate::Unchanged,
+        deadline: FieldUpdate::Unchanged,
         status: None,
-        project_uuid: None,
-        area_uuid: None,
+        project_uuid: FieldUpdate::Unchanged,
+        area_uuid: FieldUpdate::Unchanged,
         tags: None,
     };
 
@@ -773,12 +914,12 @@ async fn test_update_nonexistent_task() {
     let update_request = UpdateTaskRequest {
         uuid: nonexistent_uuid,
         title: Some("Updated Title".to_string()),
-        notes: None,
-        start_date: None,
-        deadline: None,
+        notes: FieldUpdate::Unchanged,
+        start_date: FieldUpdate::Unchanged,
+        deadline: FieldUpdate::Unchanged,
         status: None,
-        project_uuid: None,
-        area_uuid: None,
+        project_uuid: FieldUpdate::Unchanged,
+        area_uuid: FieldUpdate::Unchanged,
         tags: None,
     };
 
@@ -816,12 +957,12 @@ async fn test_update_task_with_no_fields() {
     let update_request = UpdateTaskRequest {
         uuid: task_uuid,
         title: None,
-        notes: None,
-        start_date: None,
-        deadline: None,
+        notes: FieldUpdate::Unchanged,
+        start_date: FieldUpdate::Unchanged,
+        deadline: FieldUpdate::Unchanged,
         status: None,
-        project_uuid: None,
-        area_uuid: None,
+        project_uuid: FieldUpdate::Unchanged,
+        area_uuid: FieldUpdate::Unchanged,
         tags: None,
     };
 
@@ -857,12 +998,12 @@ async fn test_update_task_with_invalid_project_uuid() {
     let update_request = UpdateTaskRequest {
         uuid: task_uuid,
         title: None,
-        notes: None,
-        start_date: None,
-        deadline: None,
+        notes: FieldUpdate::Unchanged,
+        start_date: FieldUpdate::Unchanged,
+        deadline: FieldUpdate::Unchanged,
         status: None,
-        project_uuid: Some(invalid_project_uuid),
-        area_uuid: None,
+        project_uuid: FieldUpdate::Set(invalid_project_uuid),
+        area_uuid: FieldUpdate::Unchanged,
         tags: None,
     };
 
@@ -901,12 +1042,12 @@ async fn test_update_task_with_invalid_area_uuid() {
     let update_request = UpdateTaskRequest {
         uuid: task_uuid,
         title: None,
-        notes: None,
-        start_date: None,
-        deadline: None,
+        notes: FieldUpdate::Unchanged,
+        start_date: FieldUpdate::Unchanged,
+        deadline: FieldUpdate::Unchanged,
         status: None,
-        project_uuid: None,
-        area_uuid: Some(invalid_area_uuid),
+        project_uuid: FieldUpdate::Unchanged,
+        area_uuid: FieldUpdate::Set(invalid_area_uuid),
         tags: None,
     };
 
@@ -1148,12 +1289,12 @@ async fn test_update_task_remove_optional_fields() {
     let update_request = UpdateTaskRequest {
         uuid,
         title: None,
-        notes: Some("".to_string()),
-        start_date: None,
-        deadline: None,
+        notes: FieldUpdate::Set("".to_string()),
+        start_date: FieldUpdate::Unchanged,
+        deadline: FieldUpdate::Unchanged,
         status: None,
-        project_uuid: None,
-        area_uuid: None,
+        project_uuid: FieldUpdate::Unchanged,
+        area_uuid: FieldUpdate::Unchanged,
         tags: None,
     };
 
@@ -1215,3 +1356,23 @@ fn test_tags_round_trip_consistency() {
         assert_eq!(tags, result, "Round-trip should preserve tags");
     }
 }
+
+#[test]
+fn test_tags_blob_formats() {
+    use things3_core::database::{
+        deserialize_tags_from_blob_as, serialize_tags_to_blob_as, TagBlobFormat,
+    };
+
+    let tags = vec!["work".to_string()];
+    let blob = serialize_tags_to_blob_as(&tags, TagBlobFormat::Json).unwrap();
+    assert_eq!(blob, br#"["work"]"#);
+
+    // The native encoding is selectable but not implemented yet
+    assert!(serialize_tags_to_blob_as(&tags, TagBlobFormat::ThingsNative).is_err());
+    assert!(deserialize_tags_from_blob_as(&blob, TagBlobFormat::ThingsNative).is_err());
+
+    // A Things-written binary plist is reported rather than misparsed as JSON
+    let native = b"bplist00\xa1\x01";
+    let err = deserialize_tags_from_blob_as(native, TagBlobFormat::Json).unwrap_err();
+    assert!(err.to_string().contains("TMTaskTag"));
+}